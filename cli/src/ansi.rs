@@ -0,0 +1,219 @@
+//! Classic 16-color ANSI terminal palette generation.
+//!
+//! Produces the 8 normal + 8 bright ANSI colors (black, red, green, yellow, blue, magenta, cyan, white) from a single accent.
+//! Grays follow the base16-shell mapping onto the neutral ramp used by [`crate::base16_builder`].
+//! Also maps arbitrary colors onto the xterm 256-color palette for terminals without truecolor.
+
+use crate::base16_builder::{DEFAULT_NEUTRAL_DEPTH, Variant, assign_hue_slots, generate_neutrals};
+use crate::colors::{Hsl, Lab, Rgb, Srgb8};
+use crate::diffs::delta_e_2000;
+use crate::harmonies::{HarmonyKind, harmonies};
+use crate::tinted_theming::SchemeError;
+
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// ANSI color names in terminal index order (0-15).
+pub const ANSI16_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright_black",
+    "bright_red",
+    "bright_green",
+    "bright_yellow",
+    "bright_blue",
+    "bright_magenta",
+    "bright_cyan",
+    "bright_white",
+];
+
+/// Target hues for the six chromatic ANSI slots: red, green, yellow, blue, magenta, cyan.
+const CHROMATIC_HUES: [f32; 6] = [0.0, 120.0, 60.0, 220.0, 300.0, 180.0];
+const BRIGHT_LIGHTNESS_SHIFT: f32 = 0.12;
+/// xterm's default RGB values for the 16 system colors (indices 0-15).
+const XTERM_SYSTEM_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+/// Channel levels of the xterm 6x6x6 color cube (indices 16-231).
+const XTERM_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+/// First index of the xterm 24-step grayscale ramp (232-255).
+const XTERM_GRAY_START: u8 = 232;
+
+/// Generates a 16-color ANSI palette from `accent` using a triadic harmony and the default neutral depth.
+pub fn generate_ansi16(accent: Srgb8, variant: Variant) -> [Srgb8; 16] {
+    generate_ansi16_with(accent, variant, HarmonyKind::Triadic, DEFAULT_NEUTRAL_DEPTH)
+}
+
+/// Generates a 16-color ANSI palette with an explicit harmony and neutral depth.
+///
+/// Harmony colors are assigned to the closest red/green/yellow/blue/magenta/cyan slot (keeping their hue);
+/// remaining slots fall back to the canonical target hue.
+/// Bright variants are lighter than their normal counterparts for dark themes and darker for light themes.
+///
+/// Grays mirror base16-shell: black = base00, white = base05, bright black = base03, bright white = base07.
+pub fn generate_ansi16_with(accent: Srgb8, variant: Variant, harmony: HarmonyKind, neutral_depth: f32) -> [Srgb8; 16] {
+    let neutrals = generate_neutrals(variant, neutral_depth, None, None);
+    let accent_hsl: Hsl = Rgb::from(accent).into();
+
+    let (lightness, saturation, bright_shift) = match variant {
+        Variant::Dark => (0.55, 0.65, BRIGHT_LIGHTNESS_SHIFT),
+        Variant::Light => (0.42, 0.70, -BRIGHT_LIGHTNESS_SHIFT),
+    };
+
+    let mut hues = CHROMATIC_HUES;
+    let harmony_hues: Vec<f32> = harmonies(accent_hsl, harmony).iter().map(|color| color.h).collect();
+    for (&hue, slot) in harmony_hues
+        .iter()
+        .zip(assign_hue_slots(&CHROMATIC_HUES, &harmony_hues))
+    {
+        if let Some(idx) = slot {
+            hues[idx] = hue;
+        }
+    }
+
+    let mut palette = [Srgb8::new(0, 0, 0); 16];
+    palette[0] = neutrals[0];
+    palette[7] = neutrals[5];
+    palette[8] = neutrals[3];
+    palette[15] = neutrals[7];
+
+    for (i, &hue) in hues.iter().enumerate() {
+        let normal = Hsl::new(hue, saturation, lightness);
+        let bright = Hsl::new(hue, (saturation + 0.1).min(1.0), lightness + bright_shift);
+        palette[i + 1] = Srgb8::from(Rgb::from(normal));
+        palette[i + 9] = Srgb8::from(Rgb::from(bright));
+    }
+
+    palette
+}
+
+/// Returns the sRGB color xterm uses for a 256-color palette index.
+///
+/// Indices 0-15 use xterm's default system colors; most terminal themes override these.
+pub fn xterm256_color(index: u8) -> Srgb8 {
+    match index {
+        0..=15 => {
+            let (r, g, b) = XTERM_SYSTEM_COLORS[index as usize];
+            Srgb8::new(r, g, b)
+        }
+        16..=231 => {
+            let cube = index - 16;
+            Srgb8::new(
+                XTERM_CUBE_LEVELS[(cube / 36) as usize],
+                XTERM_CUBE_LEVELS[(cube / 6 % 6) as usize],
+                XTERM_CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - XTERM_GRAY_START);
+            Srgb8::new(level, level, level)
+        }
+    }
+}
+
+/// Maps a color to the perceptually nearest xterm 256-color index (ΔE2000).
+///
+/// Only the color cube (16-231) and grayscale ramp (232-255) are candidates: the 16 system colors are redefined by
+/// most terminal themes, so matching against their defaults would give unpredictable results.
+pub fn to_xterm256(color: Srgb8) -> u8 {
+    static XTERM_LABS: OnceLock<Vec<(u8, Lab)>> = OnceLock::new();
+    let candidates = XTERM_LABS.get_or_init(|| (16..=255).map(|i| (i, Lab::from(xterm256_color(i)))).collect());
+
+    let target = Lab::from(color);
+    candidates
+        .iter()
+        .map(|&(index, lab)| (index, delta_e_2000(target, lab)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(16, |(index, _)| index)
+}
+
+#[derive(Debug, Serialize)]
+struct Ansi16Yaml<'a> {
+    system: &'static str,
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<&'a str>,
+    variant: &'static str,
+    palette: serde_yml::Mapping,
+}
+
+/// Writes an ANSI palette as YAML with the colors keyed by [`ANSI16_NAMES`] in terminal order.
+pub fn write_ansi16_palette(
+    colors: &[Srgb8; 16], name: &str, author: Option<&str>, variant: Variant, path: impl AsRef<Path>,
+) -> Result<(), SchemeError> {
+    let mut palette = serde_yml::Mapping::new();
+    for (key, color) in ANSI16_NAMES.iter().zip(colors) {
+        palette.insert((*key).into(), color.to_hex().into());
+    }
+
+    let output = Ansi16Yaml { system: "ansi16", name, author, variant: variant.as_str(), palette };
+    let yaml = serde_yml::to_string(&output).map_err(|source| SchemeError::Serialize { source })?;
+
+    let path_ref = path.as_ref();
+    fs::write(path_ref, yaml).map_err(|source| SchemeError::Io { path: path_ref.to_path_buf(), source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wcag::relative_luminance;
+
+    #[test]
+    fn xterm256_maps_primaries_and_grays() {
+        assert_eq!(to_xterm256(Srgb8::new(255, 0, 0)), 196);
+        assert!(to_xterm256(Srgb8::new(128, 128, 128)) >= XTERM_GRAY_START);
+        assert_eq!(to_xterm256(Srgb8::new(0, 0, 0)), 16);
+        assert_eq!(xterm256_color(231), Srgb8::new(255, 255, 255));
+    }
+
+    #[test]
+    fn bright_variants_are_lighter_in_dark_theme() {
+        let palette = generate_ansi16(Srgb8::new(97, 175, 239), Variant::Dark);
+        for i in 0..8 {
+            assert!(
+                relative_luminance(palette[i + 8]) > relative_luminance(palette[i]),
+                "{} should be lighter than {}",
+                ANSI16_NAMES[i + 8],
+                ANSI16_NAMES[i]
+            );
+        }
+    }
+
+    #[test]
+    fn chromatic_slots_follow_target_hues() {
+        let palette = generate_ansi16(Srgb8::new(229, 108, 117), Variant::Dark);
+        let red: Hsl = Rgb::from(palette[1]).into();
+        let blue: Hsl = Rgb::from(palette[4]).into();
+        assert!(red.h < 40.0 || red.h > 320.0, "red hue {}", red.h);
+        assert!((180.0..=260.0).contains(&blue.h), "blue hue {}", blue.h);
+    }
+
+    #[test]
+    fn light_theme_uses_light_background() {
+        let palette = generate_ansi16(Srgb8::new(97, 175, 239), Variant::Light);
+        assert!(relative_luminance(palette[0]) > relative_luminance(palette[15]));
+    }
+}
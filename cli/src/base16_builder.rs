@@ -2,20 +2,82 @@
 //!
 //! This module generates color schemes that adhere to the Base16/Base24 specification.
 
-use crate::colors::{Hsl, Rgb, Srgb8};
+use crate::colors::{Hsl, Lab, Oklab, Oklch, Rgb, Srgb8, map_oklch_to_gamut};
+use crate::diffs::delta_e_2000;
 use crate::harmonies::{HarmonyKind, harmonies};
 use crate::tinted_theming::{Base16Scheme, Base24Scheme, SchemeMetadata};
-use crate::wcag::contrast_ratio;
+use crate::wcag::{contrast_ratio, make_accessible};
 
 pub const NEUTRAL_MAX_SATURATION: f32 = 0.10;
 pub const DEFAULT_NEUTRAL_DEPTH: f32 = 1.0;
+/// Default neutral hues: dark themes lean cool (blue-gray), light themes warm (paper).
+const DARK_NEUTRAL_HUE: f32 = 220.0;
+const LIGHT_NEUTRAL_HUE: f32 = 40.0;
+/// Saturation floor for neutrals with an explicit hue, so the tint survives deep (otherwise pure gray) neutrals.
+const TINTED_NEUTRAL_MIN_SATURATION: f32 = 0.05;
+/// Minimum ΔE2000 expected between any two accents (base08-base0F) before they read as the same color.
+pub const DEFAULT_ACCENT_DELTA_E: f32 = 10.0;
 
 const MIN_CONTRAST: f32 = 4.5;
+/// Contrast base05 (body text) must reach against base00, matching WCAG AAA for normal text.
+const BODY_TEXT_CONTRAST: f32 = 7.0;
 const DARK_NEUTRAL_CLASSIC: [f32; 8] = [0.08, 0.13, 0.18, 0.30, 0.50, 0.90, 0.95, 0.98];
 const DARK_NEUTRAL_MOODY: [f32; 8] = [0.008, 0.019, 0.033, 0.060, 0.100, 0.279, 0.456, 0.631];
 const LIGHT_NEUTRAL_CLASSIC: [f32; 8] = [0.98, 0.95, 0.90, 0.70, 0.50, 0.18, 0.13, 0.08];
 const LIGHT_NEUTRAL_MOODY: [f32; 8] = [0.95, 0.90, 0.80, 0.67, 0.54, 0.32, 0.20, 0.11];
 const NEUTRAL_SAT_DEPTH_FACTOR: f32 = 1.0;
+/// Target hues for base08-base0F: red, orange, yellow, green, cyan, blue, magenta, brown.
+pub(crate) const ACCENT_TARGET_HUES: [f32; 8] = [0.0, 30.0, 60.0, 120.0, 180.0, 220.0, 280.0, 20.0];
+/// Hue band a tinted-theming accent role is expected to fall in, checked by [`crate::tinted_theming::lint_scheme`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoleHueBand {
+    /// Base16 slot the band applies to (0x08-0x0E).
+    pub slot: usize,
+    /// Color name of the role, e.g. "red" for base08.
+    pub name: &'static str,
+    /// Center of the band as a CSS HSL hue in degrees.
+    pub hue: f32,
+    /// Allowed distance from `hue` in degrees, either way around the wheel.
+    pub tolerance: f32,
+}
+
+impl RoleHueBand {
+    /// Degrees by which `color`'s CSS HSL hue falls outside the band, or `None` when it is inside.
+    pub fn deviation(&self, color: Srgb8) -> Option<f32> {
+        let excess = hue_distance(Hsl::from(color).h, self.hue) - self.tolerance;
+        (excess > 0.0).then_some(excess)
+    }
+}
+
+/// Expected hue bands for the named accent roles, measured as CSS HSL hue. Centers use the same degrees as
+/// `ACCENT_TARGET_HUES`, but those targets are linear-RGB hues, so generated accents sit a few degrees off them here.
+/// Tolerances are wide enough for muted classics (Solarized's olive green, Solarized's violet in base0E). base0F
+/// (brown, deprecated) has no agreed hue and is not checked.
+pub const ROLE_HUE_BANDS: [RoleHueBand; 7] = [
+    RoleHueBand { slot: 0x08, name: "red", hue: 0.0, tolerance: 30.0 },
+    RoleHueBand { slot: 0x09, name: "orange", hue: 30.0, tolerance: 30.0 },
+    RoleHueBand { slot: 0x0A, name: "yellow", hue: 60.0, tolerance: 30.0 },
+    RoleHueBand { slot: 0x0B, name: "green", hue: 120.0, tolerance: 60.0 },
+    RoleHueBand { slot: 0x0C, name: "cyan", hue: 180.0, tolerance: 45.0 },
+    RoleHueBand { slot: 0x0D, name: "blue", hue: 220.0, tolerance: 50.0 },
+    RoleHueBand { slot: 0x0E, name: "magenta", hue: 280.0, tolerance: 65.0 },
+];
+/// Degrees kept between a harmony hue placed in a role slot and the edge of that role's [`ROLE_HUE_BANDS`] band,
+/// covering the shift from the generator's linear-RGB hue to the CSS hue the band is measured in.
+const ROLE_BAND_MARGIN: f32 = 10.0;
+/// Accent indices (into base08-base0F) brightened for base12-base17: red, yellow, green, cyan, blue, magenta.
+const BASE24_BRIGHT_SOURCES: [usize; 6] = [0, 2, 3, 4, 5, 6];
+/// OKLab lightness shared by all accents when `use_oklch` is set, before any contrast adjustment.
+const OKLCH_ACCENT_LIGHTNESS_DARK: f32 = 0.72;
+const OKLCH_ACCENT_LIGHTNESS_LIGHT: f32 = 0.52;
+/// Target OKLCH chroma for base08-base0E; base0F (brown) stays muted.
+const OKLCH_ACCENT_CHROMA: f32 = 0.14;
+const OKLCH_BROWN_CHROMA: f32 = 0.06;
+const OKLCH_LIGHTNESS_STEP: f32 = 0.02;
+/// Lightness offsets for the monochrome accents (base08-base0F), interleaved so neighbouring roles differ.
+const MONOCHROME_LIGHTNESS_STEPS: [f32; 8] = [0.0, 0.12, -0.06, 0.18, 0.06, -0.12, 0.24, -0.18];
+/// Saturation per monochrome accent; alternates so roles at similar lightness still separate by chroma.
+const MONOCHROME_SATURATION_STEPS: [f32; 8] = [0.70, 0.45, 0.85, 0.55, 0.35, 0.75, 0.50, 0.30];
 
 /// Theme variant determines background/foreground lightness progression.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,6 +87,16 @@ pub enum Variant {
 }
 
 impl Variant {
+    /// Reads a variant from scheme metadata, falling back to base00's Lab lightness (light at L* 50 and above).
+    pub fn infer(variant: Option<&str>, base00: Srgb8) -> Self {
+        match variant {
+            Some("light") => Variant::Light,
+            Some("dark") => Variant::Dark,
+            _ if Lab::from(base00).l >= 50.0 => Variant::Light,
+            _ => Variant::Dark,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Variant::Dark => "dark",
@@ -42,6 +114,35 @@ pub struct Base16Config {
     pub accent_color: Srgb8,
     pub harmony: HarmonyKind,
     pub neutral_depth: f32,
+    /// Build accents at one shared OKLCH lightness instead of HSL (`false` keeps the classic output).
+    pub use_oklch: bool,
+    /// Hue (degrees) used to tint base00-base07; `None` keeps the default cool-dark/warm-light grays.
+    pub neutral_hue: Option<f32>,
+    /// Start and end hues (degrees) interpolated from base00 to base07; takes precedence over `neutral_hue`.
+    pub neutral_hue_range: Option<(f32, f32)>,
+    /// HSL lightness for the accents before contrast adjustment; `None` uses 0.65 (dark) or 0.45 (light).
+    pub accent_lightness: Option<f32>,
+    /// HSL saturation for base08-base0E; `None` uses 0.70 (dark) or 0.75 (light). base0F stays muted.
+    pub accent_saturation: Option<f32>,
+}
+
+impl Default for Base16Config {
+    /// An unnamed dark Triadic scheme around a mid blue accent, with every optional tweak left at its default.
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            author: None,
+            variant: Variant::Dark,
+            accent_color: Srgb8::new(52, 152, 219),
+            harmony: HarmonyKind::Triadic,
+            neutral_depth: DEFAULT_NEUTRAL_DEPTH,
+            use_oklch: false,
+            neutral_hue: None,
+            neutral_hue_range: None,
+            accent_lightness: None,
+            accent_saturation: None,
+        }
+    }
 }
 
 /// Configuration for Base24 scheme generation.
@@ -53,6 +154,35 @@ pub struct Base24Config {
     pub accent_color: Srgb8,
     pub harmony: HarmonyKind,
     pub neutral_depth: f32,
+    /// Build accents at one shared OKLCH lightness instead of HSL (`false` keeps the classic output).
+    pub use_oklch: bool,
+    /// Hue (degrees) used to tint base00-base07; `None` keeps the default cool-dark/warm-light grays.
+    pub neutral_hue: Option<f32>,
+    /// Start and end hues (degrees) interpolated from base00 to base07; takes precedence over `neutral_hue`.
+    pub neutral_hue_range: Option<(f32, f32)>,
+    /// HSL lightness for the accents before contrast adjustment; `None` uses 0.65 (dark) or 0.45 (light).
+    pub accent_lightness: Option<f32>,
+    /// HSL saturation for base08-base0E; `None` uses 0.70 (dark) or 0.75 (light). base0F stays muted.
+    pub accent_saturation: Option<f32>,
+}
+
+impl Default for Base24Config {
+    /// An unnamed dark Triadic scheme around a mid blue accent, with every optional tweak left at its default.
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            author: None,
+            variant: Variant::Dark,
+            accent_color: Srgb8::new(52, 152, 219),
+            harmony: HarmonyKind::Triadic,
+            neutral_depth: DEFAULT_NEUTRAL_DEPTH,
+            use_oklch: false,
+            neutral_hue: None,
+            neutral_hue_range: None,
+            accent_lightness: None,
+            accent_saturation: None,
+        }
+    }
 }
 
 /// Generates a Base16 scheme from a single accent color using color harmonies.
@@ -68,9 +198,21 @@ pub fn generate_base16_scheme(config: Base16Config) -> Base16Scheme {
         variant: Some(config.variant.as_str().to_string()),
     };
 
-    let neutrals = generate_neutrals(config.variant, config.neutral_depth);
+    let neutrals = generate_neutrals(
+        config.variant,
+        config.neutral_depth,
+        config.neutral_hue,
+        config.neutral_hue_range,
+    );
     let accent_hsl: Hsl = Rgb::from(config.accent_color).into();
-    let accents = generate_accents(accent_hsl, config.harmony, neutrals[0], config.variant);
+    let accents = generate_accents(
+        accent_hsl,
+        config.harmony,
+        neutrals[0],
+        config.variant,
+        config.use_oklch,
+        AccentTargets { lightness: config.accent_lightness, saturation: config.accent_saturation },
+    );
 
     let mut colors = [Srgb8::new(0, 0, 0); 16];
     for (i, &color) in neutrals.iter().enumerate() {
@@ -83,6 +225,96 @@ pub fn generate_base16_scheme(config: Base16Config) -> Base16Scheme {
     Base16Scheme::new(metadata, colors)
 }
 
+/// Generates a Base16 scheme that keeps several seed colors (e.g. brand colors) as accents.
+///
+/// Each seed takes the accent slot whose target hue it matches best (see [`assign_hue_slots`]), nudged only if it
+/// misses the minimum contrast against base00. Every remaining slot is filled from the harmony of the seed closest
+/// in hue to that slot, exactly as [`generate_base16_scheme`] would build it. Seeds left over once all eight slots
+/// are taken are dropped. `config.accent_color` is ignored; an empty `seeds` falls back to it.
+pub fn generate_base16_from_accents(config: Base16Config, seeds: &[Srgb8]) -> Base16Scheme {
+    if seeds.is_empty() {
+        return generate_base16_scheme(config);
+    }
+
+    let metadata = SchemeMetadata {
+        system: "base16".to_string(),
+        name: config.name,
+        author: config.author,
+        variant: Some(config.variant.as_str().to_string()),
+    };
+
+    let neutrals = generate_neutrals(
+        config.variant,
+        config.neutral_depth,
+        config.neutral_hue,
+        config.neutral_hue_range,
+    );
+    let seed_hsl: Vec<Hsl> = seeds.iter().map(|&seed| Rgb::from(seed).into()).collect();
+    let seed_hues: Vec<f32> = seed_hsl.iter().map(|hsl| hsl.h).collect();
+    let harmony_accents: Vec<[Srgb8; 8]> = seed_hsl
+        .iter()
+        .map(|&hsl| {
+            generate_accents(
+                hsl,
+                config.harmony,
+                neutrals[0],
+                config.variant,
+                config.use_oklch,
+                AccentTargets { lightness: config.accent_lightness, saturation: config.accent_saturation },
+            )
+        })
+        .collect();
+
+    let mut accents: [Option<Srgb8>; 8] = [None; 8];
+    for (&seed, slot) in seeds.iter().zip(assign_hue_slots(&ACCENT_TARGET_HUES, &seed_hues)) {
+        if let Some(slot) = slot {
+            accents[slot] = Some(fix_accent_contrast(seed, neutrals[0], config.variant));
+        }
+    }
+    // Any seed may lend its harmony to any open slot, so none is marked as taken.
+    let seeds_taken = vec![false; seeds.len()];
+    let accents = std::array::from_fn::<Srgb8, 8, _>(|slot| {
+        accents[slot].unwrap_or_else(|| {
+            let nearest = find_closest_hue_index(&seed_hues, ACCENT_TARGET_HUES[slot], &seeds_taken).unwrap_or(0);
+            harmony_accents[nearest][slot]
+        })
+    });
+
+    let mut colors = [Srgb8::new(0, 0, 0); 16];
+    colors[..8].copy_from_slice(&neutrals);
+    colors[8..].copy_from_slice(&accents);
+
+    Base16Scheme::new(metadata, colors)
+}
+
+/// Generates a Base16 scheme whose accents all share the accent color's hue.
+///
+/// Intended for focus/minimal themes: base08-base0F differ only in lightness and saturation, and each still meets
+/// the minimum contrast against base00. The configured harmony is ignored.
+pub fn generate_monochrome_scheme(config: Base16Config) -> Base16Scheme {
+    let metadata = SchemeMetadata {
+        system: "base16".to_string(),
+        name: config.name,
+        author: config.author,
+        variant: Some(config.variant.as_str().to_string()),
+    };
+
+    let neutrals = generate_neutrals(
+        config.variant,
+        config.neutral_depth,
+        config.neutral_hue,
+        config.neutral_hue_range,
+    );
+    let accent_hsl: Hsl = Rgb::from(config.accent_color).into();
+    let accents = generate_monochrome_accents(accent_hsl.h, neutrals[0], config.variant);
+
+    let mut colors = [Srgb8::new(0, 0, 0); 16];
+    colors[..8].copy_from_slice(&neutrals);
+    colors[8..].copy_from_slice(&accents);
+
+    Base16Scheme::new(metadata, colors)
+}
+
 /// Generates a Base24 scheme from a single accent color using color harmonies.
 ///
 /// Extends Base16 with base10-base17 as brighter/darker variants of accent colors.
@@ -94,9 +326,21 @@ pub fn generate_base24_scheme(config: Base24Config) -> Base24Scheme {
         variant: Some(config.variant.as_str().to_string()),
     };
 
-    let neutrals = generate_neutrals(config.variant, config.neutral_depth);
+    let neutrals = generate_neutrals(
+        config.variant,
+        config.neutral_depth,
+        config.neutral_hue,
+        config.neutral_hue_range,
+    );
     let accent_hsl: Hsl = Rgb::from(config.accent_color).into();
-    let accents = generate_accents(accent_hsl, config.harmony, neutrals[0], config.variant);
+    let accents = generate_accents(
+        accent_hsl,
+        config.harmony,
+        neutrals[0],
+        config.variant,
+        config.use_oklch,
+        AccentTargets { lightness: config.accent_lightness, saturation: config.accent_saturation },
+    );
     let extended = generate_base24_extended(&neutrals, &accents, config.variant);
 
     let mut colors = [Srgb8::new(0, 0, 0); 24];
@@ -113,33 +357,125 @@ pub fn generate_base24_scheme(config: Base24Config) -> Base24Scheme {
     Base24Scheme::new(metadata, colors)
 }
 
+/// Flips a Base16 scheme between dark and light variants.
+///
+/// The neutral ramp is reversed (base00↔base07, base01↔base06, ...) and each accent is re-run through the contrast
+/// check against the new base00 (see [`fix_accent_contrast`]), so hues survive.
+/// The current variant comes from the metadata, or from base00's lightness when the metadata doesn't say.
+pub fn invert_variant(scheme: &Base16Scheme) -> Base16Scheme {
+    let source = scheme.colors();
+    let target = match scheme_variant(scheme) {
+        Variant::Dark => Variant::Light,
+        Variant::Light => Variant::Dark,
+    };
+
+    let mut colors = [Srgb8::new(0, 0, 0); 16];
+    for i in 0..8 {
+        colors[i] = source[7 - i];
+    }
+    for i in 8..16 {
+        colors[i] = fix_accent_contrast(source[i], colors[0], target);
+    }
+
+    let metadata = SchemeMetadata { variant: Some(target.as_str().to_string()), ..scheme.metadata.clone() };
+    Base16Scheme::new(metadata, colors)
+}
+
+/// Combines the neutrals (base00-base07) of one scheme with the accents (base08-base0F) of another.
+///
+/// Accents are re-checked for contrast against the new base00 (see [`fix_accent_contrast`]). The result keeps the
+/// neutral scheme's metadata, named after both inputs.
+pub fn merge_schemes(neutrals: &Base16Scheme, accents: &Base16Scheme) -> Base16Scheme {
+    let variant = scheme_variant(neutrals);
+    let mut colors = [Srgb8::new(0, 0, 0); 16];
+    colors[..8].copy_from_slice(&neutrals.colors()[..8]);
+    for i in 8..16 {
+        colors[i] = fix_accent_contrast(accents.colors()[i], colors[0], variant);
+    }
+
+    let metadata = SchemeMetadata {
+        name: format!("{} + {}", neutrals.metadata.name, accents.metadata.name),
+        variant: Some(variant.as_str().to_string()),
+        ..neutrals.metadata.clone()
+    };
+    Base16Scheme::new(metadata, colors)
+}
+
+/// Reads a scheme's variant from its metadata, falling back to base00's lightness.
+fn scheme_variant(scheme: &Base16Scheme) -> Variant {
+    Variant::infer(scheme.metadata.variant.as_deref(), scheme.colors()[0])
+}
+
+/// Returns `accent` unchanged if it meets the minimum contrast against `background`, otherwise moves its lightness
+/// until it does.
+///
+/// The HSL lightness search in [`ensure_contrast`] keeps hue but has fixed bounds that a mid-gray background (as left
+/// by moody neutral ramps) can defeat, so any accent still short is finished with [`make_accessible`].
+fn fix_accent_contrast(accent: Srgb8, background: Srgb8, variant: Variant) -> Srgb8 {
+    if contrast_ratio(background, accent) >= MIN_CONTRAST {
+        return accent;
+    }
+    let adjusted = ensure_contrast(Rgb::from(accent).into(), background, variant);
+    make_accessible(&[adjusted], background, MIN_CONTRAST)[0]
+}
+
 /// Generates 8 neutral colors (base00-base07) with low saturation.
 ///
 /// Dark themes: base00 (darkest) → base07 (lightest)
 /// Light themes: base00 (lightest) → base07 (darkest)
-fn generate_neutrals(variant: Variant, neutral_depth: f32) -> [Srgb8; 8] {
+///
+/// `neutral_hue` tints the grays toward a chosen hue; without it dark themes lean cool (220°) and light themes
+/// warm (40°). `hue_range` instead drifts the hue from its start at base00 to its end at base07, taking the shorter
+/// way around the wheel (through decreasing hues when start and end are exactly 180° apart).
+pub(crate) fn generate_neutrals(
+    variant: Variant, neutral_depth: f32, neutral_hue: Option<f32>, hue_range: Option<(f32, f32)>,
+) -> [Srgb8; 8] {
     let depth = neutral_depth.clamp(0.0, 1.0);
     let (lightness_values, hue, saturation) = match variant {
         Variant::Dark => (
             blend_lightness_curve(&DARK_NEUTRAL_CLASSIC, &DARK_NEUTRAL_MOODY, depth),
-            220.0,
+            DARK_NEUTRAL_HUE,
             adjusted_neutral_saturation(NEUTRAL_MAX_SATURATION * 0.8, depth),
         ),
         Variant::Light => (
             blend_lightness_curve(&LIGHT_NEUTRAL_CLASSIC, &LIGHT_NEUTRAL_MOODY, depth),
-            40.0,
+            LIGHT_NEUTRAL_HUE,
             adjusted_neutral_saturation(NEUTRAL_MAX_SATURATION * 0.6, depth),
         ),
     };
+    let ((start_hue, end_hue), saturation) = match (hue_range, neutral_hue) {
+        (Some(range), _) => (range, saturation.max(TINTED_NEUTRAL_MIN_SATURATION)),
+        (None, Some(neutral_hue)) => (
+            (neutral_hue, neutral_hue),
+            saturation.max(TINTED_NEUTRAL_MIN_SATURATION),
+        ),
+        (None, None) => ((hue, hue), saturation),
+    };
     let saturation = saturation.min(NEUTRAL_MAX_SATURATION);
+    let hue_span = (end_hue - start_hue + 540.0).rem_euclid(360.0) - 180.0;
 
-    let mut neutrals = [Srgb8::new(0, 0, 0); 8];
-    for (i, &lightness) in lightness_values.iter().enumerate() {
-        let hsl = Hsl::new(hue, saturation, lightness);
-        let rgb: Rgb = hsl.into();
-        neutrals[i] = Srgb8::from(rgb);
+    let mut ramp: [Hsl; 8] = std::array::from_fn(|i| {
+        let hue = (start_hue + hue_span * i as f32 / 7.0).rem_euclid(360.0);
+        Hsl::new(hue, saturation, lightness_values[i])
+    });
+
+    // Deep backgrounds can leave the foregrounds short of readable contrast, so nudge base04/base05 away from
+    // base00 and carry the brighter slots along to keep the ramp ordered.
+    let background = Srgb8::from(Rgb::from(ramp[0]));
+    for slot in 4..8 {
+        ramp[slot].l = match variant {
+            Variant::Dark => ramp[slot].l.max(ramp[slot - 1].l),
+            Variant::Light => ramp[slot].l.min(ramp[slot - 1].l),
+        };
+        let target = match slot {
+            4 => MIN_CONTRAST,
+            5 => BODY_TEXT_CONTRAST,
+            _ => continue,
+        };
+        ramp[slot] = reach_contrast(ramp[slot], background, variant, target);
     }
-    neutrals
+
+    ramp.map(|hsl| Srgb8::from(Rgb::from(hsl)))
 }
 
 fn blend_lightness_curve(base: &[f32; 8], moody: &[f32; 8], depth: f32) -> [f32; 8] {
@@ -155,6 +491,13 @@ fn adjusted_neutral_saturation(base: f32, depth: f32) -> f32 {
     scaled.max(0.0)
 }
 
+/// Optional HSL lightness/saturation overrides for [`generate_accents`].
+#[derive(Debug, Clone, Copy, Default)]
+struct AccentTargets {
+    lightness: Option<f32>,
+    saturation: Option<f32>,
+}
+
 /// Generates 8 accent colors (base08-base0F) mapped to semantic roles.
 ///
 /// Semantic roles:
@@ -166,47 +509,106 @@ fn adjusted_neutral_saturation(base: f32, depth: f32) -> f32 {
 /// - base0D (blue): functions, headings
 /// - base0E (magenta): keywords, storage
 /// - base0F (brown): deprecated
-fn generate_accents(base: Hsl, harmony: HarmonyKind, background: Srgb8, variant: Variant) -> [Srgb8; 8] {
-    let harmony_colors = harmonies(base, harmony);
-    let target_hues = [0.0, 30.0, 60.0, 120.0, 180.0, 220.0, 280.0, 20.0];
+///
+/// Harmony hues replace the target hue of their closest slot, clamped by [`within_role_band`] so each accent keeps
+/// reading as its role. With `use_oklch`, the same hues are rebuilt at a single OKLab lightness (see
+/// [`oklch_accents`]) and `targets` is ignored.
+fn generate_accents(
+    base: Hsl, harmony: HarmonyKind, background: Srgb8, variant: Variant, use_oklch: bool, targets: AccentTargets,
+) -> [Srgb8; 8] {
+    let mut hues = ACCENT_TARGET_HUES;
+    let harmony_hues: Vec<f32> = harmonies(base, harmony).iter().map(|color| color.h).collect();
+    for (&hue, slot) in harmony_hues
+        .iter()
+        .zip(assign_hue_slots(&ACCENT_TARGET_HUES, &harmony_hues))
+    {
+        if let Some(idx) = slot {
+            hues[idx] = within_role_band(idx, hue);
+        }
+    }
+
+    if use_oklch {
+        return oklch_accents(&hues, background, variant);
+    }
 
-    let target_lightness = match variant {
+    let target_lightness = targets.lightness.unwrap_or(match variant {
         Variant::Dark => 0.65,
         Variant::Light => 0.45,
-    };
+    });
 
-    let target_saturation = match variant {
+    let target_saturation = targets.saturation.unwrap_or(match variant {
         Variant::Dark => 0.70,
         Variant::Light => 0.75,
-    };
+    });
 
     let mut accents = [Srgb8::new(0, 0, 0); 8];
-
-    let mut assigned = [false; 8];
-    for harmony_color in harmony_colors {
-        let closest_idx = find_closest_hue_index(&target_hues, harmony_color.h, &assigned);
-        if let Some(idx) = closest_idx {
-            let adjusted = Hsl::new(
-                harmony_color.h,
-                if idx == 7 { 0.35 } else { target_saturation },
-                target_lightness,
-            );
-            accents[idx] = ensure_contrast(adjusted, background, variant);
-            assigned[idx] = true;
-        }
+    for (i, &hue) in hues.iter().enumerate() {
+        let hsl = Hsl::new(hue, if i == 7 { 0.35 } else { target_saturation }, target_lightness);
+        accents[i] = ensure_contrast(hsl, background, variant);
     }
 
-    for (i, &is_assigned) in assigned.iter().enumerate() {
-        if !is_assigned {
-            let hsl = Hsl::new(
-                target_hues[i],
-                if i == 7 { 0.35 } else { target_saturation },
-                target_lightness,
-            );
-            accents[i] = ensure_contrast(hsl, background, variant);
+    accents
+}
+
+/// Pulls a harmony hue placed in accent slot `idx` toward the slot's target hue so the finished accent still lints as
+/// its role. Slots without a [`ROLE_HUE_BANDS`] entry (base0F) keep the hue as-is.
+fn within_role_band(idx: usize, hue: f32) -> f32 {
+    let Some(band) = ROLE_HUE_BANDS.get(idx) else {
+        return hue;
+    };
+    let target = ACCENT_TARGET_HUES[idx];
+    let limit = band.tolerance - ROLE_BAND_MARGIN;
+    let offset = (hue - target + 540.0).rem_euclid(360.0) - 180.0;
+    (target + offset.clamp(-limit, limit)).rem_euclid(360.0)
+}
+
+/// Builds accents that share one OKLab lightness so they read as equally bright.
+///
+/// Each (linear HSL) hue is mapped to its OKLCH hue and chroma is reduced until the color fits in sRGB. If any
+/// accent misses the minimum contrast, the shared lightness steps away from the background for all of them.
+fn oklch_accents(hues: &[f32; 8], background: Srgb8, variant: Variant) -> [Srgb8; 8] {
+    let (mut lightness, step) = match variant {
+        Variant::Dark => (OKLCH_ACCENT_LIGHTNESS_DARK, OKLCH_LIGHTNESS_STEP),
+        Variant::Light => (OKLCH_ACCENT_LIGHTNESS_LIGHT, -OKLCH_LIGHTNESS_STEP),
+    };
+    let ok_hues = hues.map(|hue| Oklch::from(Oklab::from(Rgb::from(Hsl::new(hue, 0.7, 0.5)))).h);
+
+    loop {
+        let accents: [Srgb8; 8] = std::array::from_fn(|i| {
+            let chroma = if i == 7 { OKLCH_BROWN_CHROMA } else { OKLCH_ACCENT_CHROMA };
+            map_oklch_to_gamut(Oklch::new(lightness, chroma, ok_hues[i]))
+        });
+        let next = lightness + step;
+        let passes = accents
+            .iter()
+            .all(|&accent| contrast_ratio(background, accent) >= MIN_CONTRAST);
+        if passes || !(0.15..=0.95).contains(&next) {
+            return accents;
         }
+        lightness = next;
     }
+}
 
+/// Generates 8 accents (base08-base0F) on a single hue, stepping lightness and saturation per role.
+fn generate_monochrome_accents(hue: f32, background: Srgb8, variant: Variant) -> [Srgb8; 8] {
+    let base_lightness = match variant {
+        Variant::Dark => 0.65,
+        Variant::Light => 0.45,
+    };
+
+    let mut accents = [Srgb8::new(0, 0, 0); 8];
+    for (i, accent) in accents.iter_mut().enumerate() {
+        let offset = match variant {
+            Variant::Dark => MONOCHROME_LIGHTNESS_STEPS[i],
+            Variant::Light => -MONOCHROME_LIGHTNESS_STEPS[i],
+        };
+        let lightness = (base_lightness + offset).clamp(0.15, 0.95);
+        *accent = ensure_contrast(
+            Hsl::new(hue, MONOCHROME_SATURATION_STEPS[i], lightness),
+            background,
+            variant,
+        );
+    }
     accents
 }
 
@@ -214,7 +616,12 @@ fn generate_accents(base: Hsl, harmony: HarmonyKind, background: Srgb8, variant:
 ///
 /// Per Base24 spec:
 /// - base10-base11: darker/darkest backgrounds
-/// - base12-base17: brighter versions of base08-base0D
+/// - base12: bright red (from base08)
+/// - base13: bright yellow (from base0A)
+/// - base14: bright green (from base0B)
+/// - base15: bright cyan (from base0C)
+/// - base16: bright blue (from base0D)
+/// - base17: bright magenta (from base0E)
 fn generate_base24_extended(neutrals: &[Srgb8; 8], accents: &[Srgb8; 8], variant: Variant) -> [Srgb8; 8] {
     let mut extended = [Srgb8::new(0, 0, 0); 8];
 
@@ -247,8 +654,8 @@ fn generate_base24_extended(neutrals: &[Srgb8; 8], accents: &[Srgb8; 8], variant
         }
     }
 
-    for i in 0..6 {
-        let accent_hsl: Hsl = Rgb::from(accents[i]).into();
+    for (i, &source) in BASE24_BRIGHT_SOURCES.iter().enumerate() {
+        let accent_hsl: Hsl = Rgb::from(accents[source]).into();
         let brighter_l = match variant {
             Variant::Dark => (accent_hsl.l + 0.15).min(0.85),
             Variant::Light => (accent_hsl.l - 0.15).max(0.30),
@@ -259,8 +666,57 @@ fn generate_base24_extended(neutrals: &[Srgb8; 8], accents: &[Srgb8; 8], variant
     extended
 }
 
-/// Finds the closest unassigned target hue index.
-fn find_closest_hue_index(targets: &[f32], hue: f32, assigned: &[bool]) -> Option<usize> {
+/// Returns the Base16 slot (0x00-0x0F) whose role best describes `color`.
+///
+/// Chromatic colors map to the accent (base08-base0F) with the nearest target hue, using the same targets as scheme
+/// generation. Colors at or below [`NEUTRAL_MAX_SATURATION`] map to a neutral slot by lightness, following the dark
+/// variant ramp (base00 darkest, base07 lightest).
+pub fn closest_role(color: Srgb8) -> usize {
+    let hsl: Hsl = Rgb::from(color).into();
+    if hsl.s <= NEUTRAL_MAX_SATURATION {
+        return (Lab::from(color).l / 100.0 * 7.0).round().clamp(0.0, 7.0) as usize;
+    }
+
+    let any = [false; 8];
+    8 + find_closest_hue_index(&ACCENT_TARGET_HUES, hsl.h, &any).unwrap_or(0)
+}
+
+/// Matches each hue to a distinct target slot, closest pairs first.
+///
+/// Every (hue, target) pair is ranked by circular distance and taken in that order while both sides are still free,
+/// so a hue that fits a slot well can't be locked out by an earlier hue that merely fits it best of the remaining
+/// ones. Ties go to the lower target index, then the lower hue value, which keeps the result independent of the
+/// order of `hues`. Returns the slot for each hue, or `None` for hues left over once every target is taken.
+pub(crate) fn assign_hue_slots(targets: &[f32], hues: &[f32]) -> Vec<Option<usize>> {
+    let mut pairs: Vec<(f32, usize, usize)> = hues
+        .iter()
+        .enumerate()
+        .flat_map(|(h, &hue)| {
+            targets
+                .iter()
+                .enumerate()
+                .map(move |(t, &target)| (hue_distance(hue, target), t, h))
+        })
+        .collect();
+    pairs.sort_by(|a, b| {
+        a.0.total_cmp(&b.0)
+            .then(a.1.cmp(&b.1))
+            .then(hues[a.2].total_cmp(&hues[b.2]))
+    });
+
+    let mut slots = vec![None; hues.len()];
+    let mut taken = vec![false; targets.len()];
+    for (_, target, hue) in pairs {
+        if slots[hue].is_none() && !taken[target] {
+            slots[hue] = Some(target);
+            taken[target] = true;
+        }
+    }
+    slots
+}
+
+/// Finds the closest unassigned target hue index. Ties go to the earliest target.
+pub(crate) fn find_closest_hue_index(targets: &[f32], hue: f32, assigned: &[bool]) -> Option<usize> {
     let mut best_idx = None;
     let mut best_distance = f32::MAX;
 
@@ -286,37 +742,79 @@ fn hue_distance(h1: f32, h2: f32) -> f32 {
 
 /// Ensures color meets minimum contrast ratio against background.
 fn ensure_contrast(color: Hsl, background: Srgb8, variant: Variant) -> Srgb8 {
-    let mut adjusted = color;
-    let rgb: Rgb = adjusted.into();
-    let mut current = Srgb8::from(rgb);
+    Srgb8::from(Rgb::from(reach_contrast(color, background, variant, MIN_CONTRAST)))
+}
 
+/// Steps a color's lightness away from the background until it reaches `target` contrast (or the step limit).
+fn reach_contrast(color: Hsl, background: Srgb8, variant: Variant, target: f32) -> Hsl {
+    let mut adjusted = color;
     let mut iterations = 0;
-    while contrast_ratio(background, current) < MIN_CONTRAST && iterations < 20 {
+    while contrast_ratio(background, Srgb8::from(Rgb::from(adjusted))) < target && iterations < 20 {
         adjusted.l = match variant {
             Variant::Dark => (adjusted.l + 0.05).min(0.95),
             Variant::Light => (adjusted.l - 0.05).max(0.15),
         };
-        let rgb: Rgb = adjusted.into();
-        current = Srgb8::from(rgb);
         iterations += 1;
     }
 
-    current
+    adjusted
+}
+
+/// Finds adjacent neutrals whose perceptual lightness runs against the variant's direction.
+///
+/// Dark schemes should get lighter from base00 to base07 and light schemes darker.
+/// Returns the index `i` of every pair `(i, i + 1)` that breaks the ramp.
+pub fn neutral_ramp_breaks(neutrals: &[Srgb8], variant: Variant) -> Vec<usize> {
+    let lightness: Vec<f32> = neutrals.iter().map(|&color| Lab::from(color).l).collect();
+    lightness
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| match variant {
+            Variant::Dark => pair[1] < pair[0],
+            Variant::Light => pair[1] > pair[0],
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Finds pairs of accents that are closer than `threshold` (ΔE2000).
+///
+/// Returns `(i, j, ΔE)` for every pair with `i < j`, where the indices are positions in `accents`.
+pub fn indistinct_accents(accents: &[Srgb8], threshold: f32) -> Vec<(usize, usize, f32)> {
+    let labs: Vec<Lab> = accents.iter().copied().map(Lab::from).collect();
+    let mut pairs = Vec::new();
+    for i in 0..labs.len() {
+        for j in i + 1..labs.len() {
+            let delta = delta_e_2000(labs[i], labs[j]);
+            if delta < threshold {
+                pairs.push((i, j, delta));
+            }
+        }
+    }
+    pairs
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn closest_role_matches_accent_hues_and_neutrals() {
+        assert_eq!(closest_role(Srgb8::new(220, 40, 40)), 0x08);
+        assert_eq!(closest_role(Srgb8::new(40, 200, 60)), 0x0B);
+        assert_eq!(closest_role(Srgb8::new(50, 90, 230)), 0x0D);
+        assert_eq!(closest_role(Srgb8::new(10, 10, 10)), 0x00);
+        assert_eq!(closest_role(Srgb8::new(250, 250, 250)), 0x07);
+    }
+
     #[test]
     fn base16_scheme_has_16_colors() {
         let config = Base16Config {
             name: "Test Dark".to_string(),
-            author: None,
             variant: Variant::Dark,
             accent_color: Srgb8::new(229, 108, 117),
             harmony: HarmonyKind::Triadic,
-            neutral_depth: DEFAULT_NEUTRAL_DEPTH,
+            ..Default::default()
         };
         let scheme = generate_base16_scheme(config);
         assert_eq!(scheme.colors().len(), 16);
@@ -326,19 +824,141 @@ mod tests {
     fn base24_scheme_has_24_colors() {
         let config = Base24Config {
             name: "Test Light".to_string(),
-            author: None,
             variant: Variant::Light,
             accent_color: Srgb8::new(52, 152, 219),
             harmony: HarmonyKind::Complementary,
-            neutral_depth: DEFAULT_NEUTRAL_DEPTH,
+            ..Default::default()
         };
         let scheme = generate_base24_scheme(config);
         assert_eq!(scheme.colors().len(), 24);
     }
 
+    #[test]
+    fn monochrome_accents_share_accent_hue() {
+        let accent_color = Srgb8::new(52, 152, 219);
+        let accent_hue = Hsl::from(Rgb::from(accent_color)).h;
+        for variant in [Variant::Dark, Variant::Light] {
+            let config = Base16Config {
+                name: "Test Mono".to_string(),
+                variant,
+                accent_color,
+                harmony: HarmonyKind::Triadic,
+                ..Default::default()
+            };
+            let scheme = generate_monochrome_scheme(config);
+            let colors = scheme.colors();
+            for (i, &accent) in colors[8..].iter().enumerate() {
+                let hsl: Hsl = Rgb::from(accent).into();
+                assert!(
+                    hue_distance(hsl.h, accent_hue) < 5.0,
+                    "base{:02X} hue {} drifted from {}",
+                    i + 8,
+                    hsl.h,
+                    accent_hue
+                );
+                assert!(contrast_ratio(colors[0], accent) >= MIN_CONTRAST);
+            }
+        }
+    }
+
+    #[test]
+    fn invert_variant_flips_dark_to_light() {
+        let config = Base16Config {
+            name: "Test Dark".to_string(),
+            variant: Variant::Dark,
+            accent_color: Srgb8::new(229, 108, 117),
+            harmony: HarmonyKind::Triadic,
+            ..Default::default()
+        };
+        let dark = generate_base16_scheme(config);
+        let light = invert_variant(&dark);
+
+        assert_eq!(light.metadata.variant.as_deref(), Some("light"));
+        assert_eq!(light.colors()[0], dark.colors()[7]);
+        assert!(Lab::from(light.colors()[0]).l > 50.0);
+        for &accent in &light.colors()[8..] {
+            assert!(contrast_ratio(light.colors()[0], accent) >= MIN_CONTRAST);
+        }
+    }
+
+    #[test]
+    fn oklch_accents_share_perceptual_lightness() {
+        for variant in [Variant::Dark, Variant::Light] {
+            let config = Base16Config {
+                name: "Test OKLCH".to_string(),
+                variant,
+                accent_color: Srgb8::new(229, 108, 117),
+                harmony: HarmonyKind::Triadic,
+                use_oklch: true,
+                ..Default::default()
+            };
+            let scheme = generate_base16_scheme(config);
+            let colors = scheme.colors();
+            let lightness: Vec<f32> = colors[8..].iter().map(|&c| Oklab::from(c).l).collect();
+            let spread =
+                lightness.iter().cloned().fold(f32::MIN, f32::max) - lightness.iter().cloned().fold(f32::MAX, f32::min);
+            assert!(spread < 0.02, "{variant:?} OKLab L spread {spread}: {lightness:?}");
+            for &accent in &colors[8..] {
+                assert!(contrast_ratio(colors[0], accent) >= MIN_CONTRAST);
+            }
+        }
+    }
+
+    #[test]
+    fn merge_schemes_takes_neutrals_and_accents() {
+        let config = |variant, accent_color| Base16Config {
+            name: format!("{variant:?}"),
+            variant,
+            accent_color,
+            harmony: HarmonyKind::Triadic,
+            ..Default::default()
+        };
+        let dark = generate_base16_scheme(config(Variant::Dark, Srgb8::new(97, 175, 239)));
+        let light = generate_base16_scheme(config(Variant::Light, Srgb8::new(229, 108, 117)));
+
+        let merged = merge_schemes(&dark, &light);
+        assert_eq!(merged.metadata.name, "Dark + Light");
+        assert_eq!(&merged.colors()[..8], &dark.colors()[..8]);
+        for (&accent, &source) in merged.colors()[8..].iter().zip(&light.colors()[8..]) {
+            assert!(contrast_ratio(merged.colors()[0], accent) >= MIN_CONTRAST);
+            let (fixed, original) = (Hsl::from(Rgb::from(accent)), Hsl::from(Rgb::from(source)));
+            assert!(hue_distance(fixed.h, original.h) < 5.0 || fixed.s < 0.05);
+        }
+
+        let same = merge_schemes(&dark, &dark);
+        assert_eq!(same.colors(), dark.colors(), "passing accents are left alone");
+    }
+
+    #[test]
+    fn assign_hue_slots_prefers_global_best_match() {
+        // Greedy list-order assignment would give 10° the red slot and push 1° to brown.
+        assert_eq!(
+            assign_hue_slots(&ACCENT_TARGET_HUES, &[10.0, 1.0]),
+            vec![Some(7), Some(0)]
+        );
+        assert_eq!(
+            assign_hue_slots(&ACCENT_TARGET_HUES, &[1.0, 10.0]),
+            vec![Some(0), Some(7)]
+        );
+
+        let hues = [215.0, 5.0, 125.0, 62.0];
+        let expected = [5, 0, 3, 2];
+        for rotation in 0..hues.len() {
+            let mut rotated = hues;
+            rotated.rotate_left(rotation);
+            let slots = assign_hue_slots(&ACCENT_TARGET_HUES, &rotated);
+            for (i, slot) in slots.into_iter().enumerate() {
+                assert_eq!(slot, Some(expected[(i + rotation) % hues.len()]));
+            }
+        }
+
+        let crowded = assign_hue_slots(&[0.0, 120.0], &[0.0, 10.0, 120.0]);
+        assert_eq!(crowded, vec![Some(0), None, Some(1)]);
+    }
+
     #[test]
     fn neutrals_are_low_saturation() {
-        let neutrals = generate_neutrals(Variant::Dark, DEFAULT_NEUTRAL_DEPTH);
+        let neutrals = generate_neutrals(Variant::Dark, DEFAULT_NEUTRAL_DEPTH, None, None);
         for color in neutrals {
             let hsl: Hsl = Rgb::from(color).into();
             assert!(
@@ -351,7 +971,7 @@ mod tests {
 
     #[test]
     fn dark_theme_base00_darker_than_base07() {
-        let neutrals = generate_neutrals(Variant::Dark, DEFAULT_NEUTRAL_DEPTH);
+        let neutrals = generate_neutrals(Variant::Dark, DEFAULT_NEUTRAL_DEPTH, None, None);
         let base00: Hsl = Rgb::from(neutrals[0]).into();
         let base07: Hsl = Rgb::from(neutrals[7]).into();
         assert!(base00.l < base07.l, "Dark theme: base00 should be darker than base07");
@@ -359,7 +979,7 @@ mod tests {
 
     #[test]
     fn light_theme_base00_lighter_than_base07() {
-        let neutrals = generate_neutrals(Variant::Light, DEFAULT_NEUTRAL_DEPTH);
+        let neutrals = generate_neutrals(Variant::Light, DEFAULT_NEUTRAL_DEPTH, None, None);
         let base00: Hsl = Rgb::from(neutrals[0]).into();
         let base07: Hsl = Rgb::from(neutrals[7]).into();
         assert!(base00.l > base07.l, "Light theme: base00 should be lighter than base07");
@@ -367,9 +987,16 @@ mod tests {
 
     #[test]
     fn accents_meet_contrast_requirements() {
-        let neutrals = generate_neutrals(Variant::Dark, DEFAULT_NEUTRAL_DEPTH);
+        let neutrals = generate_neutrals(Variant::Dark, DEFAULT_NEUTRAL_DEPTH, None, None);
         let base_hsl = Hsl::new(0.0, 0.7, 0.6);
-        let accents = generate_accents(base_hsl, HarmonyKind::Triadic, neutrals[0], Variant::Dark);
+        let accents = generate_accents(
+            base_hsl,
+            HarmonyKind::Triadic,
+            neutrals[0],
+            Variant::Dark,
+            false,
+            AccentTargets::default(),
+        );
 
         for accent in accents {
             let ratio = contrast_ratio(neutrals[0], accent);
@@ -383,10 +1010,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deep_dark_neutrals_keep_body_text_readable() {
+        let neutrals = generate_neutrals(Variant::Dark, 1.0, None, None);
+        let body = contrast_ratio(neutrals[0], neutrals[5]);
+        assert!(body > BODY_TEXT_CONTRAST, "base05 contrast {body}");
+        assert!(contrast_ratio(neutrals[0], neutrals[4]) >= MIN_CONTRAST);
+        assert!(neutral_ramp_breaks(&neutrals, Variant::Dark).is_empty());
+    }
+
     #[test]
     fn neutral_depth_controls_darkness() {
-        let shallow = generate_neutrals(Variant::Dark, 0.0);
-        let deep = generate_neutrals(Variant::Dark, 1.0);
+        let shallow = generate_neutrals(Variant::Dark, 0.0, None, None);
+        let deep = generate_neutrals(Variant::Dark, 1.0, None, None);
         let shallow_l: Hsl = Rgb::from(shallow[0]).into();
         let deep_l: Hsl = Rgb::from(deep[0]).into();
         assert!(
@@ -397,15 +1033,118 @@ mod tests {
         );
     }
 
+    #[test]
+    fn neutral_hue_tints_neutrals() {
+        for variant in [Variant::Dark, Variant::Light] {
+            let config = Base16Config {
+                name: "Warm".to_string(),
+                variant,
+                accent_color: Srgb8::new(97, 175, 239),
+                harmony: HarmonyKind::Triadic,
+                neutral_hue: Some(30.0),
+                ..Default::default()
+            };
+            let scheme = generate_base16_scheme(config);
+            let mut tinted = 0;
+            for (i, &neutral) in scheme.colors()[..8].iter().enumerate() {
+                // Near-white/black slots round to channels a step or two apart, where the hue is just noise.
+                let spread = neutral.r.max(neutral.g).max(neutral.b) - neutral.r.min(neutral.g).min(neutral.b);
+                if spread <= 2 {
+                    continue;
+                }
+                let hsl = Hsl::from(Rgb::from(neutral));
+                assert!(
+                    hue_distance(hsl.h, 30.0) < 15.0,
+                    "{variant:?} base{i:02X} hue {}",
+                    hsl.h
+                );
+                tinted += 1;
+            }
+            assert!(tinted >= 5, "{variant:?} only {tinted} neutrals carry the tint");
+        }
+    }
+
+    #[test]
+    fn neutral_hue_range_drifts_from_base00_to_base07() {
+        // Mid depth keeps both ends of the dark ramp far enough from black and white for the hue to survive rounding.
+        let neutrals = generate_neutrals(Variant::Dark, 0.5, None, Some((30.0, 200.0)));
+        let hues: Vec<f32> = neutrals.iter().map(|&n| Hsl::from(Rgb::from(n)).h).collect();
+        assert!(hue_distance(hues[0], 30.0) < 15.0, "base00 hue {}", hues[0]);
+        assert!(hue_distance(hues[7], 200.0) < 15.0, "base07 hue {}", hues[7]);
+        assert!(
+            hue_distance(hues[4], 30.0) > 30.0,
+            "base04 hue {} should have drifted",
+            hues[4]
+        );
+    }
+
+    #[test]
+    fn accent_saturation_override_raises_saturation_at_same_hues() {
+        let config = |accent_saturation| Base16Config {
+            name: "Punchy".to_string(),
+            variant: Variant::Dark,
+            accent_color: Srgb8::new(97, 175, 239),
+            harmony: HarmonyKind::Triadic,
+            accent_saturation,
+            ..Default::default()
+        };
+        let default = generate_base16_scheme(config(None));
+        let punchy = generate_base16_scheme(config(Some(0.95)));
+
+        for slot in 0x08..0x0F {
+            let before = Hsl::from(Rgb::from(default.colors()[slot]));
+            let after = Hsl::from(Rgb::from(punchy.colors()[slot]));
+            assert!(
+                after.s > before.s,
+                "base{slot:02X} saturation {} -> {}",
+                before.s,
+                after.s
+            );
+            assert!(
+                hue_distance(after.h, before.h) < 3.0,
+                "base{slot:02X} hue {} -> {}",
+                before.h,
+                after.h
+            );
+            assert!(contrast_ratio(punchy.colors()[0], punchy.colors()[slot]) >= MIN_CONTRAST);
+        }
+    }
+
+    #[test]
+    fn seed_accents_land_in_nearest_role_slots() {
+        let config = Base16Config {
+            name: "Brand".to_string(),
+            variant: Variant::Dark,
+            accent_color: Srgb8::new(0, 0, 0),
+            harmony: HarmonyKind::Triadic,
+            ..Default::default()
+        };
+        let red = Srgb8::new(0xff, 0x55, 0x55);
+        let blue = Srgb8::new(0x55, 0x99, 0xff);
+        let scheme = generate_base16_from_accents(config, &[blue, red]);
+        let colors = scheme.colors();
+
+        assert_eq!(colors[0x08], red, "red seed belongs to base08");
+        assert_eq!(colors[0x0D], blue, "blue seed belongs to base0D");
+        for slot in 0x08..0x10 {
+            assert!(
+                contrast_ratio(colors[0], colors[slot]) >= MIN_CONTRAST,
+                "base{slot:02X}"
+            );
+        }
+        let green = Hsl::from(Rgb::from(colors[0x0B])).h;
+        assert!(hue_distance(green, ACCENT_TARGET_HUES[3]) < 45.0, "base0B hue {green}");
+    }
+
     #[test]
     fn neutral_depth_extreme_matches_expected_hexes() {
         let config_deep = Base16Config {
             name: "Depth Test".into(),
-            author: None,
             variant: Variant::Dark,
             accent_color: Srgb8::new(97, 175, 239),
             harmony: HarmonyKind::Triadic,
             neutral_depth: 1.0,
+            ..Default::default()
         };
         let scheme = generate_base16_scheme(config_deep.clone());
         assert_eq!(scheme.colors()[0], Srgb8::new(0x16, 0x16, 0x16));
@@ -416,4 +1155,93 @@ mod tests {
         let scheme_light = generate_base16_scheme(config_light);
         assert_eq!(scheme_light.colors()[0], Srgb8::new(0x4d, 0x4f, 0x53));
     }
+
+    #[test]
+    fn variant_infer_prefers_metadata_then_base00_lightness() {
+        let white = Srgb8::new(0xff, 0xff, 0xff);
+        let black = Srgb8::new(0, 0, 0);
+        assert_eq!(Variant::infer(Some("dark"), white), Variant::Dark);
+        assert_eq!(Variant::infer(Some("light"), black), Variant::Light);
+        assert_eq!(Variant::infer(None, white), Variant::Light);
+        assert_eq!(Variant::infer(Some("other"), black), Variant::Dark);
+
+        // #767676 sits just under L* 50 and #777777 just over it.
+        assert!(Lab::from(Srgb8::new(0x76, 0x76, 0x76)).l < 50.0);
+        assert_eq!(Variant::infer(None, Srgb8::new(0x76, 0x76, 0x76)), Variant::Dark);
+        assert!(Lab::from(Srgb8::new(0x77, 0x77, 0x77)).l >= 50.0);
+        assert_eq!(Variant::infer(None, Srgb8::new(0x77, 0x77, 0x77)), Variant::Light);
+    }
+
+    #[test]
+    fn neutral_ramp_breaks_detects_scrambled_ramp() {
+        let ramp: Vec<Srgb8> = [0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80]
+            .map(|v| Srgb8::new(v, v, v))
+            .to_vec();
+        assert!(neutral_ramp_breaks(&ramp, Variant::Dark).is_empty());
+        assert_eq!(neutral_ramp_breaks(&ramp, Variant::Light).len(), 7);
+
+        let mut scrambled = ramp.clone();
+        scrambled.swap(1, 2);
+        assert_eq!(neutral_ramp_breaks(&scrambled, Variant::Dark), vec![1]);
+
+        let reversed: Vec<Srgb8> = ramp.iter().rev().copied().collect();
+        assert!(neutral_ramp_breaks(&reversed, Variant::Light).is_empty());
+    }
+
+    #[test]
+    fn base24_bright_slots_follow_spec_hues() {
+        let config = Base24Config {
+            name: "Spec".to_string(),
+            variant: Variant::Dark,
+            accent_color: Srgb8::new(255, 0, 0),
+            harmony: HarmonyKind::Triadic,
+            ..Default::default()
+        };
+        let scheme = generate_base24_scheme(config);
+        let hue = |i: usize| Hsl::from(Rgb::from(scheme.colors()[i])).h;
+
+        assert!(hue_distance(hue(0x12), 0.0) < 15.0, "base12 hue {}", hue(0x12));
+        assert!(hue_distance(hue(0x13), 60.0) < 15.0, "base13 hue {}", hue(0x13));
+        assert!(hue_distance(hue(0x14), 120.0) < 15.0, "base14 hue {}", hue(0x14));
+        assert!(hue_distance(hue(0x17), hue(0x0E)) < 5.0, "base17 hue {}", hue(0x17));
+    }
+
+    #[test]
+    fn generated_accents_stay_in_their_role_bands() {
+        for variant in [Variant::Dark, Variant::Light] {
+            for use_oklch in [false, true] {
+                for step in 0..720 {
+                    let accent_color = Srgb8::from(Hsl::new(step as f32 * 0.5, 0.6, 0.5));
+                    let scheme =
+                        generate_base16_scheme(Base16Config { variant, accent_color, use_oklch, ..Default::default() });
+                    for band in &ROLE_HUE_BANDS {
+                        let color = scheme.colors()[band.slot];
+                        let context = format!("{variant:?} oklch={use_oklch} {accent_color} base{:02X}", band.slot);
+                        assert_eq!(band.deviation(color), None, "{context}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn indistinct_accents_flags_near_duplicates() {
+        let mut accents = [
+            Srgb8::new(0xe0, 0x6c, 0x75),
+            Srgb8::new(0xd1, 0x9a, 0x66),
+            Srgb8::new(0xe5, 0xc0, 0x7b),
+            Srgb8::new(0x98, 0xc3, 0x79),
+            Srgb8::new(0x56, 0xb6, 0xc2),
+            Srgb8::new(0x61, 0xaf, 0xef),
+            Srgb8::new(0xc6, 0x78, 0xdd),
+            Srgb8::new(0xbe, 0x50, 0x46),
+        ];
+        assert!(indistinct_accents(&accents, DEFAULT_ACCENT_DELTA_E).is_empty());
+
+        accents[6] = Srgb8::new(0x62, 0xaf, 0xee);
+        let pairs = indistinct_accents(&accents, DEFAULT_ACCENT_DELTA_E);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].0, pairs[0].1), (5, 6));
+        assert!(pairs[0].2 < 1.0);
+    }
 }
@@ -0,0 +1,138 @@
+//! Layer blending modes for compositing palette colors.
+//!
+//! Implements the separable blend modes from the W3C Compositing and Blending specification.
+//! Each function takes a `base` (backdrop) and a `layer` (source) color and operates per channel in linear RGB space.
+
+use crate::colors::Rgb;
+
+/// Multiplies the base and layer channels.
+///
+/// The result is always at least as dark as either input.
+/// Multiplying with white leaves the other color unchanged; multiplying with black produces black.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::blend::multiply;
+/// use colorizer::colors::Rgb;
+///
+/// let base = Rgb::new(0.8, 0.4, 0.2);
+/// let dimmed = multiply(base, Rgb::new(0.5, 0.5, 0.5));
+/// assert!((dimmed.r - 0.4).abs() < 1e-6);
+/// ```
+pub fn multiply(base: Rgb, layer: Rgb) -> Rgb {
+    per_channel(base, layer, |a, b| a * b)
+}
+
+/// Screens the base and layer channels (inverse of multiplying the inverses).
+///
+/// The result is always at least as light as either input.
+/// Screening with black leaves the other color unchanged; screening with white produces white.
+pub fn screen(base: Rgb, layer: Rgb) -> Rgb {
+    per_channel(base, layer, screen_channel)
+}
+
+/// Multiplies or screens depending on the base channel, preserving the base's highlights and shadows.
+///
+/// Equivalent to [`hard_light`] with the inputs swapped.
+pub fn overlay(base: Rgb, layer: Rgb) -> Rgb {
+    per_channel(base, layer, |a, b| hard_light_channel(b, a))
+}
+
+/// Multiplies or screens depending on the layer channel, like shining a harsh spotlight on the base.
+pub fn hard_light(base: Rgb, layer: Rgb) -> Rgb {
+    per_channel(base, layer, hard_light_channel)
+}
+
+/// Darkens or lightens depending on the layer channel, like shining a diffused spotlight on the base.
+///
+/// Uses the W3C soft-light formula, which avoids the discontinuities of older Photoshop variants.
+pub fn soft_light(base: Rgb, layer: Rgb) -> Rgb {
+    per_channel(base, layer, |a, b| {
+        if b <= 0.5 {
+            a - (1.0 - 2.0 * b) * a * (1.0 - a)
+        } else {
+            let d = if a <= 0.25 { ((16.0 * a - 12.0) * a + 4.0) * a } else { a.sqrt() };
+            a + (2.0 * b - 1.0) * (d - a)
+        }
+    })
+}
+
+/// Subtracts the darker channel from the lighter one.
+///
+/// Blending with black leaves the other color unchanged; blending with white inverts it.
+pub fn difference(base: Rgb, layer: Rgb) -> Rgb {
+    per_channel(base, layer, |a, b| (a - b).abs())
+}
+
+fn screen_channel(a: f32, b: f32) -> f32 {
+    a + b - a * b
+}
+
+fn hard_light_channel(a: f32, b: f32) -> f32 {
+    if b <= 0.5 { a * 2.0 * b } else { screen_channel(a, 2.0 * b - 1.0) }
+}
+
+fn per_channel(base: Rgb, layer: Rgb, f: impl Fn(f32, f32) -> f32) -> Rgb {
+    Rgb::new(f(base.r, layer.r), f(base.g, layer.g), f(base.b, layer.b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.001;
+
+    fn approx_eq_rgb(a: Rgb, b: Rgb) -> bool {
+        (a.r - b.r).abs() < EPSILON && (a.g - b.g).abs() < EPSILON && (a.b - b.b).abs() < EPSILON
+    }
+
+    const WHITE: Rgb = Rgb { r: 1.0, g: 1.0, b: 1.0 };
+    const BLACK: Rgb = Rgb { r: 0.0, g: 0.0, b: 0.0 };
+    const COLOR: Rgb = Rgb { r: 0.8, g: 0.35, b: 0.1 };
+
+    #[test]
+    fn test_multiply_identity_and_zero() {
+        assert!(approx_eq_rgb(multiply(COLOR, WHITE), COLOR));
+        assert!(approx_eq_rgb(multiply(WHITE, COLOR), COLOR));
+        assert!(approx_eq_rgb(multiply(COLOR, BLACK), BLACK));
+    }
+
+    #[test]
+    fn test_screen_identity_and_one() {
+        assert!(approx_eq_rgb(screen(COLOR, BLACK), COLOR));
+        assert!(approx_eq_rgb(screen(BLACK, COLOR), COLOR));
+        assert!(approx_eq_rgb(screen(COLOR, WHITE), WHITE));
+    }
+
+    #[test]
+    fn test_overlay_and_hard_light_are_swapped() {
+        let layer = Rgb::new(0.3, 0.6, 0.9);
+        assert!(approx_eq_rgb(overlay(COLOR, layer), hard_light(layer, COLOR)));
+    }
+
+    #[test]
+    fn test_hard_light_known_values() {
+        let gray = Rgb::new(0.5, 0.5, 0.5);
+        assert!(approx_eq_rgb(hard_light(COLOR, gray), COLOR));
+        assert!(approx_eq_rgb(hard_light(COLOR, BLACK), BLACK));
+        assert!(approx_eq_rgb(hard_light(COLOR, WHITE), WHITE));
+    }
+
+    #[test]
+    fn test_soft_light_neutral_gray_is_identity() {
+        let gray = Rgb::new(0.5, 0.5, 0.5);
+        assert!(approx_eq_rgb(soft_light(COLOR, gray), COLOR));
+
+        let darker = soft_light(COLOR, BLACK);
+        let lighter = soft_light(COLOR, WHITE);
+        assert!(darker.g < COLOR.g && lighter.g > COLOR.g);
+    }
+
+    #[test]
+    fn test_difference_known_values() {
+        assert!(approx_eq_rgb(difference(COLOR, BLACK), COLOR));
+        assert!(approx_eq_rgb(difference(COLOR, WHITE), Rgb::new(0.2, 0.65, 0.9)));
+        assert!(approx_eq_rgb(difference(COLOR, COLOR), BLACK));
+    }
+}
@@ -1,29 +1,49 @@
 //! Core color type definitions and helper utilities.
 //!
 //! Provides fundamental color representations used throughout the colorizer library:
-//! - sRGB (8-bit and float)
+//! - sRGB (8-bit, 16-bit, and float)
 //! - Linear RGB
 //! - HSL and HSV (cylindrical color spaces)
 //! - CIE Lab and Lch (perceptually uniform spaces)
+//! - HSLuv (perceptually uniform HSL)
+//! - OKLab and OKLCH (perceptually uniform spaces with better hue linearity than CIE Lab)
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 /// Linear RGB color with components in [0, 1] range.
 ///
 /// This represents color in linear light space, commonly used for physically-based rendering and blending operations.
 /// Each component represents the actual light intensity without gamma correction.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Rgb {
     pub r: f32,
     pub g: f32,
     pub b: f32,
 }
 
+/// Tolerance used by [`Rgb::in_gamut`] to absorb floating-point round-off from matrix conversions.
+const GAMUT_EPSILON: f32 = 1e-4;
+
 impl Rgb {
     /// Creates a new linear RGB color, clamping components to [0, 1].
     pub fn new(r: f32, g: f32, b: f32) -> Self {
         Self { r: clamp01(r), g: clamp01(g), b: clamp01(b) }
     }
+
+    /// Creates a new linear RGB color without clamping, so out-of-gamut components stay visible.
+    ///
+    /// The XYZ and Lab conversions use this form; check [`Rgb::in_gamut`] before treating the result as displayable.
+    pub fn new_unclamped(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Returns true when every component lies in [0, 1], allowing for conversion round-off.
+    pub fn in_gamut(&self) -> bool {
+        [self.r, self.g, self.b]
+            .iter()
+            .all(|&c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(&c))
+    }
 }
 
 /// sRGB color with float components in [0, 1] range (gamma-corrected).
@@ -63,16 +83,27 @@ impl Srgb8 {
 
     /// Parses a hex color string in format "#RRGGBB" or "RRGGBB".
     pub fn from_hex(hex: &str) -> Option<Self> {
+        Self::try_from_hex(hex).ok()
+    }
+
+    /// Parses a hex color string in format "#RRGGBB" or "RRGGBB", reporting why it was rejected.
+    ///
+    /// Only the six-digit form used by scheme files is accepted; [`Color::try_parse`] also expands CSS short hex.
+    pub fn try_from_hex(hex: &str) -> Result<Self, ParseColorError> {
         let hex = hex.strip_prefix('#').unwrap_or(hex);
-        if hex.len() != 6 {
-            return None;
+        if hex.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+        let digits = hex.chars().count();
+        if digits != 6 {
+            return Err(ParseColorError::BadLength(digits));
+        }
+        if let Some(bad) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ParseColorError::BadDigit(bad));
         }
 
-        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-
-        Some(Self::new(r, g, b))
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).expect("validated hex digits");
+        Ok(Self::new(channel(0), channel(2), channel(4)))
     }
 
     /// Converts to hex string format "#RRGGBB".
@@ -81,19 +112,353 @@ impl Srgb8 {
     }
 }
 
+/// Serializes as a `#rrggbb` hex string, the form used in scheme files and palette exports.
+impl Serialize for Srgb8 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+/// Deserializes from a hex string, with or without the leading `#`.
+impl<'de> Deserialize<'de> for Srgb8 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Srgb8::try_from_hex(&hex).map_err(serde::de::Error::custom)
+    }
+}
+
 impl fmt::Display for Srgb8 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.to_hex())
     }
 }
 
+/// Builds a color from `[r, g, b]`, the channel layout of `image::Rgb<u8>`.
+impl From<[u8; 3]> for Srgb8 {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<(u8, u8, u8)> for Srgb8 {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<Srgb8> for [u8; 3] {
+    fn from(c: Srgb8) -> Self {
+        [c.r, c.g, c.b]
+    }
+}
+
+impl From<Srgb8> for (u8, u8, u8) {
+    fn from(c: Srgb8) -> Self {
+        (c.r, c.g, c.b)
+    }
+}
+
+/// sRGB color with 16-bit components in [0, 65535] range.
+///
+/// Same gamma-encoded space as [`Srgb8`] with 256 times finer steps, for exports where 8-bit banding shows, such
+/// as large, smooth gradients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Srgb16 {
+    pub r: u16,
+    pub g: u16,
+    pub b: u16,
+}
+
+impl Srgb16 {
+    /// Creates a new 16-bit sRGB color.
+    pub const fn new(r: u16, g: u16, b: u16) -> Self {
+        Self { r, g, b }
+    }
+}
+
+/// Splits into `[r, g, b]`, the channel layout of `image::Rgb<u16>`.
+impl From<Srgb16> for [u16; 3] {
+    fn from(c: Srgb16) -> Self {
+        [c.r, c.g, c.b]
+    }
+}
+
+/// Why a color string could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// Nothing but whitespace (or a lone `#`).
+    Empty,
+    /// A hex color without exactly six digits; holds the digit count found.
+    BadLength(usize),
+    /// A character that is not a hex digit.
+    BadDigit(char),
+    /// A word that is not a CSS color name.
+    UnknownName(String),
+    /// An `rgb()`/`hsl()`-style function with an unknown name or malformed arguments.
+    BadFunction(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::Empty => write!(f, "color value is empty"),
+            ParseColorError::BadLength(digits) => write!(f, "expected 6 hex digits, got {digits}"),
+            ParseColorError::BadDigit(digit) => write!(f, "'{digit}' is not a hex digit"),
+            ParseColorError::UnknownName(name) => write!(f, "unknown color name '{name}'"),
+            ParseColorError::BadFunction(value) => write!(f, "malformed color function '{value}'"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// CSS named colors (CSS Color Module Level 4), sorted by name for binary search.
+pub(crate) const CSS_NAMED_COLORS: [(&str, Srgb8); 148] = [
+    ("aliceblue", Srgb8::new(240, 248, 255)),
+    ("antiquewhite", Srgb8::new(250, 235, 215)),
+    ("aqua", Srgb8::new(0, 255, 255)),
+    ("aquamarine", Srgb8::new(127, 255, 212)),
+    ("azure", Srgb8::new(240, 255, 255)),
+    ("beige", Srgb8::new(245, 245, 220)),
+    ("bisque", Srgb8::new(255, 228, 196)),
+    ("black", Srgb8::new(0, 0, 0)),
+    ("blanchedalmond", Srgb8::new(255, 235, 205)),
+    ("blue", Srgb8::new(0, 0, 255)),
+    ("blueviolet", Srgb8::new(138, 43, 226)),
+    ("brown", Srgb8::new(165, 42, 42)),
+    ("burlywood", Srgb8::new(222, 184, 135)),
+    ("cadetblue", Srgb8::new(95, 158, 160)),
+    ("chartreuse", Srgb8::new(127, 255, 0)),
+    ("chocolate", Srgb8::new(210, 105, 30)),
+    ("coral", Srgb8::new(255, 127, 80)),
+    ("cornflowerblue", Srgb8::new(100, 149, 237)),
+    ("cornsilk", Srgb8::new(255, 248, 220)),
+    ("crimson", Srgb8::new(220, 20, 60)),
+    ("cyan", Srgb8::new(0, 255, 255)),
+    ("darkblue", Srgb8::new(0, 0, 139)),
+    ("darkcyan", Srgb8::new(0, 139, 139)),
+    ("darkgoldenrod", Srgb8::new(184, 134, 11)),
+    ("darkgray", Srgb8::new(169, 169, 169)),
+    ("darkgreen", Srgb8::new(0, 100, 0)),
+    ("darkgrey", Srgb8::new(169, 169, 169)),
+    ("darkkhaki", Srgb8::new(189, 183, 107)),
+    ("darkmagenta", Srgb8::new(139, 0, 139)),
+    ("darkolivegreen", Srgb8::new(85, 107, 47)),
+    ("darkorange", Srgb8::new(255, 140, 0)),
+    ("darkorchid", Srgb8::new(153, 50, 204)),
+    ("darkred", Srgb8::new(139, 0, 0)),
+    ("darksalmon", Srgb8::new(233, 150, 122)),
+    ("darkseagreen", Srgb8::new(143, 188, 143)),
+    ("darkslateblue", Srgb8::new(72, 61, 139)),
+    ("darkslategray", Srgb8::new(47, 79, 79)),
+    ("darkslategrey", Srgb8::new(47, 79, 79)),
+    ("darkturquoise", Srgb8::new(0, 206, 209)),
+    ("darkviolet", Srgb8::new(148, 0, 211)),
+    ("deeppink", Srgb8::new(255, 20, 147)),
+    ("deepskyblue", Srgb8::new(0, 191, 255)),
+    ("dimgray", Srgb8::new(105, 105, 105)),
+    ("dimgrey", Srgb8::new(105, 105, 105)),
+    ("dodgerblue", Srgb8::new(30, 144, 255)),
+    ("firebrick", Srgb8::new(178, 34, 34)),
+    ("floralwhite", Srgb8::new(255, 250, 240)),
+    ("forestgreen", Srgb8::new(34, 139, 34)),
+    ("fuchsia", Srgb8::new(255, 0, 255)),
+    ("gainsboro", Srgb8::new(220, 220, 220)),
+    ("ghostwhite", Srgb8::new(248, 248, 255)),
+    ("gold", Srgb8::new(255, 215, 0)),
+    ("goldenrod", Srgb8::new(218, 165, 32)),
+    ("gray", Srgb8::new(128, 128, 128)),
+    ("green", Srgb8::new(0, 128, 0)),
+    ("greenyellow", Srgb8::new(173, 255, 47)),
+    ("grey", Srgb8::new(128, 128, 128)),
+    ("honeydew", Srgb8::new(240, 255, 240)),
+    ("hotpink", Srgb8::new(255, 105, 180)),
+    ("indianred", Srgb8::new(205, 92, 92)),
+    ("indigo", Srgb8::new(75, 0, 130)),
+    ("ivory", Srgb8::new(255, 255, 240)),
+    ("khaki", Srgb8::new(240, 230, 140)),
+    ("lavender", Srgb8::new(230, 230, 250)),
+    ("lavenderblush", Srgb8::new(255, 240, 245)),
+    ("lawngreen", Srgb8::new(124, 252, 0)),
+    ("lemonchiffon", Srgb8::new(255, 250, 205)),
+    ("lightblue", Srgb8::new(173, 216, 230)),
+    ("lightcoral", Srgb8::new(240, 128, 128)),
+    ("lightcyan", Srgb8::new(224, 255, 255)),
+    ("lightgoldenrodyellow", Srgb8::new(250, 250, 210)),
+    ("lightgray", Srgb8::new(211, 211, 211)),
+    ("lightgreen", Srgb8::new(144, 238, 144)),
+    ("lightgrey", Srgb8::new(211, 211, 211)),
+    ("lightpink", Srgb8::new(255, 182, 193)),
+    ("lightsalmon", Srgb8::new(255, 160, 122)),
+    ("lightseagreen", Srgb8::new(32, 178, 170)),
+    ("lightskyblue", Srgb8::new(135, 206, 250)),
+    ("lightslategray", Srgb8::new(119, 136, 153)),
+    ("lightslategrey", Srgb8::new(119, 136, 153)),
+    ("lightsteelblue", Srgb8::new(176, 196, 222)),
+    ("lightyellow", Srgb8::new(255, 255, 224)),
+    ("lime", Srgb8::new(0, 255, 0)),
+    ("limegreen", Srgb8::new(50, 205, 50)),
+    ("linen", Srgb8::new(250, 240, 230)),
+    ("magenta", Srgb8::new(255, 0, 255)),
+    ("maroon", Srgb8::new(128, 0, 0)),
+    ("mediumaquamarine", Srgb8::new(102, 205, 170)),
+    ("mediumblue", Srgb8::new(0, 0, 205)),
+    ("mediumorchid", Srgb8::new(186, 85, 211)),
+    ("mediumpurple", Srgb8::new(147, 112, 219)),
+    ("mediumseagreen", Srgb8::new(60, 179, 113)),
+    ("mediumslateblue", Srgb8::new(123, 104, 238)),
+    ("mediumspringgreen", Srgb8::new(0, 250, 154)),
+    ("mediumturquoise", Srgb8::new(72, 209, 204)),
+    ("mediumvioletred", Srgb8::new(199, 21, 133)),
+    ("midnightblue", Srgb8::new(25, 25, 112)),
+    ("mintcream", Srgb8::new(245, 255, 250)),
+    ("mistyrose", Srgb8::new(255, 228, 225)),
+    ("moccasin", Srgb8::new(255, 228, 181)),
+    ("navajowhite", Srgb8::new(255, 222, 173)),
+    ("navy", Srgb8::new(0, 0, 128)),
+    ("oldlace", Srgb8::new(253, 245, 230)),
+    ("olive", Srgb8::new(128, 128, 0)),
+    ("olivedrab", Srgb8::new(107, 142, 35)),
+    ("orange", Srgb8::new(255, 165, 0)),
+    ("orangered", Srgb8::new(255, 69, 0)),
+    ("orchid", Srgb8::new(218, 112, 214)),
+    ("palegoldenrod", Srgb8::new(238, 232, 170)),
+    ("palegreen", Srgb8::new(152, 251, 152)),
+    ("paleturquoise", Srgb8::new(175, 238, 238)),
+    ("palevioletred", Srgb8::new(219, 112, 147)),
+    ("papayawhip", Srgb8::new(255, 239, 213)),
+    ("peachpuff", Srgb8::new(255, 218, 185)),
+    ("peru", Srgb8::new(205, 133, 63)),
+    ("pink", Srgb8::new(255, 192, 203)),
+    ("plum", Srgb8::new(221, 160, 221)),
+    ("powderblue", Srgb8::new(176, 224, 230)),
+    ("purple", Srgb8::new(128, 0, 128)),
+    ("rebeccapurple", Srgb8::new(102, 51, 153)),
+    ("red", Srgb8::new(255, 0, 0)),
+    ("rosybrown", Srgb8::new(188, 143, 143)),
+    ("royalblue", Srgb8::new(65, 105, 225)),
+    ("saddlebrown", Srgb8::new(139, 69, 19)),
+    ("salmon", Srgb8::new(250, 128, 114)),
+    ("sandybrown", Srgb8::new(244, 164, 96)),
+    ("seagreen", Srgb8::new(46, 139, 87)),
+    ("seashell", Srgb8::new(255, 245, 238)),
+    ("sienna", Srgb8::new(160, 82, 45)),
+    ("silver", Srgb8::new(192, 192, 192)),
+    ("skyblue", Srgb8::new(135, 206, 235)),
+    ("slateblue", Srgb8::new(106, 90, 205)),
+    ("slategray", Srgb8::new(112, 128, 144)),
+    ("slategrey", Srgb8::new(112, 128, 144)),
+    ("snow", Srgb8::new(255, 250, 250)),
+    ("springgreen", Srgb8::new(0, 255, 127)),
+    ("steelblue", Srgb8::new(70, 130, 180)),
+    ("tan", Srgb8::new(210, 180, 140)),
+    ("teal", Srgb8::new(0, 128, 128)),
+    ("thistle", Srgb8::new(216, 191, 216)),
+    ("tomato", Srgb8::new(255, 99, 71)),
+    ("turquoise", Srgb8::new(64, 224, 208)),
+    ("violet", Srgb8::new(238, 130, 238)),
+    ("wheat", Srgb8::new(245, 222, 179)),
+    ("white", Srgb8::new(255, 255, 255)),
+    ("whitesmoke", Srgb8::new(245, 245, 245)),
+    ("yellow", Srgb8::new(255, 255, 0)),
+    ("yellowgreen", Srgb8::new(154, 205, 50)),
+];
+
+/// Parses a CSS color value into an 8-bit sRGB color.
+///
+/// Recognizes:
+/// - hex in the `#RRGGBB` form accepted by [`Srgb8::from_hex`], plus the CSS short `#RGB`/`#RGBA` and `#RRGGBBAA`
+///   forms when written with the `#`
+/// - named colors such as `rebeccapurple`
+/// - `rgb()`/`rgba()` with 0-255 or percentage channels
+/// - `hsl()`/`hsla()` with hue in degrees and percentage saturation/lightness
+///
+/// Function arguments may be separated by commas or whitespace, with an optional `/ alpha`. Alpha, in functions or
+/// hex, is accepted but discarded. Matching is case-insensitive; returns `None` for anything else.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::{Srgb8, parse_css_color};
+///
+/// assert_eq!(parse_css_color("rgb(255, 128, 0)"), Some(Srgb8::new(255, 128, 0)));
+/// assert_eq!(parse_css_color("hsl(120 100% 25%)"), Some(Srgb8::new(0, 128, 0)));
+/// assert_eq!(parse_css_color("tomato"), Some(Srgb8::new(255, 99, 71)));
+/// assert_eq!(parse_css_color("#f80"), Some(Srgb8::new(255, 136, 0)));
+/// assert_eq!(parse_css_color("rgb(1, 2)"), None);
+/// ```
+pub fn parse_css_color(value: &str) -> Option<Srgb8> {
+    Color::parse(value).map(|color| color.to_srgb8())
+}
+
+/// Rewrites `#rgb`, `#rgba`, and `#rrggbbaa` as `rrggbb`, dropping alpha; anything else is returned as-is for
+/// [`Srgb8::try_from_hex`] to accept or reject. Short and alpha forms need the `#`, so words like "bed" stay names.
+fn expand_css_hex(value: &str) -> Result<String, ParseColorError> {
+    let Some(hex) = value.strip_prefix('#') else {
+        return Ok(value.to_string());
+    };
+    let digits: Vec<char> = hex.chars().collect();
+    if !matches!(digits.len(), 3 | 4 | 8) {
+        return Ok(hex.to_string());
+    }
+    if let Some(&bad) = digits.iter().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(ParseColorError::BadDigit(bad));
+    }
+    Ok(match digits.len() {
+        8 => digits[..6].iter().collect(),
+        _ => digits[..3].iter().flat_map(|&digit| [digit, digit]).collect(),
+    })
+}
+
+/// Parses an `rgb()`/`rgba()`/`hsl()`/`hsla()` function; `value` must already be trimmed and lowercase.
+fn parse_css_function(value: &str) -> Option<Color> {
+    let (name, args) = value.strip_suffix(')')?.split_once('(')?;
+    let args: Vec<&str> = args
+        .split([',', '/', ' ', '\t'])
+        .filter(|arg| !arg.is_empty())
+        .collect();
+    let (channels, alpha) = match args.len() {
+        3 => (&args[..], None),
+        4 => (&args[..3], Some(args[3])),
+        _ => return None,
+    };
+    if let Some(alpha) = alpha {
+        parse_css_number(alpha.strip_suffix('%').unwrap_or(alpha))?;
+    }
+
+    match name.trim_end() {
+        "rgb" | "rgba" => {
+            let mut rgb = [0; 3];
+            for (component, arg) in rgb.iter_mut().zip(channels) {
+                let channel = match arg.strip_suffix('%') {
+                    Some(percent) => parse_css_number(percent)? / 100.0 * 255.0,
+                    None => parse_css_number(arg)?,
+                };
+                *component = channel.round().clamp(0.0, 255.0) as u8;
+            }
+            Some(Color::Srgb8(Srgb8::new(rgb[0], rgb[1], rgb[2])))
+        }
+        "hsl" | "hsla" => {
+            let h = parse_css_number(channels[0].strip_suffix("deg").unwrap_or(channels[0]))?;
+            let s = parse_css_number(channels[1].strip_suffix('%').unwrap_or(channels[1]))? / 100.0;
+            let l = parse_css_number(channels[2].strip_suffix('%').unwrap_or(channels[2]))? / 100.0;
+            Some(Color::Hsl(Hsl::new(h, s, l)))
+        }
+        _ => None,
+    }
+}
+
+fn parse_css_number(value: &str) -> Option<f32> {
+    value.parse::<f32>().ok().filter(|number| number.is_finite())
+}
+
 /// HSL (Hue, Saturation, Lightness) color representation.
 ///
 /// Cylindrical color space where:
 /// - `h` is hue in degrees [0, 360)
 /// - `s` is saturation in [0, 1] (0 = gray, 1 = full color)
 /// - `l` is lightness in [0, 1] (0 = black, 0.5 = pure color, 1 = white)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Hsl {
     pub h: f32,
     pub s: f32,
@@ -115,7 +480,7 @@ impl Hsl {
 /// - `h` is hue in degrees [0, 360)
 /// - `s` is saturation in [0, 1] (0 = white, 1 = full color)
 /// - `v` is value/brightness in [0, 1] (0 = black, 1 = full brightness)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Hsv {
     pub h: f32,
     pub s: f32,
@@ -139,7 +504,7 @@ impl Hsv {
 /// - `b` is blue-yellow axis (negative = blue, positive = yellow)
 ///
 /// Lab is perceptually uniform, meaning equal distances in Lab space correspond to roughly equal perceived color differences.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Lab {
     pub l: f32,
     pub a: f32,
@@ -165,7 +530,7 @@ impl Lab {
 ///
 /// Lch is useful for operations like hue rotation while maintaining perceptual uniformity.
 /// Chroma represents colorfulness independent of lightness.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Lch {
     pub l: f32,
     pub c: f32,
@@ -182,6 +547,72 @@ impl Lch {
     }
 }
 
+/// OKLab color representation.
+///
+/// Björn Ottosson's perceptual space, built directly on linear RGB:
+/// - `l` is lightness [0, 1] (0 = black, 1 = white)
+/// - `a` is green-red axis, `b` is blue-yellow axis (both roughly within ±0.4 for sRGB colors)
+///
+/// Predicts perceived lightness and hue more evenly than CIE Lab, especially for blues.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Oklab {
+    /// Creates a new OKLab color. No clamping is performed.
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+}
+
+/// OKLCH color representation (cylindrical OKLab).
+///
+/// - `l` is lightness [0, 1] (same as OKLab)
+/// - `c` is chroma [0, ∞), roughly up to 0.37 inside sRGB
+/// - `h` is hue angle in degrees [0, 360)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl Oklch {
+    /// Creates a new OKLCH color with normalized hue.
+    ///
+    /// Hue is wrapped to [0, 360); L and C are not clamped.
+    pub fn new(l: f32, c: f32, h: f32) -> Self {
+        Self { l, c, h: wrap_degrees(h) }
+    }
+}
+
+/// HSLuv color representation (perceptually uniform HSL).
+///
+/// Cylindrical space built on CIE Luv where:
+/// - `h` is hue in degrees [0, 360)
+/// - `s` is saturation in [0, 100] as a percentage of the maximum in-gamut chroma for that hue and lightness
+/// - `l` is lightness in [0, 100] (same as CIE L*)
+///
+/// Unlike [`Hsl`], equal `l` values look equally bright across hues, and every `s` in range stays inside sRGB.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsluv {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl Hsluv {
+    /// Creates a new HSLuv color with normalized values.
+    ///
+    /// Hue is wrapped to [0, 360) and saturation/lightness are clamped to [0, 100].
+    pub fn new(h: f32, s: f32, l: f32) -> Self {
+        Self { h: wrap_degrees(h), s: s.clamp(0.0, 100.0), l: l.clamp(0.0, 100.0) }
+    }
+}
+
 /// CIE XYZ color representation (device-independent).
 ///
 /// Intermediate color space used for conversions between RGB and Lab.
@@ -203,6 +634,226 @@ impl Xyz {
     }
 }
 
+/// A color in any of the supported spaces, kept in the space it was authored in.
+///
+/// Lets mixed collections (e.g. `Vec<Color>`) defer conversion until export. [`Hsl`] and [`Hsv`] values are read
+/// the CSS way, on gamma-encoded sRGB, so they pass through float [`Srgb`] on the way to other spaces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    Rgb(Rgb),
+    Srgb(Srgb),
+    Srgb8(Srgb8),
+    Srgb16(Srgb16),
+    Hsl(Hsl),
+    Hsv(Hsv),
+    Lab(Lab),
+    Lch(Lch),
+    Oklab(Oklab),
+    Oklch(Oklch),
+    Hsluv(Hsluv),
+    Xyz(Xyz),
+}
+
+impl Color {
+    /// Parses a hex, named, or functional CSS color, keeping `hsl()`/`hsla()` values as [`Color::Hsl`].
+    ///
+    /// Everything else becomes [`Color::Srgb8`]. See [`parse_css_color`] for the accepted syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colorizer::colors::{Color, Hsl, Srgb8};
+    ///
+    /// assert_eq!(Color::parse("hsl(120 100% 25%)"), Some(Color::Hsl(Hsl::new(120.0, 1.0, 0.25))));
+    /// assert_eq!(Color::parse("#ff8000"), Some(Color::Srgb8(Srgb8::new(255, 128, 0))));
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::try_parse(value).ok()
+    }
+
+    /// Like [`Color::parse`], but reports why a value was rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colorizer::colors::{Color, ParseColorError};
+    ///
+    /// assert_eq!(Color::try_parse("#fffff"), Err(ParseColorError::BadLength(5)));
+    /// assert_eq!(Color::try_parse("blurple"), Err(ParseColorError::UnknownName("blurple".to_string())));
+    /// ```
+    pub fn try_parse(value: &str) -> Result<Self, ParseColorError> {
+        let value = value.trim().to_ascii_lowercase();
+        if value.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+        if value.ends_with(')') && value.contains('(') {
+            return parse_css_function(&value).ok_or(ParseColorError::BadFunction(value));
+        }
+        if let Ok(index) = CSS_NAMED_COLORS.binary_search_by(|(name, _)| name.cmp(&value.as_str())) {
+            return Ok(Color::Srgb8(CSS_NAMED_COLORS[index].1));
+        }
+        // Bare words are names unless they could be (mistyped) hex, like "fff" or "c0ffee".
+        if value.chars().all(|c| c.is_ascii_alphabetic()) && !value.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ParseColorError::UnknownName(value));
+        }
+
+        Srgb8::try_from_hex(&expand_css_hex(&value)?).map(Color::Srgb8)
+    }
+
+    /// Converts to 8-bit sRGB.
+    pub fn to_srgb8(&self) -> Srgb8 {
+        match *self {
+            Color::Rgb(c) => Srgb8::from(c),
+            Color::Srgb(c) => Srgb8::from(c),
+            Color::Srgb8(c) => c,
+            Color::Srgb16(c) => Srgb8::from(c),
+            Color::Hsl(c) => Srgb8::from(c),
+            Color::Hsv(c) => Srgb8::from(c),
+            Color::Lab(c) => Srgb8::from(c),
+            Color::Lch(c) => Srgb8::from(c),
+            Color::Oklab(c) => Srgb8::from(c),
+            Color::Oklch(c) => Srgb8::from(c),
+            Color::Hsluv(c) => Srgb8::from(c),
+            Color::Xyz(c) => Srgb8::from(Rgb::from(c)),
+        }
+    }
+
+    /// Converts to linear RGB. Lab-family and XYZ results are unclamped, as with their own conversions.
+    pub fn to_rgb(&self) -> Rgb {
+        match *self {
+            Color::Rgb(c) => c,
+            Color::Srgb(c) => Rgb::from(c),
+            Color::Srgb8(c) => Rgb::from(c),
+            Color::Srgb16(c) => Rgb::from(c),
+            Color::Hsl(c) => Rgb::from(Srgb::from(c)),
+            Color::Hsv(c) => Rgb::from(Srgb::from(c)),
+            Color::Lab(c) => Rgb::from(c),
+            Color::Lch(c) => Rgb::from(Lab::from(c)),
+            Color::Oklab(c) => Rgb::from(c),
+            Color::Oklch(c) => Rgb::from(Oklab::from(c)),
+            Color::Hsluv(c) => Rgb::from(c),
+            Color::Xyz(c) => Rgb::from(c),
+        }
+    }
+
+    /// Converts to HSL on gamma-encoded sRGB (the CSS interpretation).
+    pub fn to_hsl(&self) -> Hsl {
+        match *self {
+            Color::Hsl(c) => c,
+            _ => Hsl::from(self.to_srgb8()),
+        }
+    }
+
+    /// Converts to CIE Lab.
+    pub fn to_lab(&self) -> Lab {
+        match *self {
+            Color::Srgb8(c) => Lab::from(c),
+            Color::Lab(c) => c,
+            Color::Lch(c) => Lab::from(c),
+            Color::Xyz(c) => Lab::from(c),
+            _ => Lab::from(self.to_rgb()),
+        }
+    }
+
+    /// Converts to CIE Lch.
+    pub fn to_lch(&self) -> Lch {
+        match *self {
+            Color::Lch(c) => c,
+            _ => Lch::from(self.to_lab()),
+        }
+    }
+
+    /// Converts to OKLab.
+    pub fn to_oklab(&self) -> Oklab {
+        match *self {
+            Color::Srgb8(c) => Oklab::from(c),
+            Color::Oklab(c) => c,
+            Color::Oklch(c) => Oklab::from(c),
+            _ => Oklab::from(self.to_rgb()),
+        }
+    }
+
+    /// Converts to OKLCH.
+    pub fn to_oklch(&self) -> Oklch {
+        match *self {
+            Color::Oklch(c) => c,
+            _ => Oklch::from(self.to_oklab()),
+        }
+    }
+}
+
+impl From<Rgb> for Color {
+    fn from(color: Rgb) -> Self {
+        Color::Rgb(color)
+    }
+}
+
+impl From<Srgb> for Color {
+    fn from(color: Srgb) -> Self {
+        Color::Srgb(color)
+    }
+}
+
+impl From<Srgb8> for Color {
+    fn from(color: Srgb8) -> Self {
+        Color::Srgb8(color)
+    }
+}
+
+impl From<Srgb16> for Color {
+    fn from(color: Srgb16) -> Self {
+        Color::Srgb16(color)
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(color: Hsl) -> Self {
+        Color::Hsl(color)
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(color: Hsv) -> Self {
+        Color::Hsv(color)
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(color: Lab) -> Self {
+        Color::Lab(color)
+    }
+}
+
+impl From<Lch> for Color {
+    fn from(color: Lch) -> Self {
+        Color::Lch(color)
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(color: Oklab) -> Self {
+        Color::Oklab(color)
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(color: Oklch) -> Self {
+        Color::Oklch(color)
+    }
+}
+
+impl From<Hsluv> for Color {
+    fn from(color: Hsluv) -> Self {
+        Color::Hsluv(color)
+    }
+}
+
+impl From<Xyz> for Color {
+    fn from(color: Xyz) -> Self {
+        Color::Xyz(color)
+    }
+}
+
 /// Wraps an angle in degrees to the range [0, 360).
 ///
 /// Handles negative angles and angles greater than 360 by using modulo arithmetic to bring them into the standard range.
@@ -223,6 +874,69 @@ pub fn wrap_degrees(h: f32) -> f32 {
     h
 }
 
+/// Perceived warmth of a color in [-1, 1]: positive for warm reds, oranges, and yellows, negative for cool blues and
+/// cyans, and near zero for neutrals.
+///
+/// Follows the warm–cool colour-emotion model of Ou et al. (2004), `0.02 · C*^1.07 · cos(h − 50°)` on CIE Lch,
+/// without its constant offset (so grays score zero) and squashed into [-1, 1] with `tanh`.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::{Srgb8, warmth};
+///
+/// assert!(warmth(Srgb8::new(255, 120, 0)) > 0.9);
+/// assert!(warmth(Srgb8::new(0, 160, 255)) < -0.5);
+/// ```
+pub fn warmth(color: Srgb8) -> f32 {
+    let lch = Lch::from(color);
+    (0.02 * lch.c.powf(1.07) * (lch.h - 50.0).to_radians().cos()).tanh()
+}
+
+/// Brings an Lch color into sRGB by reducing chroma at fixed lightness and hue.
+///
+/// In-gamut colors convert as-is; otherwise the highest chroma that still fits is found by bisection.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::{Lch, Rgb, map_to_gamut};
+///
+/// let fitted = map_to_gamut(Lch::new(60.0, 150.0, 140.0));
+/// assert!(Rgb::from(fitted).in_gamut());
+/// ```
+pub fn map_to_gamut(color: Lch) -> Srgb8 {
+    let (l, h) = (color.l.clamp(0.0, 100.0), color.h);
+    let c = max_in_gamut_chroma(color.c, |c| Rgb::from(Lab::from(Lch::new(l, c, h))));
+    Srgb8::from(Lch::new(l, c, h))
+}
+
+/// Brings an OKLCH color into sRGB by reducing chroma at fixed lightness and hue, like [`map_to_gamut`].
+pub fn map_oklch_to_gamut(color: Oklch) -> Srgb8 {
+    let (l, h) = (color.l.clamp(0.0, 1.0), color.h);
+    let c = max_in_gamut_chroma(color.c, |c| Rgb::from(Oklab::from(Oklch::new(l, c, h))));
+    Srgb8::from(Oklab::from(Oklch::new(l, c, h)))
+}
+
+/// Bisects for the highest chroma in [0, `chroma`] whose `to_rgb` conversion is inside sRGB.
+fn max_in_gamut_chroma(chroma: f32, to_rgb: impl Fn(f32) -> Rgb) -> f32 {
+    let chroma = chroma.max(0.0);
+    if to_rgb(chroma).in_gamut() {
+        return chroma;
+    }
+
+    let (mut low, mut high) = (0.0, chroma);
+    for _ in 0..24 {
+        let mid = (low + high) / 2.0;
+        if to_rgb(mid).in_gamut() {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
 /// Clamps a value to the range [0, 1].
 ///
 /// Values below 0 are clamped to 0, values above 1 are clamped to 1.
@@ -253,6 +967,125 @@ pub fn clamp01(x: f32) -> f32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn srgb8_serializes_as_hex_string() {
+        let color = Srgb8::new(255, 128, 0);
+        let json = serde_json::to_string(&color).unwrap();
+        assert_eq!(json, "\"#ff8000\"");
+        assert_eq!(serde_json::from_str::<Srgb8>(&json).unwrap(), color);
+        assert_eq!(serde_json::from_str::<Srgb8>("\"FF8000\"").unwrap(), color);
+        assert!(serde_json::from_str::<Srgb8>("\"#fff\"").is_err());
+
+        let lab = Lab::new(50.0, 20.0, -30.0);
+        assert_eq!(
+            serde_json::from_str::<Lab>(&serde_json::to_string(&lab).unwrap()).unwrap(),
+            lab
+        );
+    }
+
+    #[test]
+    fn warmth_separates_warm_cool_and_neutral() {
+        assert!(warmth(Srgb8::new(255, 0, 0)) > 0.8);
+        assert!(warmth(Srgb8::new(0, 0, 255)) < -0.5);
+        assert!(warmth(Srgb8::new(128, 128, 128)).abs() < 0.05);
+        assert!(warmth(Srgb8::new(255, 255, 0)) > 0.0);
+        assert!(warmth(Srgb8::new(0, 255, 255)) < 0.0);
+    }
+
+    #[test]
+    fn parse_css_color_function_forms() {
+        let orange = Some(Srgb8::new(255, 128, 0));
+        assert_eq!(parse_css_color("rgb(255, 128, 0)"), orange);
+        assert_eq!(parse_css_color("  RGB( 255 128 0 ) "), orange);
+        assert_eq!(parse_css_color("rgba(255, 128, 0, 0.5)"), orange);
+        assert_eq!(parse_css_color("rgb(255 128 0 / 50%)"), orange);
+        assert_eq!(parse_css_color("rgb(100%, 50.2%, 0%)"), orange);
+        assert_eq!(parse_css_color("hsl(30, 100%, 50%)"), orange);
+        assert_eq!(parse_css_color("hsla(30deg 100% 50% / 0.8)"), orange);
+        assert_eq!(parse_css_color("hsl(390 100 50)"), orange);
+
+        assert_eq!(parse_css_color("#ff8000"), orange);
+        assert_eq!(parse_css_color("FF8000"), orange);
+        assert_eq!(parse_css_color("#ff800080"), orange);
+        assert_eq!(parse_css_color("#F80"), Some(Srgb8::new(0xff, 0x88, 0x00)));
+        assert_eq!(parse_css_color("#f80c"), Some(Srgb8::new(0xff, 0x88, 0x00)));
+        assert_eq!(parse_css_color("RebeccaPurple"), Some(Srgb8::new(102, 51, 153)));
+        assert!(CSS_NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn parse_css_color_rejects_malformed_input() {
+        for value in [
+            "",
+            "rgb(255, 128)",
+            "rgb(255, 128, 0",
+            "rgb(a, b, c)",
+            "hsv(30, 100%, 50%)",
+            "#f8",
+            "#ff800",
+            "#gg8000",
+            "#ff8000zz",
+            "f80",
+            "notacolor",
+        ] {
+            assert_eq!(parse_css_color(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn srgb8_converts_to_and_from_arrays_and_tuples() {
+        let color = Srgb8::new(0x12, 0x34, 0x56);
+        assert_eq!(Srgb8::from([0x12, 0x34, 0x56]), color);
+        assert_eq!(Srgb8::from((0x12, 0x34, 0x56)), color);
+        assert_eq!(<[u8; 3]>::from(color), [0x12, 0x34, 0x56]);
+        assert_eq!(<(u8, u8, u8)>::from(color), (0x12, 0x34, 0x56));
+
+        let array: [u8; 3] = color.into();
+        let tuple: (u8, u8, u8) = color.into();
+        assert_eq!(Srgb8::from(array), color);
+        assert_eq!(Srgb8::from(tuple), color);
+
+        assert_eq!(<[u16; 3]>::from(Srgb16::from(color)), [0x1212, 0x3434, 0x5656]);
+    }
+
+    #[test]
+    fn color_conversions_match_manual_chains() {
+        let hsl = Hsl::new(210.0, 0.6, 0.45);
+        assert_eq!(Color::Hsl(hsl).to_srgb8(), Srgb8::from(hsl));
+        assert_eq!(Color::from(hsl).to_lab(), Lab::from(Rgb::from(Srgb::from(hsl))));
+        assert_eq!(
+            Color::Hsv(Hsv::new(210.0, 0.6, 0.45)).to_rgb(),
+            Rgb::from(Srgb::from(Hsv::new(210.0, 0.6, 0.45)))
+        );
+        assert_ne!(
+            Color::from(hsl).to_lab(),
+            Lab::from(Srgb8::from(hsl)),
+            "no 8-bit rounding on the way to Lab"
+        );
+
+        let lch = Lch::new(60.0, 40.0, 140.0);
+        assert_eq!(Color::Lch(lch).to_srgb8(), Srgb8::from(lch));
+        assert_eq!(Color::Lch(lch).to_rgb(), Rgb::from(Lab::from(lch)));
+
+        let orange = Srgb8::new(255, 128, 0);
+        assert_eq!(Color::Srgb8(orange).to_oklch(), Oklch::from(orange));
+        assert_eq!(Color::Oklab(Oklab::from(orange)).to_srgb8(), orange);
+    }
+
+    #[test]
+    fn color_parse_keeps_authored_space() {
+        assert_eq!(
+            Color::parse("hsl(30 100% 50%)"),
+            Some(Color::Hsl(Hsl::new(30.0, 1.0, 0.5)))
+        );
+        assert_eq!(
+            Color::parse("rgb(255, 128, 0)"),
+            Some(Color::Srgb8(Srgb8::new(255, 128, 0)))
+        );
+        assert_eq!(Color::parse("tomato"), Some(Color::Srgb8(Srgb8::new(255, 99, 71))));
+        assert_eq!(Color::parse("nope"), None);
+    }
+
     #[test]
     fn test_wrap_degrees() {
         assert_eq!(wrap_degrees(0.0), 0.0);
@@ -286,6 +1119,32 @@ mod tests {
         assert!(Srgb8::from_hex("#fff").is_none());
     }
 
+    #[test]
+    fn parse_errors_explain_the_problem() {
+        assert_eq!(Srgb8::try_from_hex("#"), Err(ParseColorError::Empty));
+        assert_eq!(Color::try_parse("#f80"), Ok(Color::Srgb8(Srgb8::new(0xff, 0x88, 0x00))));
+        assert_eq!(Srgb8::try_from_hex("#ff80"), Err(ParseColorError::BadLength(4)));
+        assert_eq!(Srgb8::try_from_hex("#ff80000"), Err(ParseColorError::BadLength(7)));
+        assert_eq!(Srgb8::try_from_hex("#ff80zz"), Err(ParseColorError::BadDigit('z')));
+        assert_eq!(Srgb8::try_from_hex("aé€"), Err(ParseColorError::BadLength(3)));
+
+        assert_eq!(Color::try_parse("   "), Err(ParseColorError::Empty));
+        assert_eq!(Color::try_parse("#abcde"), Err(ParseColorError::BadLength(5)));
+        assert_eq!(Color::try_parse("12345g"), Err(ParseColorError::BadDigit('g')));
+        assert_eq!(
+            Color::try_parse("Blurple"),
+            Err(ParseColorError::UnknownName("blurple".to_string()))
+        );
+        assert_eq!(
+            Color::try_parse("rgb(1, 2)"),
+            Err(ParseColorError::BadFunction("rgb(1, 2)".to_string()))
+        );
+        assert_eq!(
+            ParseColorError::BadLength(4).to_string(),
+            "expected 6 hex digits, got 4"
+        );
+    }
+
     #[test]
     fn test_srgb8_hex_formatting() {
         let color = Srgb8::new(255, 128, 0);
@@ -301,6 +1160,17 @@ mod tests {
         assert_eq!(color.b, 1.0);
     }
 
+    #[test]
+    fn test_rgb_unclamped_and_in_gamut() {
+        let color = Rgb::new_unclamped(-0.1, 0.5, 1.5);
+        assert_eq!(color.r, -0.1);
+        assert_eq!(color.b, 1.5);
+        assert!(!color.in_gamut());
+
+        assert!(Rgb::new(-0.1, 0.5, 1.5).in_gamut());
+        assert!(Rgb::new_unclamped(1.00001, 0.0, 0.5).in_gamut());
+    }
+
     #[test]
     fn test_hsl_normalization() {
         let color = Hsl::new(370.0, 1.5, -0.1);
@@ -1,33 +1,72 @@
 //! Color space conversion functions.
 //!
 //! Implements bidirectional conversions between various color spaces:
-//! - sRGB 8-bit ↔ sRGB float
+//! - sRGB 8-bit/16-bit ↔ sRGB float
 //! - sRGB ↔ linear RGB (gamma correction)
 //! - Linear RGB ↔ XYZ (D65 white point)
 //! - XYZ ↔ Lab (perceptually uniform)
 //! - Lab ↔ Lch (cylindrical representation)
+//! - Linear RGB ↔ HSLuv (via XYZ and CIE Luv)
+//! - RGB ↔ HSV (linear or gamma-encoded)
+//! - Linear RGB ↔ OKLab ↔ OKLCH
+
+use std::ops::{Add, Mul};
 
 use crate::colors::*;
 
-const D65_X: f32 = 0.95047;
-const D65_Y: f32 = 1.00000;
-const D65_Z: f32 = 1.08883;
+// The XYZ and Lab math below runs in f64 and narrows to f32 only at the public types, so chained conversions
+// (noise walks, repeated gamut mapping) don't compound single-precision rounding through the matrix and cube-root steps.
+pub(crate) const D65_X: f64 = 0.95047;
+pub(crate) const D65_Y: f64 = 1.00000;
+pub(crate) const D65_Z: f64 = 1.08883;
+
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
 
-const LAB_EPSILON: f32 = 216.0 / 24389.0;
-const LAB_KAPPA: f32 = 24389.0 / 27.0;
+/// u′ and v′ chromaticity of the D65 white point used by HSLuv.
+const REF_U: f32 = 0.197_83;
+const REF_V: f32 = 0.468_32;
 
 /// sRGB to XYZ transformation matrix (D65 white point)
-const RGB_TO_XYZ: [[f32; 3]; 3] = [
+const RGB_TO_XYZ: [[f64; 3]; 3] = [
     [0.4124564, 0.3575761, 0.1804375],
     [0.2126729, 0.7151522, 0.0721750],
-    [0.0193339, 0.119_192, 0.9503041],
+    [0.0193339, 0.1191920, 0.9503041],
+];
+
+/// Linear sRGB to OKLab cone (LMS) response matrix.
+const RGB_TO_LMS: [[f32; 3]; 3] = [
+    [0.412_221_46, 0.536_332_55, 0.051_445_995],
+    [0.211_903_5, 0.680_699_5, 0.107_396_96],
+    [0.088_302_46, 0.281_718_85, 0.629_978_7],
 ];
 
-/// XYZ to sRGB transformation matrix (D65 white point, inverse of above)
-const XYZ_TO_RGB: [[f32; 3]; 3] = [
-    [3.2404542, -1.5371385, -0.4985314],
-    [-0.969_266, 1.8760108, 0.0415560],
-    [0.0556434, -0.2040259, 1.0572252],
+/// Cube-rooted LMS to OKLab matrix.
+const LMS_TO_OKLAB: [[f32; 3]; 3] = [
+    [0.210_454_26, 0.793_617_8, -0.004_072_047],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+
+/// OKLab to cube-rooted LMS matrix (inverse of above).
+const OKLAB_TO_LMS: [[f32; 3]; 3] = [
+    [1.0, 0.396_337_78, 0.215_803_76],
+    [1.0, -0.105_561_346, -0.063_854_17],
+    [1.0, -0.089_484_18, -1.291_485_5],
+];
+
+/// LMS to linear sRGB matrix (inverse of `RGB_TO_LMS`).
+const LMS_TO_RGB: [[f32; 3]; 3] = [
+    [4.076_741_7, -3.307_711_6, 0.230_969_94],
+    [-1.268_438, 2.609_757_4, -0.341_319_38],
+    [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+];
+
+/// XYZ to sRGB transformation matrix (D65 white point, exact inverse of `RGB_TO_XYZ`)
+const XYZ_TO_RGB: [[f64; 3]; 3] = [
+    [3.240_454_836_021_408, -1.537_138_850_102_575, -0.498_531_546_868_481],
+    [-0.969_266_389_875_654, 1.876_010_928_842_491, 0.041_556_082_346_674],
+    [0.055_643_419_604_214, -0.204_025_854_267_698, 1.057_225_162_457_929],
 ];
 
 impl From<Srgb8> for Srgb {
@@ -48,6 +87,52 @@ impl From<Srgb> for Srgb8 {
     }
 }
 
+impl From<Srgb16> for Srgb {
+    /// Converts 16-bit sRGB to float sRGB by dividing by 65535.
+    fn from(c: Srgb16) -> Self {
+        Srgb::new(c.r as f32 / 65535.0, c.g as f32 / 65535.0, c.b as f32 / 65535.0)
+    }
+}
+
+impl From<Srgb> for Srgb16 {
+    /// Converts float sRGB to 16-bit sRGB by multiplying by 65535 and rounding.
+    fn from(c: Srgb) -> Self {
+        Srgb16::new(
+            (c.r * 65535.0).round() as u16,
+            (c.g * 65535.0).round() as u16,
+            (c.b * 65535.0).round() as u16,
+        )
+    }
+}
+
+impl From<Srgb8> for Srgb16 {
+    /// Widens 8-bit sRGB exactly (each channel times 257, so 0xff maps to 0xffff).
+    fn from(c: Srgb8) -> Self {
+        Srgb16::new(u16::from(c.r) * 257, u16::from(c.g) * 257, u16::from(c.b) * 257)
+    }
+}
+
+impl From<Srgb16> for Srgb8 {
+    /// Narrows 16-bit sRGB to the nearest 8-bit value.
+    fn from(c: Srgb16) -> Self {
+        Srgb::from(c).into()
+    }
+}
+
+impl From<Srgb16> for Rgb {
+    /// Converts 16-bit sRGB to linear RGB (via float sRGB).
+    fn from(c: Srgb16) -> Self {
+        Srgb::from(c).into()
+    }
+}
+
+impl From<Rgb> for Srgb16 {
+    /// Converts linear RGB to 16-bit sRGB (via float sRGB).
+    fn from(c: Rgb) -> Self {
+        Srgb::from(c).into()
+    }
+}
+
 /// Converts a single sRGB component to linear RGB using inverse gamma (linearization).
 ///
 /// Uses the standard sRGB transfer function per WCAG guidelines with piecewise 2.4 exponent.
@@ -66,6 +151,16 @@ fn linear_to_srgb(c: f32) -> f32 {
     if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 }
 }
 
+/// Double-precision [`srgb_to_linear`] for the 8-bit ↔ Lab path.
+fn srgb_to_linear64(c: f64) -> f64 {
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+/// Double-precision [`linear_to_srgb`] for the Lab ↔ 8-bit path.
+fn linear_to_srgb64(c: f64) -> f64 {
+    if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 }
+}
+
 impl From<Srgb> for Rgb {
     /// Converts sRGB to linear RGB by applying inverse gamma correction to each component.
     fn from(c: Srgb) -> Self {
@@ -83,20 +178,19 @@ impl From<Rgb> for Srgb {
 impl From<Rgb> for Xyz {
     /// Converts linear RGB to XYZ using standard sRGB↔XYZ matrix with D65 white point.
     fn from(c: Rgb) -> Self {
-        let x = RGB_TO_XYZ[0][0] * c.r + RGB_TO_XYZ[0][1] * c.g + RGB_TO_XYZ[0][2] * c.b;
-        let y = RGB_TO_XYZ[1][0] * c.r + RGB_TO_XYZ[1][1] * c.g + RGB_TO_XYZ[1][2] * c.b;
-        let z = RGB_TO_XYZ[2][0] * c.r + RGB_TO_XYZ[2][1] * c.g + RGB_TO_XYZ[2][2] * c.b;
+        let [x, y, z] = mul3(&RGB_TO_XYZ, widen([c.r, c.g, c.b])).map(|v| v as f32);
         Xyz::new(x, y, z)
     }
 }
 
 impl From<Xyz> for Rgb {
     /// Converts XYZ to linear RGB using standard XYZ↔sRGB matrix with D65 white point.
+    ///
+    /// The result is not clamped: colors outside sRGB keep components below 0 or above 1 (see [`Rgb::in_gamut`]).
+    /// Clamping happens when the value is encoded to sRGB.
     fn from(c: Xyz) -> Self {
-        let r = XYZ_TO_RGB[0][0] * c.x + XYZ_TO_RGB[0][1] * c.y + XYZ_TO_RGB[0][2] * c.z;
-        let g = XYZ_TO_RGB[1][0] * c.x + XYZ_TO_RGB[1][1] * c.y + XYZ_TO_RGB[1][2] * c.z;
-        let b = XYZ_TO_RGB[2][0] * c.x + XYZ_TO_RGB[2][1] * c.y + XYZ_TO_RGB[2][2] * c.z;
-        Rgb::new(r, g, b)
+        let [r, g, b] = mul3(&XYZ_TO_RGB, widen([c.x, c.y, c.z])).map(|v| v as f32);
+        Rgb::new_unclamped(r, g, b)
     }
 }
 
@@ -105,7 +199,7 @@ impl From<Xyz> for Rgb {
 /// Applies the piecewise function used in XYZ↔Lab conversion:
 /// - If t > ↔: t^(1/3)
 /// - Otherwise: (↔*t + 16)/116
-fn lab_f(t: f32) -> f32 {
+pub(crate) fn lab_f(t: f64) -> f64 {
     if t > LAB_EPSILON { t.cbrt() } else { (LAB_KAPPA * t + 16.0) / 116.0 }
 }
 
@@ -114,22 +208,33 @@ fn lab_f(t: f32) -> f32 {
 /// Applies the inverse of the Lab conversion function:
 /// - If t^3 > ↔: t^3
 /// - Otherwise: (116*t - 16)/↔
-fn lab_f_inv(t: f32) -> f32 {
+pub(crate) fn lab_f_inv(t: f64) -> f64 {
     let t3 = t * t * t;
     if t3 > LAB_EPSILON { t3 } else { (116.0 * t - 16.0) / LAB_KAPPA }
 }
 
+/// XYZ to Lab components using CIE formulas with D65 white reference.
+fn xyz_to_lab([x, y, z]: [f64; 3]) -> [f64; 3] {
+    let fx = lab_f(x / D65_X);
+    let fy = lab_f(y / D65_Y);
+    let fz = lab_f(z / D65_Z);
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Lab to XYZ components using the inverse CIE transform with D65 white reference.
+fn lab_to_xyz([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+
+    [D65_X * lab_f_inv(fx), D65_Y * lab_f_inv(fy), D65_Z * lab_f_inv(fz)]
+}
+
 impl From<Xyz> for Lab {
     /// Converts XYZ to Lab using CIE formulas with D65 white reference.
     fn from(c: Xyz) -> Self {
-        let fx = lab_f(c.x / D65_X);
-        let fy = lab_f(c.y / D65_Y);
-        let fz = lab_f(c.z / D65_Z);
-
-        let l = 116.0 * fy - 16.0;
-        let a = 500.0 * (fx - fy);
-        let b = 200.0 * (fy - fz);
-
+        let [l, a, b] = xyz_to_lab(widen([c.x, c.y, c.z])).map(|v| v as f32);
         Lab::new(l, a, b)
     }
 }
@@ -137,14 +242,7 @@ impl From<Xyz> for Lab {
 impl From<Lab> for Xyz {
     /// Converts Lab to XYZ using inverse CIE transform with D65 white reference.
     fn from(c: Lab) -> Self {
-        let fy = (c.l + 16.0) / 116.0;
-        let fx = c.a / 500.0 + fy;
-        let fz = fy - c.b / 200.0;
-
-        let x = D65_X * lab_f_inv(fx);
-        let y = D65_Y * lab_f_inv(fy);
-        let z = D65_Z * lab_f_inv(fz);
-
+        let [x, y, z] = lab_to_xyz(widen([c.l, c.a, c.b])).map(|v| v as f32);
         Xyz::new(x, y, z)
     }
 }
@@ -180,6 +278,75 @@ impl From<Lch> for Lab {
     }
 }
 
+impl From<Rgb> for Oklab {
+    /// Converts linear RGB to OKLab via cube-rooted LMS cone responses.
+    fn from(c: Rgb) -> Self {
+        let [l, m, s] = mul3(&RGB_TO_LMS, [c.r, c.g, c.b]).map(f32::cbrt);
+        let [l, a, b] = mul3(&LMS_TO_OKLAB, [l, m, s]);
+        Oklab::new(l, a, b)
+    }
+}
+
+impl From<Oklab> for Rgb {
+    /// Converts OKLab to linear RGB without clamping out-of-gamut components.
+    fn from(c: Oklab) -> Self {
+        let lms = mul3(&OKLAB_TO_LMS, [c.l, c.a, c.b]).map(|v| v * v * v);
+        let [r, g, b] = mul3(&LMS_TO_RGB, lms);
+        Rgb::new_unclamped(r, g, b)
+    }
+}
+
+impl From<Oklab> for Oklch {
+    /// Converts OKLab to OKLCH using cylindrical coordinates.
+    fn from(c: Oklab) -> Self {
+        Oklch::new(c.l, (c.a * c.a + c.b * c.b).sqrt(), c.b.atan2(c.a).to_degrees())
+    }
+}
+
+impl From<Oklch> for Oklab {
+    /// Converts OKLCH to OKLab using the inverse cylindrical transform.
+    fn from(c: Oklch) -> Self {
+        let h_rad = c.h.to_radians();
+        Oklab::new(c.l, c.c * h_rad.cos(), c.c * h_rad.sin())
+    }
+}
+
+impl From<Srgb8> for Oklab {
+    /// Direct conversion from 8-bit sRGB to OKLab (via linear RGB).
+    fn from(c: Srgb8) -> Self {
+        Oklab::from(Rgb::from(c))
+    }
+}
+
+impl From<Oklab> for Srgb8 {
+    /// Direct conversion from OKLab to 8-bit sRGB (via linear RGB), clamping out-of-gamut components.
+    fn from(c: Oklab) -> Self {
+        Srgb8::from(Rgb::from(c))
+    }
+}
+
+impl From<Srgb8> for Oklch {
+    /// Direct conversion from 8-bit sRGB to OKLCH (via OKLab).
+    fn from(c: Srgb8) -> Self {
+        Oklch::from(Oklab::from(c))
+    }
+}
+
+impl From<Oklch> for Srgb8 {
+    /// Direct conversion from OKLCH to 8-bit sRGB (via OKLab).
+    fn from(c: Oklch) -> Self {
+        Srgb8::from(Oklab::from(c))
+    }
+}
+
+pub(crate) fn mul3<T: Copy + Add<Output = T> + Mul<Output = T>>(matrix: &[[T; 3]; 3], v: [T; 3]) -> [T; 3] {
+    matrix.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn widen(v: [f32; 3]) -> [f64; 3] {
+    v.map(f64::from)
+}
+
 impl From<Srgb8> for Rgb {
     /// Direct conversion from 8-bit sRGB to linear RGB (via float sRGB).
     fn from(c: Srgb8) -> Self {
@@ -194,23 +361,37 @@ impl From<Rgb> for Srgb8 {
     }
 }
 
+impl From<Rgb> for Lab {
+    /// Direct conversion from linear RGB to Lab (via XYZ, without narrowing in between).
+    fn from(c: Rgb) -> Self {
+        let [l, a, b] = xyz_to_lab(mul3(&RGB_TO_XYZ, widen([c.r, c.g, c.b]))).map(|v| v as f32);
+        Lab::new(l, a, b)
+    }
+}
+
+impl From<Lab> for Rgb {
+    /// Direct conversion from Lab to linear RGB (via Xyz), without clamping out-of-gamut components.
+    fn from(c: Lab) -> Self {
+        let [r, g, b] = mul3(&XYZ_TO_RGB, lab_to_xyz(widen([c.l, c.a, c.b]))).map(|v| v as f32);
+        Rgb::new_unclamped(r, g, b)
+    }
+}
+
 impl From<Srgb8> for Lab {
-    /// Direct conversion from 8-bit sRGB to Lab (via Srgb↔Rgb↔Xyz↔Lab).
+    /// Direct conversion from 8-bit sRGB to Lab (via Srgb↔Rgb↔Xyz↔Lab), computed in double precision.
     fn from(c: Srgb8) -> Self {
-        let srgb = Srgb::from(c);
-        let rgb = Rgb::from(srgb);
-        let xyz = Xyz::from(rgb);
-        Lab::from(xyz)
+        let linear = [c.r, c.g, c.b].map(|v| srgb_to_linear64(f64::from(v) / 255.0));
+        let [l, a, b] = xyz_to_lab(mul3(&RGB_TO_XYZ, linear)).map(|v| v as f32);
+        Lab::new(l, a, b)
     }
 }
 
 impl From<Lab> for Srgb8 {
-    /// Direct conversion from Lab to 8-bit sRGB (via Xyz↔Rgb↔Srgb↔Srgb8).
+    /// Direct conversion from Lab to 8-bit sRGB (via Xyz↔Rgb↔Srgb↔Srgb8), computed in double precision.
     fn from(c: Lab) -> Self {
-        let xyz = Xyz::from(c);
-        let rgb = Rgb::from(xyz);
-        let srgb = Srgb::from(rgb);
-        Srgb8::from(srgb)
+        let linear = mul3(&XYZ_TO_RGB, lab_to_xyz(widen([c.l, c.a, c.b])));
+        let [r, g, b] = linear.map(|v| (linear_to_srgb64(v.clamp(0.0, 1.0)) * 255.0).round() as u8);
+        Srgb8::new(r, g, b)
     }
 }
 
@@ -228,6 +409,172 @@ impl From<Lch> for Srgb8 {
     }
 }
 
+impl From<Rgb> for Hsluv {
+    /// Converts linear RGB to HSLuv (via XYZ, Luv, and LCHuv).
+    ///
+    /// Saturation is chroma expressed as a percentage of the largest in-gamut chroma for the color's lightness and hue.
+    fn from(c: Rgb) -> Self {
+        let xyz = Xyz::from(c);
+        let l = if xyz.y <= LAB_EPSILON as f32 { xyz.y * LAB_KAPPA as f32 } else { 116.0 * xyz.y.cbrt() - 16.0 };
+        let denominator = xyz.x + 15.0 * xyz.y + 3.0 * xyz.z;
+        if l < 1e-6 || denominator == 0.0 {
+            return Hsluv::new(0.0, 0.0, 0.0);
+        }
+
+        let u = 13.0 * l * (4.0 * xyz.x / denominator - REF_U);
+        let v = 13.0 * l * (9.0 * xyz.y / denominator - REF_V);
+        let chroma = (u * u + v * v).sqrt();
+        let hue = if chroma < 1e-6 { 0.0 } else { v.atan2(u).to_degrees() };
+
+        if l > 99.9999 {
+            return Hsluv::new(hue, 0.0, 100.0);
+        }
+        let saturation = chroma / max_chroma_for_lh(l, hue) * 100.0;
+        Hsluv::new(hue, saturation, l)
+    }
+}
+
+impl From<Hsluv> for Rgb {
+    /// Converts HSLuv to linear RGB (via LCHuv, Luv, and XYZ).
+    fn from(c: Hsluv) -> Self {
+        if c.l < 1e-6 {
+            return Rgb::new(0.0, 0.0, 0.0);
+        }
+        if c.l > 99.9999 {
+            return Rgb::new(1.0, 1.0, 1.0);
+        }
+
+        let chroma = max_chroma_for_lh(c.l, c.h) * c.s / 100.0;
+        let h_rad = c.h.to_radians();
+        let var_u = chroma * h_rad.cos() / (13.0 * c.l) + REF_U;
+        let var_v = chroma * h_rad.sin() / (13.0 * c.l) + REF_V;
+
+        let y = if c.l <= 8.0 { c.l / LAB_KAPPA as f32 } else { ((c.l + 16.0) / 116.0).powi(3) };
+        let x = 9.0 * y * var_u / (4.0 * var_v);
+        let z = (9.0 * y - 15.0 * var_v * y - var_v * x) / (3.0 * var_v);
+
+        Rgb::from(Xyz::new(x, y, z))
+    }
+}
+
+impl From<Srgb8> for Hsluv {
+    /// Direct conversion from 8-bit sRGB to HSLuv (via linear RGB).
+    fn from(c: Srgb8) -> Self {
+        Hsluv::from(Rgb::from(c))
+    }
+}
+
+impl From<Hsluv> for Srgb8 {
+    /// Direct conversion from HSLuv to 8-bit sRGB (via linear RGB).
+    fn from(c: Hsluv) -> Self {
+        Srgb8::from(Rgb::from(c))
+    }
+}
+
+impl From<Rgb> for Hsv {
+    /// Converts linear RGB to HSV.
+    ///
+    /// NOTE: like `Hsl::from(Rgb)`, this works on linear light; use `Hsv::from(Srgb8)` to match design tools.
+    fn from(c: Rgb) -> Self {
+        hsv_from_components(c.r, c.g, c.b)
+    }
+}
+
+impl From<Hsv> for Rgb {
+    /// Converts HSV to linear RGB (the inverse of `Hsv::from(Rgb)`).
+    fn from(c: Hsv) -> Self {
+        let (r, g, b) = hsv_to_components(c);
+        Rgb::new(r, g, b)
+    }
+}
+
+impl From<Srgb8> for Hsv {
+    /// Converts 8-bit sRGB to HSV computed on the gamma-encoded values, as shown by CSS and design tools.
+    fn from(c: Srgb8) -> Self {
+        let srgb = Srgb::from(c);
+        hsv_from_components(srgb.r, srgb.g, srgb.b)
+    }
+}
+
+impl From<Hsv> for Srgb {
+    /// Converts gamma-encoded HSV to float sRGB, without 8-bit rounding.
+    fn from(c: Hsv) -> Self {
+        let (r, g, b) = hsv_to_components(c);
+        Srgb::new(r, g, b)
+    }
+}
+
+impl From<Hsv> for Srgb8 {
+    /// Converts gamma-encoded HSV to 8-bit sRGB (the inverse of `Hsv::from(Srgb8)`).
+    fn from(c: Hsv) -> Self {
+        Srgb8::from(Srgb::from(c))
+    }
+}
+
+fn hsv_from_components(r: f32, g: f32, b: f32) -> Hsv {
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+
+    if delta < 1e-10 {
+        return Hsv::new(0.0, 0.0, max);
+    }
+
+    let h = if (max - r).abs() < 1e-10 {
+        ((g - b) / delta).rem_euclid(6.0) * 60.0
+    } else if (max - g).abs() < 1e-10 {
+        ((b - r) / delta + 2.0) * 60.0
+    } else {
+        ((r - g) / delta + 4.0) * 60.0
+    };
+
+    Hsv::new(h, delta / max, max)
+}
+
+fn hsv_to_components(hsv: Hsv) -> (f32, f32, f32) {
+    let c = hsv.v * hsv.s;
+    let h_prime = hsv.h / 60.0;
+    let x = c * (1.0 - ((h_prime % 2.0) - 1.0).abs());
+
+    let (r1, g1, b1) = match h_prime as i32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    let m = hsv.v - c;
+    (r1 + m, g1 + m, b1 + m)
+}
+
+/// Finds the largest LCHuv chroma that stays inside sRGB for a given lightness and hue.
+///
+/// Each of the six sRGB gamut faces projects to a line in the Luv chroma plane; the answer is the
+/// nearest intersection of the hue ray with those lines.
+fn max_chroma_for_lh(l: f32, h: f32) -> f32 {
+    let (l, h_rad) = (f64::from(l), f64::from(h).to_radians());
+    let sub1 = (l + 16.0).powi(3) / 1_560_896.0;
+    let sub2 = if sub1 > LAB_EPSILON { sub1 } else { l / LAB_KAPPA };
+
+    let mut max_chroma = f64::MAX;
+    for [m1, m2, m3] in XYZ_TO_RGB {
+        for t in [0.0, 1.0] {
+            let top1 = (284_517.0 * m1 - 94_839.0 * m3) * sub2;
+            let top2 = (838_422.0 * m3 + 769_860.0 * m2 + 731_718.0 * m1) * l * sub2 - 769_860.0 * t * l;
+            let bottom = (632_260.0 * m3 - 126_452.0 * m2) * sub2 + 126_452.0 * t;
+
+            let slope = top1 / bottom;
+            let intercept = top2 / bottom;
+            let length = intercept / (h_rad.sin() - slope * h_rad.cos());
+            if length >= 0.0 {
+                max_chroma = max_chroma.min(length);
+            }
+        }
+    }
+    max_chroma as f32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,6 +585,50 @@ mod tests {
         (a - b).abs() < EPSILON
     }
 
+    #[test]
+    fn srgb16_round_trips_finer_than_srgb8() {
+        // A shallow linear ramp, like one channel of a wide gradient: 8-bit collapses it into a few bands.
+        let steps: Vec<Rgb> = (0..=100)
+            .map(|i| Rgb::new(0.2 + 0.01 * i as f32 / 100.0, 0.5, 0.8))
+            .collect();
+        let error8 = steps
+            .iter()
+            .map(|&rgb| (Rgb::from(Srgb8::from(rgb)).r - rgb.r).abs())
+            .fold(0.0, f32::max);
+        let error16 = steps
+            .iter()
+            .map(|&rgb| (Rgb::from(Srgb16::from(rgb)).r - rgb.r).abs())
+            .fold(0.0, f32::max);
+        assert!(error16 * 100.0 < error8, "16-bit error {error16} vs 8-bit {error8}");
+
+        let mut levels8: Vec<u8> = steps.iter().map(|&rgb| Srgb8::from(rgb).r).collect();
+        let mut levels16: Vec<u16> = steps.iter().map(|&rgb| Srgb16::from(rgb).r).collect();
+        levels8.dedup();
+        levels16.dedup();
+        assert!(levels8.len() <= 5, "{} 8-bit levels", levels8.len());
+        assert_eq!(levels16.len(), 101);
+
+        let orange = Srgb8::new(255, 128, 0);
+        assert_eq!(Srgb16::from(orange), Srgb16::new(65535, 32896, 0));
+        assert_eq!(Srgb8::from(Srgb16::from(orange)), orange);
+    }
+
+    #[test]
+    fn test_out_of_gamut_lab_is_not_clamped() {
+        let vivid = Lab::from(Lch::new(60.0, 130.0, 140.0));
+        let rgb = Rgb::from(vivid);
+
+        assert!(
+            rgb.r < 0.0 || rgb.g > 1.0 || rgb.b < 0.0,
+            "expected an out-of-range channel: {rgb:?}"
+        );
+        assert!(!rgb.in_gamut());
+        assert!(Rgb::from(Lab::from(Srgb8::new(255, 255, 255))).in_gamut());
+
+        let clipped = Srgb8::from(vivid);
+        assert!(Rgb::from(clipped).in_gamut());
+    }
+
     #[test]
     fn test_srgb8_to_srgb_conversion() {
         let c8 = Srgb8::new(255, 128, 0);
@@ -276,9 +667,9 @@ mod tests {
     fn test_rgb_to_xyz_conversion() {
         let white = Rgb::new(1.0, 1.0, 1.0);
         let xyz = Xyz::from(white);
-        assert!(approx_eq(xyz.x, D65_X));
-        assert!(approx_eq(xyz.y, D65_Y));
-        assert!(approx_eq(xyz.z, D65_Z));
+        assert!(approx_eq(xyz.x, D65_X as f32));
+        assert!(approx_eq(xyz.y, D65_Y as f32));
+        assert!(approx_eq(xyz.z, D65_Z as f32));
 
         let color = Rgb::new(0.5, 0.3, 0.7);
         let xyz = Xyz::from(color);
@@ -290,7 +681,7 @@ mod tests {
 
     #[test]
     fn test_xyz_to_lab_conversion() {
-        let white_xyz = Xyz::new(D65_X, D65_Y, D65_Z);
+        let white_xyz = Xyz::new(D65_X as f32, D65_Y as f32, D65_Z as f32);
         let lab = Lab::from(white_xyz);
         assert!(approx_eq(lab.l, 100.0));
         assert!(approx_eq(lab.a, 0.0));
@@ -304,6 +695,27 @@ mod tests {
         assert!(approx_eq(back.z, xyz.z));
     }
 
+    #[test]
+    fn test_oklab_reference_values() {
+        let white = Oklab::from(Rgb::new(1.0, 1.0, 1.0));
+        assert!(approx_eq(white.l, 1.0) && white.a.abs() < EPSILON && white.b.abs() < EPSILON);
+
+        // Reference values from Ottosson's OKLab post for pure sRGB red.
+        let red = Oklab::from(Srgb8::new(255, 0, 0));
+        assert!(approx_eq(red.l, 0.627_955) && approx_eq(red.a, 0.224_863) && approx_eq(red.b, 0.125_846));
+    }
+
+    #[test]
+    fn test_oklch_round_trip_srgb8() {
+        for color in [
+            Srgb8::new(12, 200, 90),
+            Srgb8::new(255, 128, 0),
+            Srgb8::new(40, 40, 220),
+        ] {
+            assert_eq!(Srgb8::from(Oklch::from(color)), color);
+        }
+    }
+
     #[test]
     fn test_lab_to_lch_conversion() {
         let gray = Lab::new(50.0, 0.0, 0.0);
@@ -332,7 +744,7 @@ mod tests {
         for c8 in colors {
             let lab = Lab::from(c8);
             let back = Srgb8::from(lab);
-            let tolerance = 2;
+            let tolerance = 0;
             assert!(
                 (back.r as i32 - c8.r as i32).abs() <= tolerance,
                 "Red mismatch: {} != {}",
@@ -354,12 +766,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn chained_lab_rgb_conversions_do_not_drift() {
+        let start = Lab::from(Srgb8::new(200, 100, 50));
+        let mut lab = start;
+        for _ in 0..1000 {
+            lab = Lab::from(Xyz::from(Rgb::from(lab)));
+        }
+        let drift = (lab.l - start.l).abs() + (lab.a - start.a).abs() + (lab.b - start.b).abs();
+        assert!(drift < 1e-4, "Lab drifted by {drift} over 1000 round trips");
+    }
+
     #[test]
     fn test_full_round_trip_srgb8_to_lch() {
         let c8 = Srgb8::new(200, 100, 50);
         let lch = Lch::from(c8);
         let back = Srgb8::from(lch);
-        let tolerance = 2;
+        let tolerance = 0;
         assert!(
             (back.r as i32 - c8.r as i32).abs() <= tolerance,
             "Red mismatch: {} != {}",
@@ -400,4 +823,63 @@ mod tests {
         assert!(lab.a > 75.0 && lab.a < 85.0, "a should be around 79");
         assert!(lab.b < -105.0 && lab.b > -115.0, "b should be around -108");
     }
+
+    #[test]
+    fn test_hsluv_reference_snapshot() {
+        // Expected values from the HSLuv reference snapshot (hsluv.org).
+        let cases = [
+            (Srgb8::new(255, 0, 0), (12.177, 100.0, 53.237)),
+            (Srgb8::new(0, 255, 0), (127.715, 100.0, 87.736)),
+            (Srgb8::new(0, 0, 255), (265.874, 100.0, 32.301)),
+        ];
+        for (color, (h, s, l)) in cases {
+            let hsluv = Hsluv::from(color);
+            assert!((hsluv.h - h).abs() < 0.1, "{color}: hue {} != {h}", hsluv.h);
+            assert!((hsluv.s - s).abs() < 0.1, "{color}: saturation {} != {s}", hsluv.s);
+            assert!((hsluv.l - l).abs() < 0.1, "{color}: lightness {} != {l}", hsluv.l);
+        }
+
+        let white = Hsluv::from(Srgb8::new(255, 255, 255));
+        assert!(white.s < 0.1 && (white.l - 100.0).abs() < 0.1);
+        let black = Hsluv::from(Srgb8::new(0, 0, 0));
+        assert_eq!((black.s, black.l), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_srgb8_to_hsv_matches_css() {
+        let orange = Hsv::from(Srgb8::new(255, 128, 0));
+        assert!(approx_eq(orange.h, 30.117_647));
+        assert!(approx_eq(orange.s, 1.0) && approx_eq(orange.v, 1.0));
+
+        let gray = Hsv::from(Srgb8::new(128, 128, 128));
+        assert_eq!(gray.s, 0.0);
+        assert!(approx_eq(gray.v, 128.0 / 255.0));
+    }
+
+    #[test]
+    fn test_hsv_round_trip() {
+        for color in [
+            Srgb8::new(255, 128, 0),
+            Srgb8::new(30, 144, 255),
+            Srgb8::new(200, 20, 120),
+            Srgb8::new(0, 0, 0),
+        ] {
+            assert_eq!(Srgb8::from(Hsv::from(color)), color);
+            assert_eq!(Srgb8::from(Rgb::from(Hsv::from(Rgb::from(color)))), color);
+        }
+    }
+
+    #[test]
+    fn test_hsluv_round_trip() {
+        for color in [
+            Srgb8::new(255, 128, 0),
+            Srgb8::new(30, 144, 255),
+            Srgb8::new(128, 128, 128),
+        ] {
+            let back = Srgb8::from(Hsluv::from(color));
+            assert!((back.r as i32 - color.r as i32).abs() <= 1, "{color} -> {back}");
+            assert!((back.g as i32 - color.g as i32).abs() <= 1, "{color} -> {back}");
+            assert!((back.b as i32 - color.b as i32).abs() <= 1, "{color} -> {back}");
+        }
+    }
 }
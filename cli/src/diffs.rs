@@ -5,10 +5,12 @@
 //! - ΔE76 (Euclidean distance)
 //! - ΔE94 (graphics/textiles variants)
 //! - ΔE2000 (CIEDE2000)
+//! - ΔE CMC(l:c) (textile/branding acceptability)
 //!
-//! Supporting helpers for "just noticeable difference" checks and enforcing a minimum perceptual spacing within color collections.
+//! Supporting helpers for "just noticeable difference" checks, enforcing a minimum perceptual spacing within color collections
+//! (by dropping or by nudging colors), and snapping colors to a fixed target palette.
 
-use crate::colors::{Lab, wrap_degrees};
+use crate::colors::{CSS_NAMED_COLORS, Lab, Rgb, Srgb8, Xyz, wrap_degrees};
 
 /// Default ΔE threshold commonly cited as the "just noticeable difference".
 pub const DEFAULT_JND_THRESHOLD: f32 = 2.3;
@@ -119,6 +121,84 @@ pub fn delta_e_2000(a: Lab, b: Lab) -> f32 {
     (l_term * l_term + c_term * c_term + h_term * h_term + r_t * c_term * h_term).sqrt()
 }
 
+/// Computes the CMC(l:c) color difference with `reference` as the standard sample.
+///
+/// CMC is asymmetric: the weighting functions are derived from the reference color, so `delta_e_cmc(a, b, ..)` and
+/// `delta_e_cmc(b, a, ..)` generally differ.
+///
+/// - `l` weights lightness differences; larger values make lightness shifts less significant.
+/// - `c` weights chroma differences.
+///
+/// Use `l = 2, c = 1` for acceptability (see [`delta_e_cmc_2_1`]) and `l = 1, c = 1` for perceptibility.
+pub fn delta_e_cmc(reference: Lab, sample: Lab, l: f32, c: f32) -> f32 {
+    let c1 = (reference.a * reference.a + reference.b * reference.b).sqrt();
+    let c2 = (sample.a * sample.a + sample.b * sample.b).sqrt();
+    let delta_l = reference.l - sample.l;
+    let delta_c = c1 - c2;
+    let delta_a = reference.a - sample.a;
+    let delta_b = reference.b - sample.b;
+    let delta_h_sq = (delta_a * delta_a + delta_b * delta_b - delta_c * delta_c).max(0.0);
+
+    let h1 = wrap_degrees(reference.b.atan2(reference.a).to_degrees());
+    let s_l = if reference.l < 16.0 { 0.511 } else { 0.040975 * reference.l / (1.0 + 0.01765 * reference.l) };
+    let s_c = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+    let c1_pow4 = c1.powi(4);
+    let f = (c1_pow4 / (c1_pow4 + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let s_h = s_c * (f * t + 1.0 - f);
+
+    let l_term = delta_l / (l * s_l);
+    let c_term = delta_c / (c * s_c);
+
+    (l_term * l_term + c_term * c_term + delta_h_sq / (s_h * s_h)).sqrt()
+}
+
+/// Computes CMC(2:1), the acceptability variant commonly used for textile and brand color tolerances.
+pub fn delta_e_cmc_2_1(reference: Lab, sample: Lab) -> f32 {
+    delta_e_cmc(reference, sample, 2.0, 1.0)
+}
+
+/// ΔE formula selected by [`distance_matrix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeltaEMetric {
+    /// [`delta_e_76`]
+    Cie76,
+    /// [`delta_e_94`] with the graphic-arts constants.
+    Cie94,
+    /// [`delta_e_2000`]
+    #[default]
+    Ciede2000,
+    /// [`delta_e_cmc_2_1`], with the first color as the reference.
+    Cmc,
+}
+
+impl DeltaEMetric {
+    /// Difference between two Lab colors under this metric.
+    pub fn delta_e(self, a: Lab, b: Lab) -> f32 {
+        match self {
+            DeltaEMetric::Cie76 => delta_e_76(a, b),
+            DeltaEMetric::Cie94 => delta_e_94(a, b, false),
+            DeltaEMetric::Ciede2000 => delta_e_2000(a, b),
+            DeltaEMetric::Cmc => delta_e_cmc_2_1(a, b),
+        }
+    }
+}
+
+/// Pairwise ΔE between every pair of colors: `matrix[i][j]` is the difference from `colors[i]` to `colors[j]`.
+///
+/// The diagonal is zero. The matrix is symmetric for every metric except [`DeltaEMetric::Cmc`], which uses the row
+/// color as the reference.
+pub fn distance_matrix(colors: &[Srgb8], metric: DeltaEMetric) -> Vec<Vec<f32>> {
+    let labs: Vec<Lab> = colors.iter().copied().map(Lab::from).collect();
+    labs.iter()
+        .map(|&a| labs.iter().map(|&b| metric.delta_e(a, b)).collect())
+        .collect()
+}
+
 /// Returns true if the given ΔE exceeds the supplied or default JND threshold.
 // TODO: Expose CLI options that let users enforce or inspect minimum ΔE values via this helper.
 pub fn is_just_noticeable(delta_e: f32, threshold: f32) -> bool {
@@ -146,6 +226,130 @@ pub fn ensure_min_distance(colors: &mut Vec<Lab>, min_delta_e: f32) {
     colors.extend(filtered);
 }
 
+/// Nudges crowded colors apart until every pair is at least `min_delta_e` apart (ΔE2000), keeping the count fixed.
+///
+/// Unlike [`ensure_min_distance`], nothing is dropped. Each iteration pushes every too-close pair away from each other
+/// along the line between them in Lab, by half the shortfall each, then pulls the colors back into the sRGB gamut.
+/// Stops early once all pairs meet the threshold; otherwise runs `iterations` rounds and returns the best effort.
+/// Returns true when the threshold is met.
+pub fn spread_min_distance(colors: &mut [Lab], min_delta_e: f32, iterations: usize) -> bool {
+    if colors.len() <= 1 || min_delta_e <= 0.0 {
+        return true;
+    }
+
+    for _ in 0..iterations {
+        let mut pushes = vec![[0.0_f32; 3]; colors.len()];
+        let mut crowded = false;
+
+        for i in 0..colors.len() {
+            for j in (i + 1)..colors.len() {
+                let distance = delta_e_2000(colors[i], colors[j]);
+                if distance >= min_delta_e {
+                    continue;
+                }
+                crowded = true;
+
+                let (a, b) = (colors[i], colors[j]);
+                let mut direction = [b.l - a.l, b.a - a.a, b.b - a.b];
+                let length = delta_e_76(a, b);
+                if length < 1e-4 {
+                    // Identical colors have no direction to separate along; fan them out around the a/b plane.
+                    let angle = (i * 7 + j) as f32;
+                    direction = [0.0, angle.cos(), angle.sin()];
+                } else {
+                    direction = direction.map(|component| component / length);
+                }
+
+                // ΔE2000 grows more slowly than Euclidean Lab distance for most colors, so scale the step to match.
+                let scale = if distance > 1e-4 { (length / distance).max(1.0) } else { 1.0 };
+                let step = (min_delta_e - distance) * 0.5 * scale;
+                for axis in 0..3 {
+                    pushes[i][axis] -= direction[axis] * step;
+                    pushes[j][axis] += direction[axis] * step;
+                }
+            }
+        }
+
+        if !crowded {
+            return true;
+        }
+        for (color, push) in colors.iter_mut().zip(&pushes) {
+            *color = into_gamut(Lab::new(color.l + push[0], color.a + push[1], color.b + push[2]));
+        }
+    }
+
+    colors
+        .iter()
+        .enumerate()
+        .all(|(i, &a)| colors[i + 1..].iter().all(|&b| delta_e_2000(a, b) >= min_delta_e))
+}
+
+/// Clips a Lab color to the nearest displayable linear RGB value.
+fn into_gamut(lab: Lab) -> Lab {
+    let rgb = Rgb::from(lab);
+    Lab::from(Xyz::from(Rgb::new(rgb.r, rgb.g, rgb.b)))
+}
+
+/// Maps each color to its nearest neighbor in `target` (using ΔE2000).
+///
+/// Returns the input unchanged when `target` is empty.
+pub fn quantize(colors: &[Srgb8], target: &[Srgb8]) -> Vec<Srgb8> {
+    if target.is_empty() {
+        return colors.to_vec();
+    }
+
+    let target_lab: Vec<Lab> = target.iter().copied().map(Lab::from).collect();
+    colors
+        .iter()
+        .map(|&color| {
+            let lab = Lab::from(color);
+            let nearest = target_lab
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| delta_e_2000(lab, **a).total_cmp(&delta_e_2000(lab, **b)))
+                .map_or(0, |(i, _)| i);
+            target[nearest]
+        })
+        .collect()
+}
+
+/// Finds the CSS named color closest to `color`, returning its name and the ΔE2000 between them.
+///
+/// Reverse of the named-color lookup in [`Color::parse`](crate::colors::Color::parse). Where CSS defines aliases for one color (`aqua`/`cyan`,
+/// `gray`/`grey`), the alphabetically first name is returned.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::diffs::nearest_css_name;
+///
+/// let (name, delta) = nearest_css_name(Srgb8::new(0x4b, 0x00, 0x82));
+/// assert_eq!(name, "indigo");
+/// assert!(delta < 0.01);
+/// ```
+pub fn nearest_css_name(color: Srgb8) -> (&'static str, f32) {
+    let target = Lab::from(color);
+    CSS_NAMED_COLORS
+        .iter()
+        .map(|&(name, named)| (name, delta_e_2000(target, Lab::from(named))))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("CSS named colors are non-empty")
+}
+
+/// Returns the 216 web-safe colors (every combination of 0x00, 0x33, 0x66, 0x99, 0xCC, 0xFF).
+pub fn web_safe_palette() -> Vec<Srgb8> {
+    const STEPS: [u8; 6] = [0x00, 0x33, 0x66, 0x99, 0xcc, 0xff];
+    STEPS
+        .iter()
+        .flat_map(|&r| {
+            STEPS
+                .iter()
+                .flat_map(move |&g| STEPS.iter().map(move |&b| Srgb8::new(r, g, b)))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +382,42 @@ mod tests {
         assert!((diff - 2.0425).abs() < 1e-4);
     }
 
+    #[test]
+    fn distance_matrix_is_symmetric_with_zero_diagonal() {
+        let colors = ["#d62828", "#f7b538", "#2a9d8f", "#1d3557", "#808080"].map(|hex| Srgb8::from_hex(hex).unwrap());
+        for metric in [
+            DeltaEMetric::Cie76,
+            DeltaEMetric::Cie94,
+            DeltaEMetric::Ciede2000,
+            DeltaEMetric::Cmc,
+        ] {
+            let matrix = distance_matrix(&colors, metric);
+            assert_eq!(matrix.len(), colors.len());
+            for (i, row) in matrix.iter().enumerate() {
+                assert_eq!(row.len(), colors.len());
+                assert_eq!(row[i], 0.0, "{metric:?} diagonal");
+                for (j, &delta) in row.iter().enumerate() {
+                    assert!(delta >= 0.0);
+                    if metric != DeltaEMetric::Cmc {
+                        assert!((delta - matrix[j][i]).abs() < 1e-4, "{metric:?} [{i}][{j}]");
+                    }
+                }
+            }
+        }
+
+        let matrix = distance_matrix(&colors, DeltaEMetric::Ciede2000);
+        assert_eq!(matrix[0][2], delta_e_2000(Lab::from(colors[0]), Lab::from(colors[2])));
+        assert!(distance_matrix(&[], DeltaEMetric::Cie76).is_empty());
+    }
+
+    #[test]
+    fn delta_e_cmc_matches_reference_case() {
+        let a = lab(0.9, 16.3, -2.22);
+        let b = lab(0.7, 14.2, -1.80);
+        assert!((delta_e_cmc_2_1(a, b) - 1.443).abs() < 1e-3);
+        assert!((delta_e_cmc(a, b, 1.0, 1.0) - 1.482).abs() < 1e-3);
+    }
+
     #[test]
     fn just_noticeable_difference_helper() {
         assert!(is_just_noticeable(3.0, DEFAULT_JND_THRESHOLD));
@@ -191,4 +431,63 @@ mod tests {
         assert_eq!(colors.len(), 2);
         assert!(delta_e_2000(colors[0], colors[1]) >= 2.0);
     }
+
+    #[test]
+    fn spread_min_distance_keeps_count_and_separates_pairs() {
+        let mut colors = vec![
+            lab(50.0, 20.0, 10.0),
+            lab(51.0, 21.0, 10.0),
+            lab(50.5, 20.0, 11.0),
+            lab(50.0, 20.0, 10.0),
+            lab(70.0, -30.0, 40.0),
+        ];
+        let min_pair = |colors: &[Lab]| {
+            let mut min = f32::MAX;
+            for i in 0..colors.len() {
+                for j in (i + 1)..colors.len() {
+                    min = min.min(delta_e_2000(colors[i], colors[j]));
+                }
+            }
+            min
+        };
+        let before = min_pair(&colors);
+
+        assert!(spread_min_distance(&mut colors, 10.0, 100));
+        assert_eq!(colors.len(), 5);
+        assert!(min_pair(&colors) > before);
+        assert!(min_pair(&colors) >= 10.0);
+    }
+
+    #[test]
+    fn quantize_snaps_to_web_safe_steps() {
+        let web_safe = web_safe_palette();
+        assert_eq!(web_safe.len(), 216);
+
+        let on_grid = Srgb8::new(0x33, 0xcc, 0x99);
+        let off_grid = [Srgb8::new(0xfe, 0x02, 0x01), Srgb8::new(0x36, 0xc9, 0x9c)];
+        let quantized = quantize(&[on_grid, off_grid[0], off_grid[1]], &web_safe);
+
+        assert_eq!(quantized[0], on_grid);
+        assert_eq!(quantized[1], Srgb8::new(0xff, 0x00, 0x00));
+        assert_eq!(quantized[2], on_grid);
+        assert_eq!(quantize(&off_grid, &[]), off_grid.to_vec());
+    }
+
+    #[test]
+    fn nearest_css_name_finds_exact_and_close_colors() {
+        for &(name, color) in &CSS_NAMED_COLORS {
+            let (found, delta) = nearest_css_name(color);
+            assert!(delta < 1e-3, "{name}: ΔE {delta}");
+            assert_eq!(
+                CSS_NAMED_COLORS.iter().find(|(n, _)| *n == found).unwrap().1,
+                color,
+                "{name} -> {found}"
+            );
+        }
+        assert_eq!(nearest_css_name(Srgb8::new(0, 255, 255)).0, "aqua");
+
+        let (name, delta) = nearest_css_name(Srgb8::new(0xfe, 0x01, 0x02));
+        assert_eq!(name, "red");
+        assert!(delta > 0.0 && delta < 2.0, "ΔE {delta}");
+    }
 }
@@ -0,0 +1,281 @@
+//! Editor theme, terminal profile, and documentation exporters for Base16 schemes.
+//!
+//! VS Code token colors reuse the scope-to-slot mapping of [`crate::syntax::base16_to_theme`], so exported themes highlight
+//! code the same way as the terminal previews.
+
+use crate::base16_builder::Variant;
+use crate::colors::Srgb8;
+use crate::syntax::{SCOPE_SLOTS, StyleOptions};
+use crate::tinted_theming::Base16Scheme;
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use syntect::highlighting::FontStyle;
+
+/// VS Code workbench color keys and the Base16 slot that fills each.
+const VSCODE_COLOR_SLOTS: [(&str, usize); 36] = [
+    ("editor.background", 0x00),
+    ("editor.foreground", 0x05),
+    ("editor.lineHighlightBackground", 0x01),
+    ("editor.selectionBackground", 0x02),
+    ("editor.findMatchHighlightBackground", 0x02),
+    ("editorCursor.foreground", 0x05),
+    ("editorLineNumber.foreground", 0x03),
+    ("editorLineNumber.activeForeground", 0x04),
+    ("editorIndentGuide.background1", 0x02),
+    ("editorWhitespace.foreground", 0x03),
+    ("editorError.foreground", 0x08),
+    ("editorWarning.foreground", 0x09),
+    ("editorInfo.foreground", 0x0D),
+    ("editorGroupHeader.tabsBackground", 0x01),
+    ("tab.activeBackground", 0x00),
+    ("tab.activeForeground", 0x05),
+    ("tab.inactiveBackground", 0x01),
+    ("tab.inactiveForeground", 0x04),
+    ("sideBar.background", 0x01),
+    ("sideBar.foreground", 0x05),
+    ("activityBar.background", 0x01),
+    ("activityBar.foreground", 0x05),
+    ("statusBar.background", 0x01),
+    ("statusBar.foreground", 0x04),
+    ("titleBar.activeBackground", 0x00),
+    ("titleBar.activeForeground", 0x05),
+    ("panel.background", 0x00),
+    ("focusBorder", 0x0D),
+    ("terminal.ansiBlack", 0x00),
+    ("terminal.ansiRed", 0x08),
+    ("terminal.ansiGreen", 0x0B),
+    ("terminal.ansiYellow", 0x0A),
+    ("terminal.ansiBlue", 0x0D),
+    ("terminal.ansiMagenta", 0x0E),
+    ("terminal.ansiCyan", 0x0C),
+    ("terminal.ansiWhite", 0x05),
+];
+
+/// iTerm2 color keys and the Base16 slot that fills each, following the tinted-theming shell mapping: bright ANSI
+/// colors reuse the accents, with base03 as bright black and base07 as bright white.
+const ITERM_COLOR_SLOTS: [(&str, usize); 23] = [
+    ("Ansi 0 Color", 0x00),
+    ("Ansi 1 Color", 0x08),
+    ("Ansi 2 Color", 0x0B),
+    ("Ansi 3 Color", 0x0A),
+    ("Ansi 4 Color", 0x0D),
+    ("Ansi 5 Color", 0x0E),
+    ("Ansi 6 Color", 0x0C),
+    ("Ansi 7 Color", 0x05),
+    ("Ansi 8 Color", 0x03),
+    ("Ansi 9 Color", 0x08),
+    ("Ansi 10 Color", 0x0B),
+    ("Ansi 11 Color", 0x0A),
+    ("Ansi 12 Color", 0x0D),
+    ("Ansi 13 Color", 0x0E),
+    ("Ansi 14 Color", 0x0C),
+    ("Ansi 15 Color", 0x07),
+    ("Background Color", 0x00),
+    ("Foreground Color", 0x05),
+    ("Bold Color", 0x05),
+    ("Cursor Color", 0x05),
+    ("Cursor Text Color", 0x00),
+    ("Selection Color", 0x02),
+    ("Selected Text Color", 0x05),
+];
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VscodeTheme {
+    name: String,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    colors: BTreeMap<&'static str, String>,
+    token_colors: Vec<VscodeTokenColor>,
+}
+
+#[derive(Debug, Serialize)]
+struct VscodeTokenColor {
+    scope: &'static str,
+    settings: VscodeTokenSettings,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VscodeTokenSettings {
+    foreground: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_style: Option<String>,
+}
+
+/// Renders a Base16 scheme as a VS Code color theme (`*-color-theme.json`).
+///
+/// The theme `type` follows the scheme's variant, falling back to base00's lightness when the variant is unset.
+/// Token colors use the default [`StyleOptions`] (italic comments, underlined links).
+pub fn to_vscode_theme(scheme: &Base16Scheme, name: &str) -> String {
+    let colors = scheme.colors();
+    let kind = Variant::infer(scheme.metadata.variant.as_deref(), colors[0]).as_str();
+
+    let options = StyleOptions::default();
+    let theme = VscodeTheme {
+        name: name.to_string(),
+        kind,
+        colors: VSCODE_COLOR_SLOTS
+            .iter()
+            .map(|&(key, slot)| (key, colors[slot].to_hex()))
+            .collect(),
+        token_colors: SCOPE_SLOTS
+            .iter()
+            .filter(|(scope, _)| !scope.is_empty())
+            .map(|&(scope, slot)| VscodeTokenColor {
+                scope,
+                settings: VscodeTokenSettings {
+                    foreground: colors[slot].to_hex(),
+                    font_style: vscode_font_style(options.scope_style(scope)),
+                },
+            })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&theme).expect("VS Code theme serializes to JSON")
+}
+
+/// Spells a font style the way VS Code's `fontStyle` expects, e.g. `"bold italic"`.
+fn vscode_font_style(style: FontStyle) -> Option<String> {
+    let names: Vec<&str> = [
+        (FontStyle::BOLD, "bold"),
+        (FontStyle::ITALIC, "italic"),
+        (FontStyle::UNDERLINE, "underline"),
+    ]
+    .into_iter()
+    .filter(|&(flag, _)| style.contains(flag))
+    .map(|(_, name)| name)
+    .collect();
+    (!names.is_empty()).then(|| names.join(" "))
+}
+
+/// Renders a Base16 scheme as an iTerm2 color preset (`*.itermcolors` XML property list).
+///
+/// Components are sRGB floats in [0, 1], tagged with the sRGB color space so iTerm2 doesn't treat them as device RGB.
+pub fn to_itermcolors(scheme: &Base16Scheme) -> String {
+    let colors = scheme.colors();
+    let mut plist = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+        "<plist version=\"1.0\">\n",
+        "<dict>\n",
+    ));
+    for &(key, slot) in &ITERM_COLOR_SLOTS {
+        plist.push_str(&format!("\t<key>{key}</key>\n{}", iterm_color_dict(colors[slot])));
+    }
+    plist.push_str("</dict>\n</plist>\n");
+    plist
+}
+
+/// One iTerm2 color entry: a dict of float components in the sRGB color space.
+fn iterm_color_dict(color: Srgb8) -> String {
+    let component = |value: u8| f32::from(value) / 255.0;
+    format!(
+        concat!(
+            "\t<dict>\n",
+            "\t\t<key>Alpha Component</key>\n\t\t<real>1</real>\n",
+            "\t\t<key>Blue Component</key>\n\t\t<real>{:.6}</real>\n",
+            "\t\t<key>Color Space</key>\n\t\t<string>sRGB</string>\n",
+            "\t\t<key>Green Component</key>\n\t\t<real>{:.6}</real>\n",
+            "\t\t<key>Red Component</key>\n\t\t<real>{:.6}</real>\n",
+            "\t</dict>\n",
+        ),
+        component(color.b),
+        component(color.g),
+        component(color.r),
+    )
+}
+
+/// Renders a Base16 scheme as a markdown table of slots, hex codes, and swatches for READMEs.
+///
+/// Swatches are shields.io badge images rather than inline styles, since GitHub strips `style` attributes.
+pub fn to_markdown_table(scheme: &Base16Scheme) -> String {
+    let mut table = String::from("| Key | Hex | Swatch |\n| --- | --- | --- |\n");
+    for (slot, color) in scheme.colors().iter().enumerate() {
+        let hex = color.to_hex();
+        let code = hex.trim_start_matches('#');
+        table.push_str(&format!(
+            "| base{slot:02X} | `{hex}` | ![{hex}](https://img.shields.io/badge/-%20%20%20%20-{code}?style=flat-square) |\n"
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tinted_theming::load_base16_schemes;
+
+    #[test]
+    fn vscode_theme_maps_editor_colors_and_tokens() {
+        let scheme = load_base16_schemes("../examples/base16/oxocarbon-dark.yml")
+            .unwrap()
+            .remove(0);
+        let json = to_vscode_theme(&scheme, "Oxocarbon");
+        let theme: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(theme["name"], "Oxocarbon");
+        assert_eq!(theme["type"], "dark");
+        assert_eq!(theme["colors"]["editor.background"], scheme.colors()[0].to_hex());
+        assert_eq!(theme["colors"]["editor.foreground"], scheme.colors()[5].to_hex());
+
+        let tokens = theme["tokenColors"].as_array().unwrap();
+        assert_eq!(tokens.len(), SCOPE_SLOTS.len() - 1);
+        let comment = tokens.iter().find(|token| token["scope"] == "comment").unwrap();
+        assert_eq!(comment["settings"]["foreground"], scheme.colors()[3].to_hex());
+        assert_eq!(comment["settings"]["fontStyle"], "italic");
+        let keyword = tokens.iter().find(|token| token["scope"] == "keyword").unwrap();
+        assert_eq!(keyword["settings"]["foreground"], scheme.colors()[0x0E].to_hex());
+        assert!(keyword["settings"].get("fontStyle").is_none());
+    }
+
+    #[test]
+    fn itermcolors_is_a_plist_of_float_components() {
+        let scheme = load_base16_schemes("../examples/base16/oxocarbon-dark.yml")
+            .unwrap()
+            .remove(0);
+        let plist = to_itermcolors(&scheme);
+
+        assert!(plist.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE plist"));
+        assert!(plist.trim_end().ends_with("</dict>\n</plist>"));
+        assert_eq!(plist.matches("<dict>").count(), plist.matches("</dict>").count());
+        assert_eq!(plist.matches("<string>sRGB</string>").count(), ITERM_COLOR_SLOTS.len());
+        for ansi in 0..16 {
+            assert!(
+                plist.contains(&format!("<key>Ansi {ansi} Color</key>")),
+                "missing Ansi {ansi}"
+            );
+        }
+
+        let background = scheme.colors()[0];
+        let entry = plist.split("<key>Background Color</key>").nth(1).unwrap();
+        let entry = &entry[..entry.find("</dict>").unwrap()];
+        for (key, value) in [("Red", background.r), ("Green", background.g), ("Blue", background.b)] {
+            let real = format!(
+                "<key>{key} Component</key>\n\t\t<real>{:.6}</real>",
+                f32::from(value) / 255.0
+            );
+            assert!(entry.contains(&real), "{real} not in {entry}");
+        }
+    }
+
+    #[test]
+    fn markdown_table_has_a_row_per_slot() {
+        let scheme = load_base16_schemes("../examples/base16/oxocarbon-dark.yml")
+            .unwrap()
+            .remove(0);
+        let table = to_markdown_table(&scheme);
+        let rows: Vec<&str> = table.lines().skip(2).collect();
+
+        assert_eq!(rows.len(), 16);
+        for (slot, row) in rows.iter().enumerate() {
+            let hex = scheme.colors()[slot].to_hex();
+            assert!(row.starts_with(&format!("| base{slot:02X} | `{hex}` |")), "{row}");
+            assert!(
+                row.contains(&format!("-{}?style", hex.trim_start_matches('#'))),
+                "{row}"
+            );
+        }
+    }
+}
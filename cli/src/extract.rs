@@ -0,0 +1,370 @@
+//! Image color analysis and palette extraction.
+//!
+//! Loads image pixels as [`Srgb8`], summarizes how they are distributed by hue or perceptual lightness (which helps
+//! judge an image before pulling a palette out of it), and clusters them into dominant colors with k-means.
+
+use crate::colors::{Hsl, Lab, Rgb, Srgb8};
+use crate::diffs::DeltaEMetric;
+
+use image::{RgbImage, RgbaImage};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Pixels with a (gamma-encoded) HSL saturation below this have no meaningful hue and are skipped by
+/// [`hue_histogram`].
+pub const ACHROMATIC_SATURATION: f32 = 0.05;
+
+/// Upper bound on k-means refinement rounds; clustering usually settles well before this.
+const KMEANS_MAX_ITERATIONS: usize = 50;
+
+/// A dominant color found by [`kmeans`] and the number of pixels assigned to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cluster {
+    pub color: Srgb8,
+    pub count: usize,
+}
+
+/// How transparent pixels are handled when loading an image with an alpha channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphaPolicy {
+    /// Drop alpha and keep the stored color, which is often black for fully transparent pixels.
+    Ignore,
+    /// Blend every pixel over this background (source-over, in linear light).
+    CompositeOver(Srgb8),
+    /// Leave out fully transparent pixels and keep the rest as stored.
+    SkipTransparent,
+}
+
+/// Loads the pixels of the image at `path`, treating alpha according to `alpha`.
+///
+/// Images without an alpha channel load as fully opaque, so every policy returns all of their pixels.
+pub fn load_pixels(path: impl AsRef<Path>, alpha: AlphaPolicy) -> Result<Vec<Srgb8>, image::ImageError> {
+    Ok(rgba_pixels(&image::open(path)?.to_rgba8(), alpha))
+}
+
+/// Collects the pixels of an RGB image in row-major order.
+pub fn image_pixels(image: &RgbImage) -> Vec<Srgb8> {
+    image.pixels().map(|pixel| Srgb8::from(pixel.0)).collect()
+}
+
+/// Collects the pixels of an RGBA image in row-major order, applying `alpha` to each.
+pub fn rgba_pixels(image: &RgbaImage, alpha: AlphaPolicy) -> Vec<Srgb8> {
+    image
+        .pixels()
+        .filter_map(|pixel| {
+            let color = Srgb8::new(pixel[0], pixel[1], pixel[2]);
+            match alpha {
+                AlphaPolicy::Ignore => Some(color),
+                AlphaPolicy::SkipTransparent => (pixel[3] > 0).then_some(color),
+                AlphaPolicy::CompositeOver(background) => {
+                    let (source, backdrop) = (Rgb::from(color), Rgb::from(background));
+                    let coverage = f32::from(pixel[3]) / 255.0;
+                    let over = |s: f32, b: f32| s * coverage + b * (1.0 - coverage);
+                    Some(Srgb8::from(Rgb::new(
+                        over(source.r, backdrop.r),
+                        over(source.g, backdrop.g),
+                        over(source.b, backdrop.b),
+                    )))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Buckets pixels into `bins` equal hue ranges and returns `(center hue, count)` per bin.
+///
+/// Bins are centered on multiples of `360 / bins`, so with 12 bins pure red, yellow, green, cyan, blue, and magenta
+/// each land in the middle of a bin. Achromatic pixels (see [`ACHROMATIC_SATURATION`]) are not counted.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::extract::hue_histogram;
+///
+/// let pixels = [Srgb8::new(255, 0, 0), Srgb8::new(250, 10, 5), Srgb8::new(0, 0, 255)];
+/// let histogram = hue_histogram(&pixels, 12);
+/// assert_eq!(histogram[0], (0.0, 2));
+/// assert_eq!(histogram[8], (240.0, 1));
+/// ```
+pub fn hue_histogram(pixels: &[Srgb8], bins: usize) -> Vec<(f32, usize)> {
+    if bins == 0 {
+        return Vec::new();
+    }
+
+    let width = 360.0 / bins as f32;
+    let mut counts = vec![0; bins];
+    for &pixel in pixels {
+        let hsl = Hsl::from(pixel);
+        if hsl.s < ACHROMATIC_SATURATION {
+            continue;
+        }
+        counts[((hsl.h + width / 2.0) / width) as usize % bins] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (i as f32 * width, count))
+        .collect()
+}
+
+/// Buckets pixels into `bins` equal ranges of Lab lightness (0-100) and returns `(center L, count)` per bin.
+pub fn lightness_histogram(pixels: &[Srgb8], bins: usize) -> Vec<(f32, usize)> {
+    if bins == 0 {
+        return Vec::new();
+    }
+
+    let width = 100.0 / bins as f32;
+    let mut counts = vec![0; bins];
+    for &pixel in pixels {
+        let l = Lab::from(pixel).l.clamp(0.0, 100.0);
+        counts[((l / width) as usize).min(bins - 1)] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| ((i as f32 + 0.5) * width, count))
+        .collect()
+}
+
+/// Groups pixels into at most `k` clusters and returns them, most common first.
+///
+/// Works on the distinct colors weighted by pixel count, so flat images stay cheap. Seeds are picked
+/// deterministically: the most common color, then repeatedly the color farthest from every seed so far. With
+/// [`DeltaEMetric::Cie76`] (straight-line Lab distance) each center moves to its cluster's mean. The other metrics have
+/// no meaningful average, so each center moves to the member with the lowest total distance to the rest of its cluster
+/// (a medoid), which costs time quadratic in a cluster's distinct colors; downscale large photos first.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::diffs::DeltaEMetric;
+/// use colorizer::extract::kmeans;
+///
+/// let pixels = [Srgb8::new(250, 0, 0), Srgb8::new(255, 5, 0), Srgb8::new(0, 0, 255)];
+/// let clusters = kmeans(&pixels, 2, DeltaEMetric::Ciede2000);
+/// assert_eq!(clusters[0].count, 2);
+/// assert_eq!(clusters[1].color, Srgb8::new(0, 0, 255));
+/// ```
+pub fn kmeans(pixels: &[Srgb8], k: usize, metric: DeltaEMetric) -> Vec<Cluster> {
+    let mut counts: HashMap<Srgb8, usize> = HashMap::new();
+    for &pixel in pixels {
+        *counts.entry(pixel).or_default() += 1;
+    }
+    let mut colors: Vec<(Srgb8, usize)> = counts.into_iter().collect();
+    colors.sort_by_key(|&(color, count)| (Reverse(count), color.r, color.g, color.b));
+    let labs: Vec<Lab> = colors.iter().map(|&(color, _)| Lab::from(color)).collect();
+
+    let k = k.min(colors.len());
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut centers = vec![labs[0]];
+    let mut nearest: Vec<f32> = labs.iter().map(|&lab| metric.delta_e(lab, labs[0])).collect();
+    while centers.len() < k {
+        let (farthest, _) = nearest
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .expect("colors is non-empty");
+        centers.push(labs[farthest]);
+        for (distance, &lab) in nearest.iter_mut().zip(&labs) {
+            *distance = distance.min(metric.delta_e(lab, labs[farthest]));
+        }
+    }
+
+    let mut assignments = vec![usize::MAX; labs.len()];
+    for _ in 0..KMEANS_MAX_ITERATIONS {
+        let mut changed = false;
+        for (assignment, &lab) in assignments.iter_mut().zip(&labs) {
+            let (closest, _) = centers
+                .iter()
+                .map(|&center| metric.delta_e(lab, center))
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .expect("k is at least one");
+            if *assignment != closest {
+                *assignment = closest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        for (cluster, center) in centers.iter_mut().enumerate() {
+            let members: Vec<usize> = (0..labs.len()).filter(|&i| assignments[i] == cluster).collect();
+            if members.is_empty() {
+                continue;
+            }
+            *center = match metric {
+                DeltaEMetric::Cie76 => weighted_mean(members.iter().map(|&i| (labs[i], colors[i].1))),
+                DeltaEMetric::Cie94 | DeltaEMetric::Ciede2000 | DeltaEMetric::Cmc => {
+                    let cost = |candidate: usize| {
+                        members
+                            .iter()
+                            .map(|&i| metric.delta_e(labs[candidate], labs[i]) * colors[i].1 as f32)
+                            .sum::<f32>()
+                    };
+                    let (medoid, _) = members
+                        .iter()
+                        .map(|&candidate| (candidate, cost(candidate)))
+                        .min_by(|a, b| a.1.total_cmp(&b.1))
+                        .expect("cluster has members");
+                    labs[medoid]
+                }
+            };
+        }
+    }
+
+    let mut clusters: Vec<Cluster> = centers
+        .iter()
+        .enumerate()
+        .map(|(cluster, &center)| Cluster {
+            color: Srgb8::from(center),
+            count: (0..labs.len())
+                .filter(|&i| assignments[i] == cluster)
+                .map(|i| colors[i].1)
+                .sum(),
+        })
+        .filter(|cluster| cluster.count > 0)
+        .collect();
+    clusters.sort_by_key(|cluster| Reverse(cluster.count));
+    clusters
+}
+
+fn weighted_mean(samples: impl Iterator<Item = (Lab, usize)>) -> Lab {
+    let (mut l, mut a, mut b, mut total) = (0.0, 0.0, 0.0, 0.0);
+    for (lab, weight) in samples {
+        let weight = weight as f32;
+        l += lab.l * weight;
+        a += lab.a * weight;
+        b += lab.b * weight;
+        total += weight;
+    }
+    Lab::new(l / total, a / total, b / total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb as ImgRgb, Rgba};
+
+    /// 30 red, 20 green, and 10 blue pixels plus a strip of gray, stacked in rows.
+    fn synthetic_image() -> RgbImage {
+        RgbImage::from_fn(10, 7, |_, y| match y {
+            0..=2 => ImgRgb([255, 0, 0]),
+            3..=4 => ImgRgb([0, 255, 0]),
+            5 => ImgRgb([0, 0, 255]),
+            _ => ImgRgb([128, 128, 128]),
+        })
+    }
+
+    #[test]
+    fn hue_histogram_counts_known_composition() {
+        let pixels = image_pixels(&synthetic_image());
+        let histogram = hue_histogram(&pixels, 12);
+
+        assert_eq!(histogram.len(), 12);
+        assert_eq!(histogram[0], (0.0, 30));
+        assert_eq!(histogram[4], (120.0, 20));
+        assert_eq!(histogram[8], (240.0, 10));
+        assert_eq!(
+            histogram.iter().map(|&(_, count)| count).sum::<usize>(),
+            60,
+            "gray pixels are skipped"
+        );
+        assert!(hue_histogram(&pixels, 0).is_empty());
+    }
+
+    #[test]
+    fn hue_histogram_wraps_reds_into_first_bin() {
+        let pixels = [Srgb8::new(255, 0, 20), Srgb8::new(255, 20, 0)];
+        assert_eq!(hue_histogram(&pixels, 12)[0].1, 2);
+    }
+
+    #[test]
+    fn kmeans_separates_two_clusters_with_each_metric() {
+        // A warm and a cool cluster, each a small spread of shades around its center.
+        let image = RgbImage::from_fn(8, 8, |x, y| {
+            let jitter = (x * 4 + y * 2) as u8;
+            if y < 4 {
+                ImgRgb([200 + jitter / 2, 60 + jitter, 40])
+            } else {
+                ImgRgb([40, 70 + jitter, 190 + jitter / 2])
+            }
+        });
+        let pixels = image_pixels(&image);
+
+        for metric in [
+            DeltaEMetric::Cie76,
+            DeltaEMetric::Cie94,
+            DeltaEMetric::Ciede2000,
+            DeltaEMetric::Cmc,
+        ] {
+            let clusters = kmeans(&pixels, 2, metric);
+            assert_eq!(clusters.len(), 2, "{metric:?}");
+            assert_eq!(
+                clusters.iter().map(|cluster| cluster.count).collect::<Vec<_>>(),
+                [32, 32],
+                "{metric:?}"
+            );
+
+            let (warm, cool) = if clusters[0].color.r > clusters[0].color.b {
+                (clusters[0].color, clusters[1].color)
+            } else {
+                (clusters[1].color, clusters[0].color)
+            };
+            assert!(warm.r > 190 && warm.b < 60, "{metric:?} warm {warm}");
+            assert!(cool.b > 180 && cool.r < 60, "{metric:?} cool {cool}");
+        }
+        assert!(kmeans(&[], 3, DeltaEMetric::Ciede2000).is_empty());
+    }
+
+    #[test]
+    fn alpha_policies_handle_transparent_quadrant() {
+        // Opaque red, except a fully transparent (stored as black) top-left quadrant and one half-covered pixel.
+        let image = RgbaImage::from_fn(4, 4, |x, y| match (x, y) {
+            (0..=1, 0..=1) => Rgba([0, 0, 0, 0]),
+            (3, 3) => Rgba([255, 0, 0, 128]),
+            _ => Rgba([255, 0, 0, 255]),
+        });
+        let red = Srgb8::new(255, 0, 0);
+        let count = |pixels: &[Srgb8], color: Srgb8| pixels.iter().filter(|&&pixel| pixel == color).count();
+
+        let ignored = rgba_pixels(&image, AlphaPolicy::Ignore);
+        assert_eq!(ignored.len(), 16);
+        assert_eq!(count(&ignored, Srgb8::new(0, 0, 0)), 4);
+
+        let skipped = rgba_pixels(&image, AlphaPolicy::SkipTransparent);
+        assert_eq!(skipped.len(), 12);
+        assert_eq!(count(&skipped, red), 12);
+
+        let white = Srgb8::new(255, 255, 255);
+        let composited = rgba_pixels(&image, AlphaPolicy::CompositeOver(white));
+        assert_eq!(composited.len(), 16);
+        assert_eq!(count(&composited, white), 4);
+        assert_eq!(count(&composited, red), 11);
+        let half = composited[15];
+        assert_eq!(half.r, 255);
+        assert!(half.g > 150 && half.g == half.b, "half-covered pixel {half}");
+    }
+
+    #[test]
+    fn lightness_histogram_counts_every_pixel() {
+        let pixels = image_pixels(&synthetic_image());
+        let histogram = lightness_histogram(&pixels, 4);
+
+        assert_eq!(histogram.len(), 4);
+        assert_eq!(histogram.iter().map(|&(_, count)| count).sum::<usize>(), 70);
+        assert_eq!(histogram[0], (12.5, 0));
+        assert_eq!(histogram[1], (37.5, 10), "blue (L≈32)");
+        assert_eq!(histogram[2], (62.5, 40), "red (L≈53) and gray (L≈54)");
+        assert_eq!(histogram[3], (87.5, 20), "green (L≈88)");
+    }
+}
@@ -4,7 +4,8 @@
 //! traditional color theory rules. All harmonies are computed by rotating the hue angle
 //! in HSL space while optionally adjusting saturation and lightness.
 
-use crate::colors::{Hsl, Rgb, clamp01};
+use crate::colors::{Hsl, Hsv, Lch, Rgb, Srgb, Srgb8, clamp01, map_to_gamut};
+use crate::wcag::relative_luminance;
 
 /// Defines different types of color harmonies based on traditional color theory.
 ///
@@ -15,88 +16,133 @@ pub enum HarmonyKind {
     Complementary,
     /// Split-complementary: base + two colors adjacent to complement (H+150�, H+210�)
     SplitComplementary,
-    /// Analogous: base + adjacent colors (H��), default �=30�
-    Analogous(f32),
+    /// Analogous: `count` colors stepped by `angle` on both sides of the base, in hue order.
+    ///
+    /// `Analogous { angle: 30.0, count: 3 }` gives H-30°, H, H+30°; count 5 adds H±60°. An even count puts the extra
+    /// color on the positive side.
+    Analogous { angle: f32, count: usize },
     /// Triadic: three evenly spaced colors (H+0�, H+120�, H+240�)
     Triadic,
     /// Tetradic (rectangle): four colors forming rectangle (H+0�, H+60�, H+180�, H+240�)
     Tetradic,
     /// Square: four evenly spaced colors (H+0�, H+90�, H+180�, H+270�)
     Square,
+    /// Rectangle: two complementary pairs separated by a custom offset (H+0°, H+θ, H+180°, H+180°+θ)
+    ///
+    /// `Rectangle(60.0)` matches [`HarmonyKind::Tetradic`] and `Rectangle(90.0)` matches [`HarmonyKind::Square`].
+    Rectangle(f32),
 }
 
-/// Converts RGB to HSL color space.
+/// Converts linear RGB to HSL color space.
 ///
 /// HSL (Hue, Saturation, Lightness) is a cylindrical representation where:
-/// - Hue represents the color angle (0-360�)
+/// - Hue represents the color angle (0-360°)
 /// - Saturation represents color intensity (0-1)
 /// - Lightness represents brightness (0-1)
 ///
-/// This implementation uses the standard RGB�HSL algorithm:
+/// This implementation uses the standard RGB→HSL algorithm:
 /// 1. Find max and min RGB components
 /// 2. Calculate lightness as (max + min) / 2
 /// 3. Calculate saturation based on lightness and delta
 /// 4. Calculate hue based on which component is maximum
+///
+/// NOTE: the input is *linear* light, so the resulting numbers differ from the HSL shown by CSS and design tools,
+/// which compute HSL on gamma-encoded sRGB. Use `Hsl::from(Srgb8)` to match those tools.
 impl From<Rgb> for Hsl {
     fn from(rgb: Rgb) -> Self {
-        let r = rgb.r;
-        let g = rgb.g;
-        let b = rgb.b;
-
-        let max = r.max(g).max(b);
-        let min = r.min(g).min(b);
-        let delta = max - min;
-
-        let l = (max + min) / 2.0;
-
-        if delta < 1e-10 {
-            return Hsl::new(0.0, 0.0, l);
-        }
+        hsl_from_components(rgb.r, rgb.g, rgb.b)
+    }
+}
 
-        let s = if l < 0.5 { delta / (max + min) } else { delta / (2.0 - max - min) };
+/// Converts HSL to linear RGB color space.
+///
+/// Uses the standard HSL→RGB algorithm with helper function for calculating
+/// RGB components from hue, chroma, and adjusted value.
+///
+/// NOTE: the output is *linear* light; this is the inverse of `Hsl::from(Rgb)`, not of CSS `hsl()`.
+/// Use `Srgb8::from(Hsl)` for the gamma-encoded (CSS/Photoshop) interpretation.
+impl From<Hsl> for Rgb {
+    fn from(hsl: Hsl) -> Self {
+        let (r, g, b) = hsl_to_components(hsl);
+        Rgb::new(r, g, b)
+    }
+}
 
-        let h = if (max - r).abs() < 1e-10 {
-            ((g - b) / delta + if g < b { 6.0 } else { 0.0 }) * 60.0
-        } else if (max - g).abs() < 1e-10 {
-            ((b - r) / delta + 2.0) * 60.0
-        } else {
-            ((r - g) / delta + 4.0) * 60.0
-        };
+/// Converts 8-bit sRGB to HSL computed on the gamma-encoded values.
+///
+/// This matches the HSL numbers reported by CSS, Photoshop, and most design tools.
+impl From<Srgb8> for Hsl {
+    fn from(color: Srgb8) -> Self {
+        let srgb = Srgb::from(color);
+        hsl_from_components(srgb.r, srgb.g, srgb.b)
+    }
+}
 
-        Hsl::new(h, s, l)
+/// Converts HSL (interpreted on gamma-encoded sRGB, as in CSS) to float sRGB, without 8-bit rounding.
+impl From<Hsl> for Srgb {
+    fn from(hsl: Hsl) -> Self {
+        let (r, g, b) = hsl_to_components(hsl);
+        Srgb::new(r, g, b)
     }
 }
 
-/// Converts HSL to RGB color space.
+/// Converts HSL (interpreted on gamma-encoded sRGB, as in CSS) to 8-bit sRGB.
 ///
-/// Uses the standard HSL�RGB algorithm with helper function for calculating
-/// RGB components from hue, chroma, and adjusted value.
-impl From<Hsl> for Rgb {
+/// Inverse of `Hsl::from(Srgb8)`; round-trips to within 1/255 per channel.
+impl From<Hsl> for Srgb8 {
     fn from(hsl: Hsl) -> Self {
-        let h = hsl.h;
-        let s = hsl.s;
-        let l = hsl.l;
+        Srgb8::from(Srgb::from(hsl))
+    }
+}
 
-        if s < 1e-10 {
-            return Rgb::new(l, l, l);
-        }
+fn hsl_from_components(r: f32, g: f32, b: f32) -> Hsl {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let l = (max + min) / 2.0;
+
+    if delta < 1e-10 {
+        return Hsl::new(0.0, 0.0, l);
+    }
+
+    let s = if l < 0.5 { delta / (max + min) } else { delta / (2.0 - max - min) };
 
-        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
-        let h_prime = h / 60.0;
-        let x = c * (1.0 - ((h_prime % 2.0) - 1.0).abs());
+    let h = if (max - r).abs() < 1e-10 {
+        ((g - b) / delta + if g < b { 6.0 } else { 0.0 }) * 60.0
+    } else if (max - g).abs() < 1e-10 {
+        ((b - r) / delta + 2.0) * 60.0
+    } else {
+        ((r - g) / delta + 4.0) * 60.0
+    };
+
+    Hsl::new(h, s, l)
+}
 
-        let (r1, g1, b1) = match h_prime as i32 {
-            0 => (c, x, 0.0),
-            1 => (x, c, 0.0),
-            2 => (0.0, c, x),
-            3 => (0.0, x, c),
-            4 => (x, 0.0, c),
-            _ => (c, 0.0, x),
-        };
+fn hsl_to_components(hsl: Hsl) -> (f32, f32, f32) {
+    let h = hsl.h;
+    let s = hsl.s;
+    let l = hsl.l;
 
-        let m = l - c / 2.0;
-        Rgb::new(r1 + m, g1 + m, b1 + m)
+    if s < 1e-10 {
+        return (l, l, l);
     }
+
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h_prime = h / 60.0;
+    let x = c * (1.0 - ((h_prime % 2.0) - 1.0).abs());
+
+    let (r1, g1, b1) = match h_prime as i32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    let m = l - c / 2.0;
+    (r1 + m, g1 + m, b1 + m)
 }
 
 /// Generates a color harmony palette from a base HSL color.
@@ -119,39 +165,45 @@ impl From<Hsl> for Rgb {
 /// // Returns [base, complement at 360�]
 /// ```
 pub fn harmonies(base: Hsl, kind: HarmonyKind) -> Vec<Hsl> {
-    let h = base.h;
-    let s = base.s;
-    let l = base.l;
+    hue_offsets(kind)
+        .into_iter()
+        .map(|offset| if offset == 0.0 { base } else { Hsl::new(base.h + offset, base.s, base.l) })
+        .collect()
+}
+
+/// Generates a color harmony palette from a base HSV color.
+///
+/// Uses the same hue relationships as [`harmonies`] while carrying saturation and value through unchanged, which
+/// keeps every harmony color at the base's brightness for vivid UI palettes.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::{colors::Hsv, HarmonyKind, harmonies_hsv};
+///
+/// let palette = harmonies_hsv(Hsv::new(200.0, 0.8, 0.9), HarmonyKind::Triadic);
+/// assert_eq!(palette.len(), 3);
+/// ```
+pub fn harmonies_hsv(base: Hsv, kind: HarmonyKind) -> Vec<Hsv> {
+    hue_offsets(kind)
+        .into_iter()
+        .map(|offset| if offset == 0.0 { base } else { Hsv::new(base.h + offset, base.s, base.v) })
+        .collect()
+}
 
+/// Hue offsets (in degrees, relative to the base) of each color produced by a harmony, in output order.
+fn hue_offsets(kind: HarmonyKind) -> Vec<f32> {
     match kind {
-        HarmonyKind::Complementary => {
-            vec![base, Hsl::new(h + 180.0, s, l)]
-        }
-        HarmonyKind::SplitComplementary => {
-            vec![base, Hsl::new(h + 150.0, s, l), Hsl::new(h + 210.0, s, l)]
-        }
-        HarmonyKind::Analogous(angle) => {
-            vec![Hsl::new(h - angle, s, l), base, Hsl::new(h + angle, s, l)]
-        }
-        HarmonyKind::Triadic => {
-            vec![base, Hsl::new(h + 120.0, s, l), Hsl::new(h + 240.0, s, l)]
-        }
-        HarmonyKind::Tetradic => {
-            vec![
-                base,
-                Hsl::new(h + 60.0, s, l),
-                Hsl::new(h + 180.0, s, l),
-                Hsl::new(h + 240.0, s, l),
-            ]
-        }
-        HarmonyKind::Square => {
-            vec![
-                base,
-                Hsl::new(h + 90.0, s, l),
-                Hsl::new(h + 180.0, s, l),
-                Hsl::new(h + 270.0, s, l),
-            ]
+        HarmonyKind::Complementary => vec![0.0, 180.0],
+        HarmonyKind::SplitComplementary => vec![0.0, 150.0, 210.0],
+        HarmonyKind::Analogous { angle, count } => {
+            let below = count.saturating_sub(1) / 2;
+            (0..count).map(|i| (i as f32 - below as f32) * angle).collect()
         }
+        HarmonyKind::Triadic => vec![0.0, 120.0, 240.0],
+        HarmonyKind::Tetradic => vec![0.0, 60.0, 180.0, 240.0],
+        HarmonyKind::Square => vec![0.0, 90.0, 180.0, 270.0],
+        HarmonyKind::Rectangle(offset) => vec![0.0, offset, 180.0, 180.0 + offset],
     }
 }
 
@@ -226,6 +278,72 @@ pub fn set_lightness(colors: &mut [Hsl], lightness: f32) {
     }
 }
 
+/// Saturation below which an HSL color is treated as a neutral gray.
+const GRAY_SATURATION: f32 = 0.02;
+
+/// Chroma below which an Lch color is treated as a neutral gray.
+const GRAY_CHROMA: f32 = 2.0;
+
+/// Bisection steps [`rotate_hue_preserve_luminance`] spends matching the original luminance.
+const LUMINANCE_SEARCH_STEPS: usize = 20;
+
+/// Rotates the hue of every chromatic color in a palette by a fixed number of degrees.
+///
+/// Useful for deriving a hue-shifted variant of a finished theme while keeping its structure.
+/// Near-gray colors are left untouched so neutrals don't pick up a tint.
+///
+/// # Arguments
+///
+/// * `colors` - Mutable slice of HSL colors to rotate
+/// * `degrees` - Hue offset in degrees (negative rotates backwards)
+pub fn rotate_hue(colors: &mut [Hsl], degrees: f32) {
+    for color in colors.iter_mut().filter(|color| color.s >= GRAY_SATURATION) {
+        *color = Hsl::new(color.h + degrees, color.s, color.l);
+    }
+}
+
+/// Rotates the hue of every chromatic color in a palette in Lch space.
+///
+/// Unlike [`rotate_hue`], lightness and chroma are perceptual, so rotated colors keep their apparent brightness.
+/// Near-gray colors are left untouched so neutrals don't pick up a tint.
+pub fn rotate_hue_lch(colors: &mut [Lch], degrees: f32) {
+    for color in colors.iter_mut().filter(|color| color.c >= GRAY_CHROMA) {
+        *color = Lch::new(color.l, color.c, color.h + degrees);
+    }
+}
+
+/// Rotates a color's Lch hue by `degrees` while keeping its WCAG relative luminance, so contrast against any
+/// background is unchanged.
+///
+/// The rotated color is gamut-mapped by reducing chroma (see [`map_to_gamut`]), then its Lch lightness is fine-tuned
+/// by bisection so the 8-bit result lands as close as possible to the original luminance. Near-gray colors are
+/// returned as-is, as in [`rotate_hue_lch`].
+pub fn rotate_hue_preserve_luminance(color: Srgb8, degrees: f32) -> Srgb8 {
+    let lch = Lch::from(color);
+    if lch.c < GRAY_CHROMA {
+        return color;
+    }
+
+    let target = relative_luminance(color);
+    let rotate = |l: f32| map_to_gamut(Lch::new(l, lch.c, lch.h + degrees));
+    let mut best = rotate(lch.l);
+    let (mut low, mut high) = (0.0, 100.0);
+    for _ in 0..LUMINANCE_SEARCH_STEPS {
+        let mid = (low + high) / 2.0;
+        let candidate = rotate(mid);
+        let luminance = relative_luminance(candidate);
+        if (luminance - target).abs() < (relative_luminance(best) - target).abs() {
+            best = candidate;
+        }
+        if luminance < target {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    best
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -361,7 +479,7 @@ mod tests {
     #[test]
     fn test_analogous_harmony() {
         let base = Hsl::new(180.0, 0.5, 0.5);
-        let palette = harmonies(base, HarmonyKind::Analogous(30.0));
+        let palette = harmonies(base, HarmonyKind::Analogous { angle: 30.0, count: 3 });
 
         assert_eq!(palette.len(), 3);
         assert!(approx_eq(palette[0].h, 150.0));
@@ -369,6 +487,34 @@ mod tests {
         assert!(approx_eq(palette[2].h, 210.0));
     }
 
+    #[test]
+    fn analogous_count_steps_both_sides_of_base() {
+        let base = Hsl::new(100.0, 0.5, 0.5);
+        let hues: Vec<f32> = harmonies(base, HarmonyKind::Analogous { angle: 20.0, count: 5 })
+            .iter()
+            .map(|c| c.h)
+            .collect();
+        assert_eq!(hues.len(), 5);
+        for (hue, expected) in hues.iter().zip([60.0, 80.0, 100.0, 120.0, 140.0]) {
+            assert!(approx_eq(*hue, expected), "{hues:?}");
+        }
+
+        assert_eq!(
+            harmonies(base, HarmonyKind::Analogous { angle: 20.0, count: 1 }),
+            vec![base]
+        );
+        let even: Vec<f32> = harmonies(base, HarmonyKind::Analogous { angle: 20.0, count: 4 })
+            .iter()
+            .map(|c| c.h)
+            .collect();
+        assert!(
+            even.iter()
+                .zip([80.0, 100.0, 120.0, 140.0])
+                .all(|(h, e)| approx_eq(*h, e)),
+            "{even:?}"
+        );
+    }
+
     #[test]
     fn test_triadic_harmony() {
         let base = Hsl::new(0.0, 0.5, 0.5);
@@ -404,6 +550,37 @@ mod tests {
         assert!(approx_eq(palette[3].h, 270.0));
     }
 
+    #[test]
+    fn test_rectangle_harmony_matches_fixed_variants() {
+        let base = Hsl::new(200.0, 0.5, 0.5);
+
+        assert_eq!(
+            harmonies(base, HarmonyKind::Rectangle(90.0)),
+            harmonies(base, HarmonyKind::Square)
+        );
+        assert_eq!(
+            harmonies(base, HarmonyKind::Rectangle(60.0)),
+            harmonies(base, HarmonyKind::Tetradic)
+        );
+
+        let palette = harmonies(base, HarmonyKind::Rectangle(30.0));
+        assert_eq!(palette.len(), 4);
+        assert!(approx_eq(palette[1].h, 230.0));
+        assert!(approx_eq(palette[2].h, 20.0));
+        assert!(approx_eq(palette[3].h, 50.0));
+    }
+
+    #[test]
+    fn test_complementary_hsv_preserves_saturation_and_value() {
+        let base = Hsv::new(30.0, 0.8, 0.6);
+        let palette = harmonies_hsv(base, HarmonyKind::Complementary);
+
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette[0], base);
+        assert!(approx_eq(palette[1].h, 210.0));
+        assert!(approx_eq(palette[1].s, 0.8) && approx_eq(palette[1].v, 0.6));
+    }
+
     #[test]
     fn test_hue_wrapping_in_harmonies() {
         let base = Hsl::new(350.0, 0.5, 0.5);
@@ -469,4 +646,80 @@ mod tests {
         normalize_saturation(&mut palette, -0.1, 1.5);
         assert!(palette[0].s >= 0.0 && palette[0].s <= 1.0);
     }
+
+    #[test]
+    fn test_srgb8_to_hsl_matches_css() {
+        let orange = Srgb8::from_hex("#ff8000").unwrap();
+        let hsl = Hsl::from(orange);
+        assert!((hsl.h - 30.0).abs() < 0.5, "Hue: {}", hsl.h);
+        assert!(approx_eq(hsl.s, 1.0));
+        assert!(approx_eq(hsl.l, 0.5));
+
+        let linear_hsl = Hsl::from(Rgb::from(orange));
+        assert!(linear_hsl.h < hsl.h, "Linear HSL should differ from sRGB HSL");
+    }
+
+    #[test]
+    fn test_srgb8_hsl_round_trip_is_lossless() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(51) {
+                    let color = Srgb8::new(r, g, b);
+                    let back = Srgb8::from(Hsl::from(color));
+                    assert!((back.r as i32 - color.r as i32).abs() <= 1, "{color} -> {back}");
+                    assert!((back.g as i32 - color.g as i32).abs() <= 1, "{color} -> {back}");
+                    assert!((back.b as i32 - color.b as i32).abs() <= 1, "{color} -> {back}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_hue_skips_grays() {
+        let gray = Hsl::new(200.0, 0.0, 0.5);
+        let mut palette = vec![gray, Hsl::new(10.0, 0.8, 0.5), Hsl::new(340.0, 0.6, 0.4)];
+        rotate_hue(&mut palette, 40.0);
+
+        assert_eq!(palette[0], gray);
+        assert!(approx_eq(palette[1].h, 50.0));
+        assert!(approx_eq(palette[2].h, 20.0));
+        assert!(approx_eq(palette[1].s, 0.8) && approx_eq(palette[1].l, 0.5));
+    }
+
+    #[test]
+    fn test_rotate_hue_lch_skips_grays() {
+        let gray = Lch::new(50.0, 0.5, 90.0);
+        let mut palette = vec![gray, Lch::new(60.0, 40.0, 30.0)];
+        rotate_hue_lch(&mut palette, -45.0);
+
+        assert_eq!(palette[0], gray);
+        assert!(approx_eq(palette[1].h, 345.0));
+        assert!(approx_eq(palette[1].c, 40.0) && approx_eq(palette[1].l, 60.0));
+    }
+
+    #[test]
+    fn test_rotate_hue_preserve_luminance_keeps_contrast() {
+        let background = Srgb8::new(0x1e, 0x1e, 0x2e);
+        for color in [
+            Srgb8::new(220, 50, 47),
+            Srgb8::new(38, 139, 210),
+            Srgb8::new(250, 200, 40),
+        ] {
+            for degrees in [60.0, 135.0, -90.0] {
+                let rotated = rotate_hue_preserve_luminance(color, degrees);
+                let (before, after) = (relative_luminance(color), relative_luminance(rotated));
+                assert!(
+                    (before - after).abs() < 0.005,
+                    "{color} by {degrees}: {before} vs {after}"
+                );
+
+                let contrast = crate::wcag::contrast_ratio(rotated, background);
+                assert!((contrast - crate::wcag::contrast_ratio(color, background)).abs() < 0.1);
+                assert_ne!(rotated, color);
+            }
+        }
+
+        let gray = Srgb8::new(128, 128, 128);
+        assert_eq!(rotate_hue_preserve_luminance(gray, 90.0), gray);
+    }
 }
@@ -0,0 +1,436 @@
+//! HCT (Hue, Chroma, Tone), the color space behind Material Design 3 dynamic color.
+//!
+//! Hue and chroma come from the CAM16 color appearance model under Material's default viewing conditions, and tone
+//! is CIELAB L*, so two colors with the same tone always have the same contrast against any third color. Tonal
+//! palettes hold hue and chroma fixed and walk tone from 0 (black) to 100 (white).
+
+use crate::colors::{Rgb, Srgb8};
+use crate::conversions::{D65_X, D65_Y, D65_Z, lab_f, lab_f_inv, mul3};
+
+use std::f64::consts::PI;
+use std::sync::OnceLock;
+
+/// Tone stops of a Material 3 tonal palette, in the order [`tonal_palette`] returns them.
+pub const TONAL_PALETTE_TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+/// Chroma resolution of the gamut search in `Srgb8::from(Hct)`.
+const CHROMA_SEARCH_ENDPOINT: f64 = 0.4;
+/// J resolution of the per-chroma lightness search.
+const LIGHTNESS_SEARCH_ENDPOINT: f64 = 0.01;
+/// Largest tone error accepted for a gamut-clipped candidate.
+const DL_MAX: f64 = 0.2;
+/// Largest CAM16-UCS distance accepted between a clipped candidate and the requested hue.
+const DE_MAX: f64 = 1.0;
+
+/// Material's sRGB to XYZ matrix. It differs from `conversions::RGB_TO_XYZ` in the fifth decimal, enough to move
+/// tonal palette stops by one 8-bit step, so HCT keeps it to match Material's reference output.
+const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.41233895, 0.35762064, 0.18051042],
+    [0.2126, 0.7152, 0.0722],
+    [0.01932141, 0.11916382, 0.95034478],
+];
+
+/// Inverse of `SRGB_TO_XYZ`.
+const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [3.2413774792388685, -1.5376652402851851, -0.49885366846268053],
+    [-0.9691452513005321, 1.8758853451067872, 0.04156585616912061],
+    [0.05562093689691305, -0.20395524564742123, 1.0571799111220335],
+];
+
+const XYZ_TO_CAM16RGB: [[f64; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+const CAM16RGB_TO_XYZ: [[f64; 3]; 3] = [
+    [1.8620678, -1.0112547, 0.14918678],
+    [0.38752654, 0.62144744, -0.00897398],
+    [-0.0158415, -0.03412294, 1.0499644],
+];
+
+/// Color in HCT space.
+///
+/// - `h` is CAM16 hue in degrees [0, 360)
+/// - `c` is CAM16 chroma (0 = gray; the most saturated sRGB colors reach roughly 110-130)
+/// - `t` is tone, CIELAB L* in [0, 100]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hct {
+    pub h: f32,
+    pub c: f32,
+    pub t: f32,
+}
+
+impl Hct {
+    /// Creates an HCT color, wrapping hue to [0, 360) and clamping chroma to >= 0 and tone to [0, 100].
+    ///
+    /// Not every combination is displayable; converting to [`Srgb8`] keeps hue and tone and reduces chroma to fit.
+    pub fn new(h: f32, c: f32, t: f32) -> Self {
+        Self { h: crate::colors::wrap_degrees(h), c: c.max(0.0), t: t.clamp(0.0, 100.0) }
+    }
+}
+
+impl From<Rgb> for Hct {
+    fn from(rgb: Rgb) -> Self {
+        let xyz = mul3(
+            &SRGB_TO_XYZ,
+            [rgb.r as f64 * 100.0, rgb.g as f64 * 100.0, rgb.b as f64 * 100.0],
+        );
+        let cam = Cam16::from_xyz(xyz);
+        Self::new(cam.hue as f32, cam.chroma as f32, lstar_from_y(xyz[1]) as f32)
+    }
+}
+
+impl From<Srgb8> for Hct {
+    fn from(color: Srgb8) -> Self {
+        Hct::from(Rgb::from(color))
+    }
+}
+
+impl From<Hct> for Srgb8 {
+    /// Finds the sRGB color with the requested hue and tone, lowering chroma until it fits in gamut.
+    fn from(hct: Hct) -> Self {
+        let (hue, chroma, tone) = (hct.h as f64, hct.c as f64, hct.t as f64);
+        if chroma < 1.0 || tone.round() <= 0.0 || tone.round() >= 100.0 {
+            return gray_from_lstar(tone);
+        }
+
+        let (mut low, mut high, mut mid) = (0.0_f64, chroma, chroma);
+        let mut answer = None;
+        let mut first_try = true;
+        while (low - high).abs() >= CHROMA_SEARCH_ENDPOINT {
+            let candidate = find_cam_by_j(hue, mid, tone);
+            if first_try {
+                if let Some(cam) = candidate {
+                    return cam.to_srgb8();
+                }
+                first_try = false;
+            } else if candidate.is_none() {
+                high = mid;
+            } else {
+                answer = candidate;
+                low = mid;
+            }
+            mid = low + (high - low) / 2.0;
+        }
+
+        answer.map_or_else(|| gray_from_lstar(tone), |cam| cam.to_srgb8())
+    }
+}
+
+/// Generates the 13-stop Material 3 tonal palette for a hue and chroma (see [`TONAL_PALETTE_TONES`]).
+///
+/// Tones that cannot hold the requested chroma (very dark or very light ones, usually) keep the hue at the highest
+/// chroma sRGB allows; tone 0 and 100 are always black and white.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::hct::tonal_palette;
+///
+/// let palette = tonal_palette(25.0, 84.0);
+/// assert_eq!(palette[0], Srgb8::new(0, 0, 0));
+/// assert_eq!(palette[12], Srgb8::new(255, 255, 255));
+/// ```
+pub fn tonal_palette(hue: f32, chroma: f32) -> [Srgb8; 13] {
+    TONAL_PALETTE_TONES.map(|tone| Srgb8::from(Hct::new(hue, chroma, tone as f32)))
+}
+
+/// Material's default viewing conditions: D65 white, a gray-world background at L* 50, and an average surround.
+struct ViewingConditions {
+    n: f64,
+    aw: f64,
+    nbb: f64,
+    ncb: f64,
+    c: f64,
+    nc: f64,
+    rgb_d: [f64; 3],
+    fl: f64,
+    fl_root: f64,
+    z: f64,
+}
+
+impl ViewingConditions {
+    fn default_conditions() -> &'static Self {
+        static CONDITIONS: OnceLock<ViewingConditions> = OnceLock::new();
+        CONDITIONS.get_or_init(|| {
+            let adapting_luminance = 200.0 / PI * y_from_lstar(50.0) / 100.0;
+            let background_lstar = 50.0;
+            let surround = 2.0;
+
+            let rgb_w = mul3(&XYZ_TO_CAM16RGB, [D65_X, D65_Y, D65_Z].map(|v| v * 100.0));
+            let f = 0.8 + surround / 10.0;
+            let c = if f >= 0.9 { lerp(0.59, 0.69, (f - 0.9) * 10.0) } else { lerp(0.525, 0.59, (f - 0.8) * 10.0) };
+            let d = (f * (1.0 - (1.0 / 3.6) * ((-adapting_luminance - 42.0) / 92.0).exp())).clamp(0.0, 1.0);
+            let rgb_d = rgb_w.map(|w| d * (100.0 / w) + 1.0 - d);
+
+            let k = 1.0 / (5.0 * adapting_luminance + 1.0);
+            let k4 = k.powi(4);
+            let k4f = 1.0 - k4;
+            let fl = k4 * adapting_luminance + 0.1 * k4f * k4f * (5.0 * adapting_luminance).cbrt();
+
+            let n = y_from_lstar(background_lstar) / (D65_Y * 100.0);
+            let z = 1.48 + n.sqrt();
+            let nbb = 0.725 / n.powf(0.2);
+
+            let rgb_a = [0, 1, 2].map(|i| {
+                let factor = (fl * rgb_d[i] * rgb_w[i] / 100.0).powf(0.42);
+                400.0 * factor / (factor + 27.13)
+            });
+            let aw = (2.0 * rgb_a[0] + rgb_a[1] + 0.05 * rgb_a[2]) * nbb;
+
+            ViewingConditions { n, aw, nbb, ncb: nbb, c, nc: f, rgb_d, fl, fl_root: fl.powf(0.25), z }
+        })
+    }
+}
+
+/// CAM16 appearance correlates needed to search for and compare colors.
+#[derive(Debug, Clone, Copy)]
+struct Cam16 {
+    hue: f64,
+    chroma: f64,
+    j: f64,
+    jstar: f64,
+    astar: f64,
+    bstar: f64,
+}
+
+impl Cam16 {
+    fn from_xyz(xyz: [f64; 3]) -> Self {
+        let vc = ViewingConditions::default_conditions();
+        let rgb_c = mul3(&XYZ_TO_CAM16RGB, xyz);
+        let rgb_a = [0, 1, 2].map(|i| {
+            let d = vc.rgb_d[i] * rgb_c[i];
+            let af = (vc.fl * d.abs() / 100.0).powf(0.42);
+            d.signum() * 400.0 * af / (af + 27.13)
+        });
+
+        let a = (11.0 * rgb_a[0] - 12.0 * rgb_a[1] + rgb_a[2]) / 11.0;
+        let b = (rgb_a[0] + rgb_a[1] - 2.0 * rgb_a[2]) / 9.0;
+        let u = (20.0 * rgb_a[0] + 20.0 * rgb_a[1] + 21.0 * rgb_a[2]) / 20.0;
+        let p2 = (40.0 * rgb_a[0] + 20.0 * rgb_a[1] + rgb_a[2]) / 20.0;
+
+        let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+        let j = 100.0 * (p2 * vc.nbb / vc.aw).powf(vc.c * vc.z);
+
+        let hue_prime = if hue < 20.14 { hue + 360.0 } else { hue };
+        let e_hue = 0.25 * ((hue_prime.to_radians() + 2.0).cos() + 3.8);
+        let p1 = 50000.0 / 13.0 * e_hue * vc.nc * vc.ncb;
+        let t = p1 * a.hypot(b) / (u + 0.305);
+        let alpha = t.powf(0.9) * (1.64 - 0.29_f64.powf(vc.n)).powf(0.73);
+        let chroma = alpha * (j / 100.0).sqrt();
+
+        Self::with_ucs(hue, chroma, j)
+    }
+
+    fn from_jch(j: f64, chroma: f64, hue: f64) -> Self {
+        Self::with_ucs(hue, chroma, j)
+    }
+
+    /// Fills in the CAM16-UCS coordinates used by [`Cam16::distance`].
+    fn with_ucs(hue: f64, chroma: f64, j: f64) -> Self {
+        let vc = ViewingConditions::default_conditions();
+        let m = chroma * vc.fl_root;
+        let mstar = (1.0 + 0.0228 * m).ln() / 0.0228;
+        let jstar = (1.0 + 100.0 * 0.007) * j / (1.0 + 0.007 * j);
+        let (sin, cos) = hue.to_radians().sin_cos();
+        Self { hue, chroma, j, jstar, astar: mstar * cos, bstar: mstar * sin }
+    }
+
+    /// Perceptual distance in CAM16-UCS, rescaled to roughly match ΔE units.
+    fn distance(&self, other: &Cam16) -> f64 {
+        let de = ((self.jstar - other.jstar).powi(2)
+            + (self.astar - other.astar).powi(2)
+            + (self.bstar - other.bstar).powi(2))
+        .sqrt();
+        1.41 * de.powf(0.63)
+    }
+
+    fn to_xyz(self) -> [f64; 3] {
+        let vc = ViewingConditions::default_conditions();
+        let alpha = if self.chroma == 0.0 || self.j == 0.0 { 0.0 } else { self.chroma / (self.j / 100.0).sqrt() };
+        let t = (alpha / (1.64 - 0.29_f64.powf(vc.n)).powf(0.73)).powf(1.0 / 0.9);
+        let (h_sin, h_cos) = self.hue.to_radians().sin_cos();
+
+        let e_hue = 0.25 * ((self.hue.to_radians() + 2.0).cos() + 3.8);
+        let ac = vc.aw * (self.j / 100.0).powf(1.0 / vc.c / vc.z);
+        let p1 = e_hue * (50000.0 / 13.0) * vc.nc * vc.ncb;
+        let p2 = ac / vc.nbb;
+
+        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * h_cos + 108.0 * t * h_sin);
+        let (a, b) = (gamma * h_cos, gamma * h_sin);
+        let rgb_a = [
+            (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0,
+            (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0,
+            (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0,
+        ];
+        let rgb_f = [0, 1, 2].map(|i| {
+            let base = (27.13 * rgb_a[i].abs() / (400.0 - rgb_a[i].abs())).max(0.0);
+            rgb_a[i].signum() * (100.0 / vc.fl) * base.powf(1.0 / 0.42) / vc.rgb_d[i]
+        });
+        mul3(&CAM16RGB_TO_XYZ, rgb_f)
+    }
+
+    /// Converts to 8-bit sRGB, clipping out-of-gamut channels.
+    fn to_srgb8(self) -> Srgb8 {
+        srgb8_from_xyz(self.to_xyz())
+    }
+}
+
+/// Searches J for a color of the given hue and chroma whose clipped sRGB value has the requested tone.
+///
+/// Returns `None` when every candidate either misses the tone or drifts too far from the hue once clipped, which
+/// means the chroma is out of reach at this tone.
+fn find_cam_by_j(hue: f64, chroma: f64, tone: f64) -> Option<Cam16> {
+    let (mut low, mut high) = (0.0_f64, 100.0);
+    let (mut best_dl, mut best_de) = (f64::MAX, f64::MAX);
+    let mut best = None;
+
+    while (low - high).abs() > LIGHTNESS_SEARCH_ENDPOINT {
+        let mid = low + (high - low) / 2.0;
+        let clipped = Cam16::from_jch(mid, chroma, hue).to_srgb8();
+        let xyz = mul3(&SRGB_TO_XYZ, linear_components(clipped));
+        let clipped_tone = lstar_from_y(xyz[1]);
+        let dl = (tone - clipped_tone).abs();
+
+        if dl < DL_MAX {
+            let clipped_cam = Cam16::from_xyz(xyz);
+            let de = clipped_cam.distance(&Cam16::from_jch(clipped_cam.j, clipped_cam.chroma, hue));
+            if de <= DE_MAX && de <= best_de {
+                best_dl = dl;
+                best_de = de;
+                best = Some(clipped_cam);
+            }
+        }
+
+        if best_dl == 0.0 && best_de == 0.0 {
+            break;
+        }
+        if clipped_tone < tone {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    best
+}
+
+/// Builds the neutral gray with the given tone; an sRGB gray's relative luminance equals its linear value.
+fn gray_from_lstar(lstar: f64) -> Srgb8 {
+    let y = (y_from_lstar(lstar) / 100.0) as f32;
+    Srgb8::from(Rgb::new(y, y, y))
+}
+
+fn srgb8_from_xyz(xyz: [f64; 3]) -> Srgb8 {
+    let linear = mul3(&XYZ_TO_SRGB, xyz);
+    Srgb8::from(Rgb::new(
+        (linear[0] / 100.0) as f32,
+        (linear[1] / 100.0) as f32,
+        (linear[2] / 100.0) as f32,
+    ))
+}
+
+fn linear_components(color: Srgb8) -> [f64; 3] {
+    let rgb = Rgb::from(color);
+    [rgb.r as f64 * 100.0, rgb.g as f64 * 100.0, rgb.b as f64 * 100.0]
+}
+
+fn lstar_from_y(y: f64) -> f64 {
+    116.0 * lab_f(y / 100.0) - 16.0
+}
+
+fn y_from_lstar(lstar: f64) -> f64 {
+    100.0 * lab_f_inv((lstar + 16.0) / 116.0)
+}
+
+fn lerp(start: f64, stop: f64, amount: f64) -> f64 {
+    start + (stop - start) * amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Srgb8, expected: &str, tone: u8) {
+        let expected = Srgb8::from_hex(expected).unwrap();
+        let channels = [(actual.r, expected.r), (actual.g, expected.g), (actual.b, expected.b)];
+        assert!(
+            channels.iter().all(|&(a, e)| a.abs_diff(e) <= 2),
+            "tone {tone}: got {actual}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn hct_round_trips_srgb() {
+        for hex in ["#6750a4", "#b3261e", "#00ff00", "#123456", "#808080"] {
+            let color = Srgb8::from_hex(hex).unwrap();
+            let hct = Hct::from(color);
+            let back = Srgb8::from(hct);
+            assert!(
+                color.r.abs_diff(back.r) <= 1 && color.g.abs_diff(back.g) <= 1 && color.b.abs_diff(back.b) <= 1,
+                "{hex} -> {hct:?} -> {back}"
+            );
+        }
+    }
+
+    #[test]
+    fn cam16_matches_reference_primaries() {
+        let red = Hct::from(Srgb8::new(255, 0, 0));
+        assert!(
+            (red.h - 27.408).abs() < 0.01 && (red.c - 113.358).abs() < 0.01,
+            "{red:?}"
+        );
+        let blue = Hct::from(Srgb8::new(0, 0, 255));
+        assert!(
+            (blue.h - 282.788).abs() < 0.01 && (blue.c - 87.231).abs() < 0.01,
+            "{blue:?}"
+        );
+    }
+
+    #[test]
+    fn hct_of_material_baseline_primary() {
+        let hct = Hct::from(Srgb8::new(0x67, 0x50, 0xa4));
+        assert!((hct.h - 298.98).abs() < 0.05, "{hct:?}");
+        assert!((hct.c - 47.86).abs() < 0.05, "{hct:?}");
+        assert!((hct.t - 40.0).abs() < 0.5, "{hct:?}");
+    }
+
+    #[test]
+    fn tonal_palette_matches_material_baseline_tones() {
+        // Material 3 baseline scheme: primary palette seeded from #6750A4 at chroma 48, error palette at hue 25/chroma 84.
+        let primary_hue = Hct::from(Srgb8::new(0x67, 0x50, 0xa4)).h;
+        let primary = tonal_palette(primary_hue, 48.0);
+        for (index, expected) in [
+            (1, "#21005d"),
+            (2, "#381e72"),
+            (4, "#6750a4"),
+            (8, "#d0bcff"),
+            (9, "#eaddff"),
+        ] {
+            assert_close(primary[index], expected, TONAL_PALETTE_TONES[index]);
+        }
+
+        let error = tonal_palette(25.0, 84.0);
+        for (index, expected) in [
+            (1, "#410002"),
+            (2, "#690005"),
+            (4, "#ba1a1a"),
+            (8, "#ffb4ab"),
+            (9, "#ffdad6"),
+        ] {
+            assert_close(error[index], expected, TONAL_PALETTE_TONES[index]);
+        }
+
+        assert_eq!(primary[0], Srgb8::new(0, 0, 0));
+        assert_eq!(primary[12], Srgb8::new(255, 255, 255));
+    }
+
+    #[test]
+    fn tones_hold_cielab_lightness() {
+        for (&tone, color) in TONAL_PALETTE_TONES.iter().zip(tonal_palette(140.0, 60.0)) {
+            assert!((Hct::from(color).t - tone as f32).abs() < 0.6, "tone {tone}: {color}");
+        }
+    }
+}
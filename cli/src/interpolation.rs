@@ -4,7 +4,7 @@
 //! RGB interpolation is simple but may produce unexpected colors;
 //! Lab and Lch interpolation are perceptually uniform and produce more natural gradients.
 
-use crate::colors::{Lab, Lch, Rgb, clamp01, wrap_degrees};
+use crate::colors::{Lab, Lch, Oklab, Oklch, Rgb, Srgb8, clamp01, wrap_degrees};
 
 /// Linearly interpolates between two RGB colors.
 ///
@@ -92,7 +92,34 @@ pub fn lerp_lch(a: Lch, b: Lch, t: f32) -> Lch {
     let t = clamp01(t);
     let l = a.l + (b.l - a.l) * t;
     let c = a.c + (b.c - a.c) * t;
-    let mut delta_h = b.h - a.h;
+    Lch::new(l, c, lerp_hue(a.h, b.h, t))
+}
+
+/// Linearly interpolates between two OKLCH colors with circular hue interpolation.
+///
+/// Works like [`lerp_lch`], but OKLCH hues are more even than CIE Lch hues, so a blend between distant hues passes
+/// through the intermediate hue you'd expect (blue to yellow goes via teal rather than pink) and, unlike Lab, stays
+/// saturated instead of graying out in the middle.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Oklch;
+/// use colorizer::interpolation::lerp_oklch;
+///
+/// let mid = lerp_oklch(Oklch::new(0.4, 0.2, 350.0), Oklch::new(0.6, 0.1, 30.0), 0.5);
+/// assert!((mid.h - 10.0).abs() < 1e-3);
+/// ```
+pub fn lerp_oklch(a: Oklch, b: Oklch, t: f32) -> Oklch {
+    let t = clamp01(t);
+    let l = a.l + (b.l - a.l) * t;
+    let c = a.c + (b.c - a.c) * t;
+    Oklch::new(l, c, lerp_hue(a.h, b.h, t))
+}
+
+/// Interpolates hue angles in degrees along the shortest path around the wheel.
+fn lerp_hue(a: f32, b: f32, t: f32) -> f32 {
+    let mut delta_h = b - a;
 
     if delta_h > 180.0 {
         delta_h -= 360.0;
@@ -100,9 +127,7 @@ pub fn lerp_lch(a: Lch, b: Lch, t: f32) -> Lch {
         delta_h += 360.0;
     }
 
-    let h = wrap_degrees(a.h + delta_h * t);
-
-    Lch::new(l, c, h)
+    wrap_degrees(a + delta_h * t)
 }
 
 /// Generates a gradient of colors in Lab space.
@@ -133,31 +158,30 @@ pub fn lerp_lch(a: Lch, b: Lch, t: f32) -> Lch {
 /// // Returns [red, ..., blue] with 5 smooth steps
 /// ```
 pub fn gradient_lab(a: Rgb, b: Rgb, steps: usize) -> Vec<Rgb> {
-    if steps < 2 {
-        return Vec::new();
-    }
-
-    let a_lab: Lab = {
-        let xyz = crate::colors::Xyz::from(a);
-        Lab::from(xyz)
-    };
-
-    let b_lab: Lab = {
-        let xyz = crate::colors::Xyz::from(b);
-        Lab::from(xyz)
-    };
-
-    let mut result = Vec::with_capacity(steps);
+    gradient_lab_iter(a, b, steps).collect()
+}
 
-    for i in 0..steps {
-        let t = if steps == 1 { 0.0 } else { i as f32 / (steps - 1) as f32 };
-        let lab = lerp_lab(a_lab, b_lab, t);
-        let xyz = crate::colors::Xyz::from(lab);
-        let rgb = Rgb::from(xyz);
-        result.push(rgb);
+/// Lazily generates a gradient in Lab space, one color per step.
+///
+/// Yields the same colors as [`gradient_lab`] without allocating, which suits per-pixel rendering of long
+/// gradient strips. Yields nothing if steps < 2.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Rgb;
+/// use colorizer::interpolation::gradient_lab_iter;
+///
+/// let red = Rgb::new(1.0, 0.0, 0.0);
+/// let blue = Rgb::new(0.0, 0.0, 1.0);
+/// assert_eq!(gradient_lab_iter(red, blue, 3840).count(), 3840);
+/// ```
+pub fn gradient_lab_iter(a: Rgb, b: Rgb, steps: usize) -> GradientLabIter {
+    GradientLabIter {
+        start: Lab::from(crate::colors::Xyz::from(a)),
+        end: Lab::from(crate::colors::Xyz::from(b)),
+        steps: GradientSteps::new(steps),
     }
-
-    result
 }
 
 /// Generates a gradient of colors in Lch space.
@@ -188,34 +212,189 @@ pub fn gradient_lab(a: Rgb, b: Rgb, steps: usize) -> Vec<Rgb> {
 /// // Returns smooth gradient through green hues
 /// ```
 pub fn gradient_lch(a: Rgb, b: Rgb, steps: usize) -> Vec<Rgb> {
-    if steps < 2 {
-        return Vec::new();
+    gradient_lch_iter(a, b, steps).collect()
+}
+
+/// Lazily generates a gradient in Lch space, one color per step.
+///
+/// Yields the same colors as [`gradient_lch`] without allocating. Yields nothing if steps < 2.
+pub fn gradient_lch_iter(a: Rgb, b: Rgb, steps: usize) -> GradientLchIter {
+    GradientLchIter {
+        start: Lch::from(Lab::from(crate::colors::Xyz::from(a))),
+        end: Lch::from(Lab::from(crate::colors::Xyz::from(b))),
+        steps: GradientSteps::new(steps),
     }
+}
 
-    let a_lch: Lch = {
-        let xyz = crate::colors::Xyz::from(a);
-        let lab = Lab::from(xyz);
-        Lch::from(lab)
-    };
+/// Interpolation parameters `t` for each step of a two-color gradient.
+#[derive(Debug, Clone)]
+struct GradientSteps {
+    next: usize,
+    steps: usize,
+}
 
-    let b_lch: Lch = {
-        let xyz = crate::colors::Xyz::from(b);
-        let lab = Lab::from(xyz);
-        Lch::from(lab)
-    };
+impl GradientSteps {
+    fn new(steps: usize) -> Self {
+        Self { next: 0, steps: if steps < 2 { 0 } else { steps } }
+    }
 
-    let mut result = Vec::with_capacity(steps);
+    fn next_t(&mut self) -> Option<f32> {
+        if self.next >= self.steps {
+            return None;
+        }
+        let t = self.next as f32 / (self.steps - 1) as f32;
+        self.next += 1;
+        Some(t)
+    }
 
-    for i in 0..steps {
-        let t = if steps == 1 { 0.0 } else { i as f32 / (steps - 1) as f32 };
-        let lch = lerp_lch(a_lch, b_lch, t);
-        let lab = Lab::from(lch);
-        let xyz = crate::colors::Xyz::from(lab);
-        let rgb = Rgb::from(xyz);
-        result.push(rgb);
+    fn remaining(&self) -> usize {
+        self.steps - self.next
     }
+}
+
+/// Iterator over a Lab gradient, created by [`gradient_lab_iter`].
+#[derive(Debug, Clone)]
+pub struct GradientLabIter {
+    start: Lab,
+    end: Lab,
+    steps: GradientSteps,
+}
+
+impl Iterator for GradientLabIter {
+    type Item = Rgb;
 
-    result
+    fn next(&mut self) -> Option<Rgb> {
+        let t = self.steps.next_t()?;
+        Some(clipped_rgb(Rgb::from(lerp_lab(self.start, self.end, t))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.steps.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for GradientLabIter {}
+
+/// Iterator over an Lch gradient, created by [`gradient_lch_iter`].
+#[derive(Debug, Clone)]
+pub struct GradientLchIter {
+    start: Lch,
+    end: Lch,
+    steps: GradientSteps,
+}
+
+impl Iterator for GradientLchIter {
+    type Item = Rgb;
+
+    fn next(&mut self) -> Option<Rgb> {
+        let t = self.steps.next_t()?;
+        Some(clipped_rgb(Rgb::from(Lab::from(lerp_lch(self.start, self.end, t)))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.steps.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for GradientLchIter {}
+
+/// Clips the components of an interpolated color, converted back to linear RGB, to [0, 1].
+fn clipped_rgb(rgb: Rgb) -> Rgb {
+    Rgb::new(rgb.r, rgb.g, rgb.b)
+}
+
+/// Color space used to interpolate between gradient stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientSpace {
+    /// Component-wise interpolation via [`lerp_rgb`].
+    Rgb,
+    /// Perceptual interpolation via [`lerp_lab`].
+    Lab,
+    /// Perceptual interpolation with circular hue via [`lerp_lch`].
+    Lch,
+    /// OKLCH interpolation with circular hue via [`lerp_oklch`]; keeps chroma up between distant hues.
+    Oklch,
+}
+
+/// Generates a gradient through any number of color stops.
+///
+/// The `steps` output colors are spread evenly along the whole gradient, so stops are equally spaced
+/// and the first and last colors are exactly the first and last stops.
+///
+/// # Returns
+///
+/// A vector of RGB colors representing the gradient. Returns empty vector if steps < 2 or fewer than two stops are given.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Rgb;
+/// use colorizer::interpolation::{GradientSpace, gradient_stops};
+///
+/// let stops = [Rgb::new(1.0, 0.0, 0.0), Rgb::new(0.0, 1.0, 0.0), Rgb::new(0.0, 0.0, 1.0)];
+/// let gradient = gradient_stops(&stops, 9, GradientSpace::Lch);
+/// assert_eq!(gradient.len(), 9);
+/// ```
+pub fn gradient_stops(stops: &[Rgb], steps: usize, space: GradientSpace) -> Vec<Rgb> {
+    if steps < 2 || stops.len() < 2 {
+        return Vec::new();
+    }
+
+    let segments = stops.len() - 1;
+    (0..steps)
+        .map(|i| {
+            let position = i as f32 / (steps - 1) as f32 * segments as f32;
+            let segment = (position.floor() as usize).min(segments - 1);
+            let t = position - segment as f32;
+            let (a, b) = (stops[segment], stops[segment + 1]);
+
+            match space {
+                GradientSpace::Rgb => lerp_rgb(a, b, t),
+                GradientSpace::Lab => {
+                    let lab = lerp_lab(
+                        Lab::from(crate::colors::Xyz::from(a)),
+                        Lab::from(crate::colors::Xyz::from(b)),
+                        t,
+                    );
+                    clipped_rgb(Rgb::from(lab))
+                }
+                GradientSpace::Lch => {
+                    let a_lch = Lch::from(Lab::from(crate::colors::Xyz::from(a)));
+                    let b_lch = Lch::from(Lab::from(crate::colors::Xyz::from(b)));
+                    clipped_rgb(Rgb::from(Lab::from(lerp_lch(a_lch, b_lch, t))))
+                }
+                GradientSpace::Oklch => {
+                    let oklch = lerp_oklch(Oklch::from(Oklab::from(a)), Oklch::from(Oklab::from(b)), t);
+                    clipped_rgb(Rgb::from(Oklab::from(oklch)))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Resamples a palette to exactly `n` evenly spaced colors, treating the input as gradient stops.
+///
+/// The first and last colors are kept as endpoints. A single-color palette is repeated `n` times, and `n == 1`
+/// returns the first color.
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::interpolation::{GradientSpace, resample_palette};
+///
+/// let palette = [Srgb8::new(255, 0, 0), Srgb8::new(0, 0, 255)];
+/// assert_eq!(resample_palette(&palette, 4, GradientSpace::Lab).len(), 4);
+/// ```
+pub fn resample_palette(colors: &[Srgb8], n: usize, space: GradientSpace) -> Vec<Srgb8> {
+    match (colors, n) {
+        ([], _) | (_, 0) => Vec::new(),
+        ([first, ..], 1) | ([first], _) => vec![*first; n],
+        _ => {
+            let stops: Vec<Rgb> = colors.iter().copied().map(Rgb::from).collect();
+            gradient_stops(&stops, n, space).into_iter().map(Srgb8::from).collect()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -228,6 +407,40 @@ mod tests {
         (a - b).abs() < EPSILON
     }
 
+    #[test]
+    fn gradient_iters_match_eager_gradients() {
+        let (a, b) = (Rgb::new(0.9, 0.2, 0.1), Rgb::new(0.1, 0.3, 0.8));
+        let (a_lab, b_lab) = (
+            Lab::from(crate::colors::Xyz::from(a)),
+            Lab::from(crate::colors::Xyz::from(b)),
+        );
+        let (a_lch, b_lch) = (Lch::from(a_lab), Lch::from(b_lab));
+
+        for steps in [0, 1, 2, 7, 64] {
+            let expected_lab: Vec<Rgb> = (0..steps)
+                .filter(|_| steps >= 2)
+                .map(|i| clipped_rgb(Rgb::from(lerp_lab(a_lab, b_lab, i as f32 / (steps - 1) as f32))))
+                .collect();
+            let expected_lch: Vec<Rgb> = (0..steps)
+                .filter(|_| steps >= 2)
+                .map(|i| {
+                    clipped_rgb(Rgb::from(Lab::from(lerp_lch(
+                        a_lch,
+                        b_lch,
+                        i as f32 / (steps - 1) as f32,
+                    ))))
+                })
+                .collect();
+
+            let lab_iter = gradient_lab_iter(a, b, steps);
+            assert_eq!(lab_iter.len(), expected_lab.len());
+            assert_eq!(lab_iter.collect::<Vec<_>>(), expected_lab);
+            assert_eq!(gradient_lab(a, b, steps), expected_lab);
+            assert_eq!(gradient_lch_iter(a, b, steps).collect::<Vec<_>>(), expected_lch);
+            assert_eq!(gradient_lch(a, b, steps), expected_lch);
+        }
+    }
+
     #[test]
     fn test_lerp_rgb_endpoints() {
         let red = Rgb::new(1.0, 0.0, 0.0);
@@ -434,4 +647,81 @@ mod tests {
             assert!(color.b >= -EPSILON && color.b <= 1.0 + EPSILON);
         }
     }
+
+    #[test]
+    fn test_resample_palette_keeps_stops_and_inserts_midpoints() {
+        let palette = [
+            Srgb8::new(230, 40, 40),
+            Srgb8::new(40, 200, 90),
+            Srgb8::new(50, 80, 220),
+        ];
+        let close = |a: Srgb8, b: Srgb8| {
+            (a.r as i16 - b.r as i16).abs() <= 1
+                && (a.g as i16 - b.g as i16).abs() <= 1
+                && (a.b as i16 - b.b as i16).abs() <= 1
+        };
+
+        for space in [GradientSpace::Rgb, GradientSpace::Lab, GradientSpace::Lch] {
+            let same = resample_palette(&palette, 3, space);
+            assert!(
+                same.iter().zip(&palette).all(|(&a, &b)| close(a, b)),
+                "{space:?}: {same:?}"
+            );
+
+            let five = resample_palette(&palette, 5, space);
+            assert_eq!(five.len(), 5);
+            assert!(close(five[0], palette[0]) && close(five[2], palette[1]) && close(five[4], palette[2]));
+            assert!(!palette.iter().any(|&c| close(c, five[1]) || close(c, five[3])));
+        }
+
+        assert_eq!(
+            resample_palette(&palette[..1], 3, GradientSpace::Lab),
+            vec![palette[0]; 3]
+        );
+        assert!(resample_palette(&palette, 0, GradientSpace::Lab).is_empty());
+    }
+
+    #[test]
+    fn test_gradient_stops_count_and_endpoints() {
+        let red = Rgb::new(1.0, 0.0, 0.0);
+        let green = Rgb::new(0.0, 1.0, 0.0);
+        let blue = Rgb::new(0.0, 0.0, 1.0);
+
+        for space in [
+            GradientSpace::Rgb,
+            GradientSpace::Lab,
+            GradientSpace::Lch,
+            GradientSpace::Oklch,
+        ] {
+            let gradient = gradient_stops(&[red, green, blue], 12, space);
+            assert_eq!(gradient.len(), 12);
+
+            let (first, last) = (gradient[0], gradient[11]);
+            assert!((first.r - 1.0).abs() < 0.01 && first.g.abs() < 0.01 && first.b.abs() < 0.01);
+            assert!(last.r.abs() < 0.01 && last.g.abs() < 0.01 && (last.b - 1.0).abs() < 0.01);
+        }
+
+        let midpoint = gradient_stops(&[red, green, blue], 5, GradientSpace::Rgb)[2];
+        assert!(approx_eq(midpoint.g, 1.0));
+        assert!(gradient_stops(&[red], 5, GradientSpace::Rgb).is_empty());
+    }
+
+    #[test]
+    fn test_oklch_gradient_keeps_chroma_between_blue_and_yellow() {
+        let (blue, yellow) = (Rgb::new(0.0, 0.0, 1.0), Rgb::new(1.0, 1.0, 0.0));
+        let midpoint = |space| Oklch::from(Oklab::from(gradient_stops(&[blue, yellow], 5, space)[2]));
+
+        let (oklch, lab) = (midpoint(GradientSpace::Oklch), midpoint(GradientSpace::Lab));
+        assert!(
+            oklch.c > 1.5 * lab.c,
+            "oklch midpoint chroma {} vs lab {}",
+            oklch.c,
+            lab.c
+        );
+
+        // CIE Lch's shortest arc from blue (306°) to yellow (103°) runs through pink; OKLCH's runs through teal.
+        assert!((150.0..210.0).contains(&oklch.h), "oklch midpoint hue {}", oklch.h);
+        let lch = midpoint(GradientSpace::Lch);
+        assert!(!(90.0..270.0).contains(&lch.h), "lch midpoint hue {}", lch.h);
+    }
 }
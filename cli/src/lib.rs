@@ -1,12 +1,19 @@
 use colors::{Hsl, Hsv, Rgb, clamp01};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::ops::Range;
 
 mod conversions;
 mod vimrc;
 
+pub mod ansi;
 pub mod base16_builder;
+pub mod blend;
 pub mod colors;
 pub mod diffs;
+pub mod exporters;
+pub mod extract;
+pub mod hct;
 pub mod palette;
 pub mod random;
 pub mod syntax;
@@ -14,13 +21,22 @@ pub mod tinted_theming;
 pub mod wcag;
 
 pub mod harmonies;
-pub use harmonies::{HarmonyKind, harmonies, normalize_saturation, set_lightness, shift_lightness};
+pub use harmonies::{
+    HarmonyKind, harmonies, harmonies_hsv, normalize_saturation, rotate_hue, rotate_hue_lch,
+    rotate_hue_preserve_luminance, set_lightness, shift_lightness,
+};
 
 pub mod shades;
-pub use shades::{darken_hsl, desaturate_hsl, lighten_hsl, mix_rgb, shade, tint, tone};
+pub use shades::{
+    darken_hsl, desaturate_hsl, lighten_hsl, mix_lab, mix_lch, mix_many, mix_many_lab, mix_rgb, scale, shade,
+    shade_lab, shade_ramp, tint, tint_lab, tint_ramp, tone, tone_lab,
+};
 
 pub mod interpolation;
-pub use interpolation::{gradient_lab, gradient_lch, lerp_lab, lerp_lch, lerp_rgb};
+pub use interpolation::{
+    GradientLabIter, GradientLchIter, GradientSpace, gradient_lab, gradient_lab_iter, gradient_lch, gradient_lch_iter,
+    gradient_stops, lerp_lab, lerp_lch, lerp_rgb, resample_palette,
+};
 
 /// Golden ratio conjugate used for hue stepping.
 pub const GOLDEN_RATIO_CONJUGATE: f32 = 0.618_034;
@@ -75,6 +91,23 @@ impl GoldenValueSpec {
     }
 }
 
+/// Random offsets added to sampled saturation and lightness/value to break up hue-correlated banding.
+#[derive(Debug, Clone)]
+struct GoldenJitter {
+    saturation: f32,
+    lum_or_value: f32,
+    rng: StdRng,
+}
+
+impl GoldenJitter {
+    fn apply(&mut self, saturation: f32, lum_or_value: f32) -> (f32, f32) {
+        let mut offset = |amount: f32| if amount > 0.0 { self.rng.random_range(-amount..=amount) } else { 0.0 };
+        let s = offset(self.saturation);
+        let lv = offset(self.lum_or_value);
+        (clamp01(saturation + s), clamp01(lum_or_value + lv))
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum GoldenSpace {
     Hsl,
@@ -88,6 +121,7 @@ pub struct GoldenPalette {
     saturation: GoldenValueSpec,
     lum_or_value: GoldenValueSpec,
     space: GoldenSpace,
+    jitter: Option<GoldenJitter>,
 }
 
 impl GoldenPalette {
@@ -98,6 +132,7 @@ impl GoldenPalette {
             saturation: GoldenValueSpec::fixed(saturation),
             lum_or_value: GoldenValueSpec::fixed(lightness),
             space: GoldenSpace::Hsl,
+            jitter: None,
         }
     }
 
@@ -108,6 +143,7 @@ impl GoldenPalette {
             saturation: GoldenValueSpec::from_range(saturation_range),
             lum_or_value: GoldenValueSpec::from_range(lightness_range),
             space: GoldenSpace::Hsl,
+            jitter: None,
         }
     }
 
@@ -118,6 +154,7 @@ impl GoldenPalette {
             saturation: GoldenValueSpec::fixed(saturation),
             lum_or_value: GoldenValueSpec::fixed(value),
             space: GoldenSpace::Hsv,
+            jitter: None,
         }
     }
 
@@ -128,9 +165,25 @@ impl GoldenPalette {
             saturation: GoldenValueSpec::from_range(saturation_range),
             lum_or_value: GoldenValueSpec::from_range(value_range),
             space: GoldenSpace::Hsv,
+            jitter: None,
         }
     }
 
+    /// Adds uniform random jitter of up to ±`saturation` and ±`lum_or_value` on top of each sampled value.
+    ///
+    /// The jitter RNG is seeded from `rng_seed`, so the same seed reproduces the same palette.
+    /// Zero jitter leaves the output identical to a palette without jitter.
+    pub fn with_jitter(mut self, saturation: f32, lum_or_value: f32, rng_seed: u64) -> Self {
+        let saturation = saturation.max(0.0);
+        let lum_or_value = lum_or_value.max(0.0);
+        self.jitter = (saturation > 0.0 || lum_or_value > 0.0).then(|| GoldenJitter {
+            saturation,
+            lum_or_value,
+            rng: StdRng::seed_from_u64(rng_seed),
+        });
+        self
+    }
+
     /// Returns the next color as HSL; panics if the palette was constructed for HSV.
     pub fn next_hsl(&mut self) -> Hsl {
         assert!(
@@ -151,24 +204,48 @@ impl GoldenPalette {
         Hsv::new(hue * 360.0, saturation, value)
     }
 
+    /// Returns an endless iterator of HSL colors; panics if the palette was constructed for HSV.
+    ///
+    /// The iterator borrows the generator, so its state keeps advancing across calls.
+    pub fn iter_hsl(&mut self) -> impl Iterator<Item = Hsl> + '_ {
+        assert!(
+            matches!(self.space, GoldenSpace::Hsl),
+            "GoldenPalette::iter_hsl called on HSV palette"
+        );
+        std::iter::from_fn(move || Some(self.next_hsl()))
+    }
+
+    /// Returns an endless iterator of HSV colors; panics if the palette was constructed for HSL.
+    pub fn iter_hsv(&mut self) -> impl Iterator<Item = Hsv> + '_ {
+        assert!(
+            matches!(self.space, GoldenSpace::Hsv),
+            "GoldenPalette::iter_hsv called on HSL palette"
+        );
+        std::iter::from_fn(move || Some(self.next_hsv()))
+    }
+
     fn advance(&mut self) -> (f32, f32, f32) {
         let hue = self.hue.next_hf();
         let s = self.saturation.sample(hue);
         let lv = self.lum_or_value.sample(hue);
-        (hue, s, lv)
+        match self.jitter.as_mut() {
+            Some(jitter) => {
+                let (s, lv) = jitter.apply(s, lv);
+                (hue, s, lv)
+            }
+            None => (hue, s, lv),
+        }
     }
 }
 
 /// Convenience helper that returns `n` evenly spaced RGB colors for a fixed HSL saturation/lightness.
 // TODO: Extend this helper to accept CLI-provided seeds and ranges once palette commands are wired up.
 pub fn golden_palette(n: usize, saturation: f32, lightness: f32) -> Vec<Rgb> {
-    let mut generator = GoldenPalette::hsl_fixed(0.0, saturation, lightness);
-    let mut result = Vec::with_capacity(n);
-    for _ in 0..n {
-        let hsl = generator.next_hsl();
-        result.push(Rgb::from(hsl));
-    }
-    result
+    GoldenPalette::hsl_fixed(0.0, saturation, lightness)
+        .iter_hsl()
+        .take(n)
+        .map(Rgb::from)
+        .collect()
 }
 
 fn wrap_unit_interval(x: f32) -> f32 {
@@ -223,6 +300,32 @@ mod tests {
         assert!(hsv.h >= 0.0 && hsv.h < 360.0);
     }
 
+    #[test]
+    fn golden_palette_iter_matches_manual_calls() {
+        let mut manual = GoldenPalette::hsl_ranged(0.3, 0.4..0.8, 0.3..0.7);
+        let mut iterated = manual.clone();
+
+        let expected: Vec<Hsl> = (0..5).map(|_| manual.next_hsl()).collect();
+        let actual: Vec<Hsl> = iterated.iter_hsl().take(5).collect();
+        assert_eq!(actual, expected);
+        assert_eq!(iterated.next_hsl(), manual.next_hsl());
+    }
+
+    #[test]
+    fn golden_palette_iter_hsv_yields_hsv_colors() {
+        let mut palette = GoldenPalette::hsv_fixed(0.1, 0.5, 0.9);
+        let colors: Vec<Hsv> = palette.iter_hsv().take(3).collect();
+        assert_eq!(colors.len(), 3);
+        assert!(colors.iter().all(|c| c.s == 0.5 && c.v == 0.9));
+    }
+
+    #[test]
+    #[should_panic(expected = "iter_hsv called on HSL palette")]
+    fn golden_palette_iter_rejects_wrong_space() {
+        let mut palette = GoldenPalette::hsl_fixed(0.0, 0.5, 0.5);
+        let _ = palette.iter_hsv().next();
+    }
+
     #[test]
     fn golden_palette_helper_returns_rgb_values() {
         let colors = golden_palette(5, 0.6, 0.5);
@@ -234,4 +337,32 @@ mod tests {
             assert!(color.b >= 0.0 && color.b <= 1.0);
         }
     }
+
+    #[test]
+    fn golden_palette_zero_jitter_matches_plain() {
+        let plain: Vec<Hsl> = GoldenPalette::hsl_ranged(0.0, 0.4..0.8, 0.3..0.7)
+            .iter_hsl()
+            .take(8)
+            .collect();
+        let jittered: Vec<Hsl> = GoldenPalette::hsl_ranged(0.0, 0.4..0.8, 0.3..0.7)
+            .with_jitter(0.0, 0.0, 7)
+            .iter_hsl()
+            .take(8)
+            .collect();
+        assert_eq!(plain, jittered);
+    }
+
+    #[test]
+    fn golden_palette_jitter_spreads_saturation() {
+        let mut plain = GoldenPalette::hsl_ranged(0.0, 0.4..0.8, 0.3..0.7);
+        let mut jittered = GoldenPalette::hsl_ranged(0.0, 0.4..0.8, 0.3..0.7).with_jitter(0.1, 0.0, 7);
+
+        let pairs: Vec<(Hsl, Hsl)> = (0..16).map(|_| (plain.next_hsl(), jittered.next_hsl())).collect();
+        assert!(pairs.iter().all(|(a, b)| a.h == b.h && a.l == b.l));
+        assert!(pairs.iter().all(|(a, b)| (a.s - b.s).abs() <= 0.1 + f32::EPSILON));
+        assert!(pairs.iter().any(|(a, b)| (a.s - b.s).abs() > 0.01));
+
+        let mut replay = GoldenPalette::hsl_ranged(0.0, 0.4..0.8, 0.3..0.7).with_jitter(0.1, 0.0, 7);
+        assert_eq!(replay.next_hsl(), pairs[0].1);
+    }
 }
@@ -1,18 +1,31 @@
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use colorizer::{
-    HarmonyKind,
+    GradientSpace, HarmonyKind, ansi,
     base16_builder::{self, Base16Config, Base24Config, Variant},
-    colors::Srgb8,
-    palette::{PaletteLabelStyle, golden_ratio_palette, palette_from_base, palette_to_image},
+    colors::{Color, Hsl, Lab, Lch, Rgb, Srgb8},
+    diffs, exporters, extract, gradient_stops, hct,
+    palette::{
+        self, PaletteExport, PaletteLabelStyle, PaletteLayout, PaletteOrientation, SortKey, contrast_sheet_image,
+        golden_ratio_palette, golden_ratio_palette_spread, palette_from_base, palette_from_base_hsv, palette_to_image,
+        palette_to_image_with_font, palette_to_image_with_layout, palette_to_svg_with_layout,
+    },
     random::{self, PaletteConstraints, PoissonConfig},
-    syntax,
-    tinted_theming::{self, SchemeMetadata},
+    resample_palette, rotate_hue_lch, rotate_hue_preserve_luminance, syntax,
+    tinted_theming::{self, Base16Scheme, Base24Scheme, SchemeMetadata, Severity},
+    wcag,
 };
-use std::fs::File;
-use std::io::{self, BufReader, Read};
+use owo_colors::OwoColorize;
+use rusttype::Font;
+use serde::Serialize;
+use std::fs;
+use std::io::{self, Read};
 use std::ops::Range;
+use std::path::Path;
 
-const NEUTRAL_SATURATION_TOLERANCE: f32 = 0.02;
+/// Background slots (base00, base01) compared by the contrast matrix and sheet.
+const CONTRAST_BACKGROUNDS: [usize; 2] = [0x00, 0x01];
+/// Foreground slots (base05 and the accents base08-base0F) compared by the contrast matrix and sheet.
+const CONTRAST_FOREGROUNDS: [usize; 9] = [0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
 
 #[derive(Parser)]
 #[command(name = "colorizer")]
@@ -36,30 +49,43 @@ enum Commands {
         #[command(subcommand)]
         action: PaletteAction,
     },
-    /// Generate palette visualization images
-    Image {
-        /// Color values as hex codes (comma-separated, e.g., "#ff0000,#00ff00,#0000ff")
-        #[arg(long, conflicts_with = "scheme_yaml")]
-        colors: Option<String>,
-        /// Base16/Base24 scheme YAML file
-        #[arg(long, conflicts_with = "colors")]
-        scheme_yaml: Option<String>,
-        /// Output image file path
-        #[arg(short, long, default_value = "palette.png")]
-        out: String,
-        /// Image width in pixels
+    /// Single-color utilities
+    Color {
+        #[command(subcommand)]
+        action: ColorAction,
+    },
+    /// Report WCAG contrast between a foreground and background color
+    Contrast {
+        /// Foreground (text) color as hex
         #[arg(long)]
-        width: Option<u32>,
-        /// Image height in pixels
+        fg: String,
+        /// Background color as hex
         #[arg(long)]
-        height: Option<u32>,
-        /// Label style for color bars
-        #[arg(long, value_parser = ["hex", "base16", "index", "none"], default_value = "index")]
-        label: String,
-        /// Show palette in terminal after generating image
+        bg: String,
+    },
+    /// Preview a gradient in the terminal and optionally save it as an image
+    Gradient {
+        /// Start color as hex
+        #[arg(long, requires = "to", conflicts_with = "stops")]
+        from: Option<String>,
+        /// End color as hex
+        #[arg(long, requires = "from", conflicts_with = "stops")]
+        to: Option<String>,
+        /// Comma-separated color stops for multi-stop gradients (e.g., "#ff0000,#00ff00,#0000ff")
         #[arg(long)]
-        viz: bool,
+        stops: Option<String>,
+        /// Number of colors to generate
+        #[arg(long, default_value_t = 10)]
+        steps: usize,
+        /// Interpolation color space
+        #[arg(long, value_parser = ["rgb", "lab", "lch", "oklch"], default_value = "lch")]
+        space: String,
+        /// Save the gradient as an image at this path
+        #[arg(short, long)]
+        out: Option<String>,
     },
+    /// Generate palette visualization images
+    Image(ImageArgs),
     /// Generate Vim colorscheme files
     VimScheme {
         /// Base16/Base24 scheme YAML file
@@ -82,13 +108,68 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum ColorAction {
+    /// Derive a stable accent color from any text, e.g. a username for avatars or tags
+    FromHash {
+        /// Text to hash
+        input: String,
+    },
+    /// Print the closest CSS named color and its ΔE2000
+    Name {
+        /// Color as hex, a CSS color name, or rgb()/hsl() syntax
+        color: String,
+    },
+}
+
+/// Options for the `image` command.
+#[derive(Args)]
+struct ImageArgs {
+    /// Color values as hex codes (comma-separated, e.g., "#ff0000,#00ff00,#0000ff"; "-" reads stdin)
+    #[arg(long, conflicts_with = "scheme_yaml")]
+    colors: Option<String>,
+    /// Base16/Base24 scheme YAML file
+    #[arg(long, conflicts_with = "colors")]
+    scheme_yaml: Option<String>,
+    /// Output image file path (a .svg extension writes vector swatches)
+    #[arg(short, long, default_value = "palette.png")]
+    out: String,
+    /// Image width in pixels
+    #[arg(long)]
+    width: Option<u32>,
+    /// Image height in pixels
+    #[arg(long)]
+    height: Option<u32>,
+    /// Label style for color bars
+    #[arg(long, value_parser = ["hex", "base16", "role", "index", "none"], default_value = "index")]
+    label: String,
+    /// TrueType font file for image labels (defaults to a system monospace font)
+    #[arg(long)]
+    font: Option<String>,
+    /// Lay colors out as side-by-side columns or stacked full-width bands
+    #[arg(long, value_parser = ["vertical", "horizontal"], default_value = "vertical")]
+    orientation: String,
+    /// Spacing in pixels between adjacent swatches
+    #[arg(long, default_value = "0")]
+    gutter: u32,
+    /// Background color shown in the gutters (defaults to black)
+    #[arg(long)]
+    bg: Option<String>,
+    /// Show palette in terminal after generating image
+    #[arg(long)]
+    viz: bool,
+    /// Render the generated image inline with half-block characters (truecolor terminals)
+    #[arg(long)]
+    preview: bool,
+}
+
 #[derive(Subcommand)]
 enum SchemeAction {
-    /// Generate a Base16 or Base24 color scheme from a single accent color
+    /// Generate a Base16, Base24, or ANSI 16-color scheme from a single accent color
     #[command(visible_alias = "gen")]
     Generate {
-        /// Scheme format (base16 or base24)
-        #[arg(value_parser = ["base16", "base24"])]
+        /// Scheme format (base16, base24, or ansi16)
+        #[arg(value_parser = ["base16", "base24", "ansi16"])]
         format: String,
         /// Scheme name
         #[arg(long)]
@@ -100,14 +181,41 @@ enum SchemeAction {
         #[arg(long, value_parser = ["dark", "light"], default_value = "dark")]
         variant: String,
         /// Accent color as hex (e.g., "#ff5500")
-        #[arg(long)]
-        accent: String,
+        #[arg(long, required_unless_present = "accents")]
+        accent: Option<String>,
+        /// Seed colors kept as accents in their nearest role slots, e.g. "#f00,#00f" (base16; contrast may nudge them)
+        #[arg(long, conflicts_with_all = ["accent", "monochrome"])]
+        accents: Option<String>,
         /// Color harmony for accent generation
-        #[arg(long, value_parser = ["complementary", "split-complementary", "analogous", "triadic", "tetradic", "square"], default_value = "triadic")]
+        #[arg(long, value_parser = ["complementary", "split-complementary", "analogous", "triadic", "tetradic", "square", "rectangle"], default_value = "triadic")]
         harmony: String,
+        /// Hue offset in degrees for analogous and rectangle harmonies (defaults: 30 and 60)
+        #[arg(long)]
+        harmony_angle: Option<f32>,
+        /// Number of colors for the analogous harmony, stepped by the angle on both sides of the base (default: 3)
+        #[arg(long, value_parser = parse_harmony_count)]
+        harmony_count: Option<usize>,
         /// Neutral darkness (0 = classic bright neutrals, 1 = moody/dark neutrals)
         #[arg(long, default_value_t = base16_builder::DEFAULT_NEUTRAL_DEPTH)]
         neutral_depth: f32,
+        /// Hue in degrees to tint the neutrals toward, e.g. 30 for warm grays (base16/base24)
+        #[arg(long)]
+        neutral_hue: Option<f32>,
+        /// Hue drift across the neutrals from base00 to base07 along the shorter arc, e.g. "30..200" (base16/base24)
+        #[arg(long, value_parser = parse_hue_range, conflicts_with = "neutral_hue")]
+        neutral_hue_range: Option<(f32, f32)>,
+        /// HSL lightness for accents before contrast adjustment, 0-1 (defaults: 0.65 dark, 0.45 light)
+        #[arg(long, conflicts_with_all = ["monochrome", "oklch"])]
+        accent_lightness: Option<f32>,
+        /// HSL saturation for accents, 0-1 (defaults: 0.70 dark, 0.75 light)
+        #[arg(long, conflicts_with_all = ["monochrome", "oklch"])]
+        accent_saturation: Option<f32>,
+        /// Give every accent the accent color's hue, varying only lightness and saturation (base16 only)
+        #[arg(long)]
+        monochrome: bool,
+        /// Place all accents at one OKLCH lightness for evenly bright syntax colors (base16/base24)
+        #[arg(long, conflicts_with = "monochrome")]
+        oklch: bool,
         /// Output YAML file path (defaults to <name>.yml)
         #[arg(long, short)]
         output: Option<String>,
@@ -132,14 +240,133 @@ enum SchemeAction {
         /// Show syntax-highlighted code demo
         #[arg(long)]
         demo: Option<String>,
-        /// Code file for syntax demo
+        /// Code file for syntax demo (defaults to a built-in sample for rust, python, and javascript)
         #[arg(long, requires = "demo")]
         file: Option<String>,
+        /// Terminal color depth for highlighted code: 24-bit truecolor or xterm 256-color
+        #[arg(long, value_parser = ["truecolor", "256"], default_value = "truecolor")]
+        color_depth: String,
     },
     /// Validate a scheme (contrast, neutrals, color roles)
     Validate {
+        /// Base16/Base24 scheme YAML file, or a directory of schemes to summarize
+        scheme: String,
+        /// Warn when two accents (base08-base0F) are closer than this ΔE2000
+        #[arg(long, default_value_t = base16_builder::DEFAULT_ACCENT_DELTA_E)]
+        min_accent_delta_e: f32,
+        /// Also warn about related syntax scopes (e.g. functions and regexes) with hard-to-distinguish colors
+        #[arg(long)]
+        syntax: bool,
+    },
+    /// Check accent hues and the neutral ramp against tinted-theming style conventions
+    Lint {
+        /// Base16/Base24 scheme YAML file
+        scheme: String,
+    },
+    /// Compare two schemes slot by slot using ΔE2000
+    Diff {
+        /// Original Base16/Base24 scheme YAML file
+        a: String,
+        /// Updated Base16/Base24 scheme YAML file
+        b: String,
+        /// Flag slots whose ΔE2000 exceeds this value
+        #[arg(long, default_value_t = diffs::DEFAULT_JND_THRESHOLD)]
+        threshold: f32,
+    },
+    /// Print the contrast ratios between foreground and background slots
+    ContrastMatrix {
+        /// Base16/Base24 scheme YAML file
+        scheme: String,
+    },
+    /// Render the contrast matrix as an image of text samples on each background
+    ContrastSheet {
+        /// Base16/Base24 scheme YAML file
+        scheme: String,
+        /// Output PNG path
+        #[arg(short, long, visible_alias = "out", default_value = "contrast-sheet.png")]
+        output: String,
+        /// Cell width in pixels
+        #[arg(long, default_value = "120")]
+        cell_width: u32,
+        /// Cell height in pixels
+        #[arg(long, default_value = "48")]
+        cell_height: u32,
+    },
+    /// Replace individual palette slots in a scheme
+    Edit {
+        /// Base16/Base24 scheme YAML file
+        scheme: String,
+        /// Slot assignment such as "base08=#ff0000" (repeatable)
+        #[arg(long = "set", value_name = "KEY=HEX", required = true)]
+        set: Vec<String>,
+        /// Output path (defaults to overwriting the input file)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Change a scheme's name, author, or variant without touching its palette
+    SetMeta {
+        /// Base16/Base24 scheme YAML file
+        scheme: String,
+        /// New scheme name
+        #[arg(long)]
+        name: Option<String>,
+        /// New author
+        #[arg(long)]
+        author: Option<String>,
+        /// New variant
+        #[arg(long, value_parser = ["dark", "light"])]
+        variant: Option<String>,
+        /// Output path (defaults to overwriting the input file)
+        #[arg(short, long, visible_alias = "out")]
+        output: Option<String>,
+    },
+    /// Rotate the accent hues (base08-base0F) of a scheme in Lch space
+    Rotate {
         /// Base16/Base24 scheme YAML file
         scheme: String,
+        /// Hue offset in degrees (negative rotates backwards)
+        #[arg(long, allow_hyphen_values = true)]
+        degrees: f32,
+        /// Keep each accent's WCAG luminance, so its contrast against the background is unchanged
+        #[arg(long)]
+        preserve_luminance: bool,
+        /// Output path for the rotated scheme
+        #[arg(short, long, visible_alias = "out")]
+        output: String,
+    },
+    /// Combine the neutrals (base00-base07) of one Base16 scheme with the accents (base08-base0F) of another
+    Merge {
+        /// Scheme supplying base00-base07
+        #[arg(long)]
+        neutrals: String,
+        /// Scheme supplying base08-base0F
+        #[arg(long)]
+        accents: String,
+        /// Output path for the merged scheme
+        #[arg(short, long, visible_alias = "out")]
+        output: String,
+    },
+    /// Flip a Base16 scheme between dark and light, keeping accent hues
+    Invert {
+        /// Base16 scheme YAML file
+        scheme: String,
+        /// Output path for the inverted scheme
+        #[arg(short, long, visible_alias = "out")]
+        output: String,
+    },
+    /// Export a scheme as an editor theme or a markdown swatch table
+    Export {
+        /// Base16/Base24 scheme YAML file (Base24 exports its first 16 slots)
+        scheme: String,
+        /// Export format
+        #[arg(long, value_parser = ["vscode", "iterm", "markdown"], default_value = "vscode")]
+        format: String,
+        /// Theme name (defaults to the scheme name)
+        #[arg(long)]
+        name: Option<String>,
+        /// Output file path (prints to stdout when omitted)
+        #[arg(short, long, visible_alias = "out")]
+        output: Option<String>,
     },
 }
 
@@ -151,8 +378,17 @@ enum PaletteAction {
         #[arg(long)]
         base: String,
         /// Harmony type to generate
-        #[arg(long, value_parser = ["complementary", "split-complementary", "analogous", "triadic", "tetradic", "square"])]
+        #[arg(long, value_parser = ["complementary", "split-complementary", "analogous", "triadic", "tetradic", "square", "rectangle"])]
         harmony: String,
+        /// Hue offset in degrees for analogous and rectangle harmonies (defaults: 30 and 60)
+        #[arg(long)]
+        harmony_angle: Option<f32>,
+        /// Number of colors for the analogous harmony, stepped by the angle on both sides of the base (default: 3)
+        #[arg(long, value_parser = parse_harmony_count)]
+        harmony_count: Option<usize>,
+        /// Color space the harmony is computed in (hsv keeps the base's brightness across hues)
+        #[arg(long, value_parser = ["hsl", "hsv"], default_value = "hsl")]
+        space: String,
         /// Number of colors to generate
         #[arg(long, default_value = "5")]
         count: usize,
@@ -163,8 +399,11 @@ enum PaletteAction {
         #[arg(long)]
         background: Option<String>,
         /// Output format
-        #[arg(long, value_parser = ["json", "yaml", "hex"], default_value = "hex")]
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
         format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
         /// Generate and save palette image to this path
         #[arg(long)]
         save_image: Option<String>,
@@ -175,8 +414,14 @@ enum PaletteAction {
         #[arg(long, default_value = "320")]
         image_height: u32,
         /// Image label style
-        #[arg(long, value_parser = ["hex", "base16", "index", "none"], default_value = "index")]
+        #[arg(long, value_parser = ["hex", "base16", "role", "index", "none"], default_value = "index")]
         label: String,
+        /// TrueType font file for image labels (defaults to a system monospace font)
+        #[arg(long)]
+        font: Option<String>,
+        /// Render the saved image inline with half-block characters (truecolor terminals)
+        #[arg(long, requires = "save_image")]
+        preview: bool,
     },
     /// Generate random color palettes
     Random {
@@ -186,15 +431,45 @@ enum PaletteAction {
         /// Generation method
         #[arg(long, value_parser = ["uniform", "golden", "poisson"], default_value = "uniform")]
         method: String,
-        /// Minimum color difference (Delta E)
+        /// Minimum color difference (Delta E), relaxed step by step when needed to reach --count
         #[arg(long)]
         min_delta_e: Option<f32>,
+        /// Nudge colors apart to meet --min-delta-e instead of dropping them, keeping the count (golden method)
+        #[arg(long, requires = "min_delta_e")]
+        spread: bool,
         /// Theme preference
         #[arg(long, value_parser = ["light", "dark"])]
         theme: Option<String>,
+        /// Base color to seed uniform generation (hex)
+        #[arg(long)]
+        base: Option<String>,
+        /// Harmony to sample around --base (uniform method)
+        #[arg(long, requires = "base")]
+        harmony: Option<String>,
+        /// Hue offset in degrees for analogous and rectangle harmonies (defaults: 30 and 60)
+        #[arg(long, requires = "harmony")]
+        harmony_angle: Option<f32>,
+        /// Number of colors for the analogous harmony, stepped by the angle on both sides of the base (default: 3)
+        #[arg(long, requires = "harmony", value_parser = parse_harmony_count)]
+        harmony_count: Option<usize>,
+        /// Saturation range for uniform sampling (e.g., "0.3..0.8")
+        #[arg(long, value_parser = parse_unit_range)]
+        sat_range: Option<Range<f32>>,
+        /// Lightness range for uniform sampling (e.g., "0.25..0.75")
+        #[arg(long, value_parser = parse_unit_range)]
+        light_range: Option<Range<f32>>,
+        /// Maximum candidates to sample before giving up (uniform method)
+        #[arg(long)]
+        max_iterations: Option<usize>,
+        /// Generate this many palettes and keep the one with the best harmony score (uniform and poisson methods)
+        #[arg(long, default_value = "1")]
+        candidates: usize,
         /// Output format
-        #[arg(long, value_parser = ["json", "yaml", "hex"], default_value = "hex")]
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
         format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
         /// Generate and save palette image to this path
         #[arg(long)]
         save_image: Option<String>,
@@ -205,8 +480,14 @@ enum PaletteAction {
         #[arg(long, default_value = "320")]
         image_height: u32,
         /// Image label style
-        #[arg(long, value_parser = ["hex", "base16", "index", "none"], default_value = "index")]
+        #[arg(long, value_parser = ["hex", "base16", "role", "index", "none"], default_value = "index")]
         label: String,
+        /// TrueType font file for image labels (defaults to a system monospace font)
+        #[arg(long)]
+        font: Option<String>,
+        /// Render the saved image inline with half-block characters (truecolor terminals)
+        #[arg(long, requires = "save_image")]
+        preview: bool,
     },
     /// Export Base16 palette from scheme
     Base16 {
@@ -214,8 +495,20 @@ enum PaletteAction {
         #[arg(long)]
         scheme_yaml: String,
         /// Output format
-        #[arg(long, value_parser = ["json", "yaml", "hex"], default_value = "hex")]
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
         format: String,
+        /// Brightness offset added in linear light (post-processing)
+        #[arg(long, default_value_t = 0.0, allow_hyphen_values = true)]
+        brightness: f32,
+        /// Contrast multiplier applied in linear light (post-processing)
+        #[arg(long, default_value_t = 1.0)]
+        contrast: f32,
+        /// Gamma exponent applied in linear light; values below 1 lift midtones (post-processing)
+        #[arg(long, default_value_t = 1.0)]
+        gamma: f32,
+        /// Scale Lch chroma by 1 + amount, gamut-mapping instead of clipping; negative mutes (post-processing)
+        #[arg(long, default_value_t = 0.0, allow_hyphen_values = true)]
+        boost_chroma: f32,
     },
     /// Export Base24 palette from scheme
     Base24 {
@@ -223,27 +516,216 @@ enum PaletteAction {
         #[arg(long)]
         scheme_yaml: String,
         /// Output format
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
+        format: String,
+        /// Brightness offset added in linear light (post-processing)
+        #[arg(long, default_value_t = 0.0, allow_hyphen_values = true)]
+        brightness: f32,
+        /// Contrast multiplier applied in linear light (post-processing)
+        #[arg(long, default_value_t = 1.0)]
+        contrast: f32,
+        /// Gamma exponent applied in linear light; values below 1 lift midtones (post-processing)
+        #[arg(long, default_value_t = 1.0)]
+        gamma: f32,
+        /// Scale Lch chroma by 1 + amount, gamut-mapping instead of clipping; negative mutes (post-processing)
+        #[arg(long, default_value_t = 0.0, allow_hyphen_values = true)]
+        boost_chroma: f32,
+    },
+    /// Find the highest- and lowest-contrast color pairs in a palette or scheme
+    BestPair {
+        /// Color values as hex codes (comma-separated; "-" reads stdin)
+        #[arg(long, conflicts_with = "base16", required_unless_present = "base16")]
+        colors: Option<String>,
+        /// Base16/Base24 scheme YAML file
+        #[arg(long)]
+        base16: Option<String>,
+    },
+    /// Snap colors to their nearest match in a target palette (ΔE2000)
+    Quantize {
+        /// Color values as hex codes (comma-separated; "-" reads stdin)
+        #[arg(long)]
+        colors: String,
+        /// Target palette: "websafe" or comma-separated hex codes
+        #[arg(long, default_value = "websafe")]
+        to: String,
+        /// Output format
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
+        format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
+    },
+    /// Split a palette into warm, cool, and neutral colors
+    Partition {
+        /// Color values as hex codes (comma-separated; "-" reads stdin)
+        #[arg(long)]
+        colors: String,
+        /// Output format
         #[arg(long, value_parser = ["json", "yaml", "hex"], default_value = "hex")]
         format: String,
     },
+    /// Print the pairwise ΔE distance matrix of a palette
+    Distances {
+        /// Color values as hex codes (comma-separated; "-" reads stdin)
+        #[arg(long)]
+        colors: String,
+        /// ΔE formula (cmc is asymmetric: rows are the reference colors)
+        #[arg(long, value_parser = ["76", "94", "2000", "cmc"], default_value = "2000")]
+        metric: String,
+    },
+    /// Sort a palette by warmth, lightness, hue, or chroma
+    Sort {
+        /// Color values as hex codes (comma-separated; "-" reads stdin)
+        #[arg(long)]
+        colors: String,
+        /// Sort key; warmth runs cool to warm, lightness dark to light
+        #[arg(long, value_parser = ["warmth", "lightness", "hue", "chroma"], default_value = "warmth")]
+        by: String,
+        /// Reverse the order (e.g. warmest first)
+        #[arg(long)]
+        reverse: bool,
+        /// Output format
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
+        format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
+    },
+    /// Resample a palette to an exact number of evenly spaced colors along its gradient
+    Resample {
+        /// Color values as hex codes (comma-separated; "-" reads stdin)
+        #[arg(long)]
+        colors: String,
+        /// Number of colors to produce
+        #[arg(long)]
+        count: usize,
+        /// Interpolation color space
+        #[arg(long, value_parser = ["rgb", "lab", "lch", "oklch"], default_value = "lab")]
+        space: String,
+        /// Output format
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
+        format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
+    },
+    /// Generate a light-to-dark color scale (tints, the base, then shades) from one color
+    Scale {
+        /// Base color (hex or CSS color)
+        #[arg(long)]
+        base: String,
+        /// Number of steps in the scale, including the base
+        #[arg(long, default_value = "10")]
+        steps: usize,
+        /// Output format
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
+        format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
+    },
+    /// Generate a Material 3 tonal palette (tones 0-100) for an HCT hue and chroma
+    Tonal {
+        /// HCT hue in degrees
+        #[arg(long)]
+        hue: f32,
+        /// HCT chroma (Material primary palettes use 48)
+        #[arg(long, default_value_t = 48.0)]
+        chroma: f32,
+        /// Output format
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
+        format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
+    },
+    /// Show how an image's pixels are distributed by hue or lightness
+    Histogram {
+        /// Image file (PNG or JPEG)
+        #[arg(long)]
+        path: String,
+        /// Number of buckets
+        #[arg(long, default_value = "12")]
+        bins: usize,
+        /// Bucket by hue (achromatic pixels skipped) or Lab lightness
+        #[arg(long, value_parser = ["hue", "lightness"], default_value = "hue")]
+        by: String,
+        /// Transparent pixels: skip fully transparent ones, composite over --alpha-bg, or ignore alpha
+        #[arg(long, value_parser = ["skip", "composite", "ignore"], default_value = "skip")]
+        on_alpha: String,
+        /// Background color for --on-alpha composite (defaults to white)
+        #[arg(long)]
+        alpha_bg: Option<String>,
+    },
+    /// Extract an image's dominant colors with k-means clustering, most common first
+    Extract {
+        /// Image file (PNG or JPEG)
+        #[arg(long)]
+        path: String,
+        /// Maximum number of colors to extract
+        #[arg(long, default_value = "6")]
+        count: usize,
+        /// ΔE formula used to cluster pixels (76 averages clusters; the others pick a representative pixel)
+        #[arg(long, value_parser = ["76", "94", "2000", "cmc"], default_value = "76")]
+        metric: String,
+        /// Transparent pixels: skip fully transparent ones, composite over --alpha-bg, or ignore alpha
+        #[arg(long, value_parser = ["skip", "composite", "ignore"], default_value = "skip")]
+        on_alpha: String,
+        /// Background color for --on-alpha composite (defaults to white)
+        #[arg(long)]
+        alpha_bg: Option<String>,
+        /// Output format
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
+        format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
+    },
+    /// Suggest vivid accent colors that meet a contrast ratio against a background
+    SuggestAccent {
+        /// Background color as hex code (e.g., "#1e1e2e")
+        #[arg(long)]
+        background: String,
+        /// Minimum contrast ratio against the background
+        #[arg(long, default_value_t = wcag::WCAG_AA_NORMAL)]
+        min_contrast: f32,
+        /// Number of accents to suggest
+        #[arg(long, default_value = "3")]
+        count: usize,
+        /// Seed for reproducible suggestions (random when omitted)
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Output format
+        #[arg(long, value_parser = ["json", "yaml", "hex", "css", "scss"], default_value = "hex")]
+        format: String,
+        /// Emit JSON/YAML as a bare array of hex codes instead of per-color metadata
+        #[arg(long)]
+        compact: bool,
+    },
 }
 
 #[derive(Subcommand)]
 enum PreviewType {
     /// Show palette as colored terminal output
     Palette {
-        /// Color values as hex codes (comma-separated)
+        /// Color values as hex codes (comma-separated; "-" reads stdin)
         #[arg(long, conflicts_with = "scheme_yaml")]
         colors: Option<String>,
         /// Base16/Base24 scheme YAML file
         #[arg(long, conflicts_with = "colors")]
         scheme_yaml: Option<String>,
+        /// Swatches per grid row (defaults to as many as fit the terminal width)
+        #[arg(long, conflicts_with = "list")]
+        columns: Option<usize>,
+        /// Print one swatch per line with contrast ratios instead of a grid
+        #[arg(long)]
+        list: bool,
     },
     /// Show syntax-highlighted code sample
     Code {
-        /// Programming language
-        #[arg(long, default_value = "rust")]
-        language: String,
+        /// Programming language (detected from --file when omitted, otherwise rust)
+        #[arg(long)]
+        language: Option<String>,
         /// Base16/Base24 scheme YAML file
         #[arg(long, conflicts_with = "base")]
         theme_yaml: Option<String>,
@@ -253,9 +735,27 @@ enum PreviewType {
         /// Harmony type (when using --base)
         #[arg(long, requires = "base")]
         harmony: Option<String>,
+        /// Hue offset in degrees for analogous and rectangle harmonies (defaults: 30 and 60)
+        #[arg(long, requires = "harmony")]
+        harmony_angle: Option<f32>,
+        /// Number of colors for the analogous harmony, stepped by the angle on both sides of the base (default: 3)
+        #[arg(long, requires = "harmony", value_parser = parse_harmony_count)]
+        harmony_count: Option<usize>,
         /// Source code file to highlight (reads from stdin if not provided)
         #[arg(long)]
         file: Option<String>,
+        /// Render comments in italics (on by default; pass "--italic-comments false" to disable)
+        #[arg(long, num_args = 0..=1, default_value_t = true, default_missing_value = "true")]
+        italic_comments: bool,
+        /// Render keywords in bold
+        #[arg(long)]
+        bold_keywords: bool,
+        /// Recolor a scope with a scheme slot, e.g. "markup.inserted=base0B" (repeatable)
+        #[arg(long = "scope", value_parser = parse_scope_override)]
+        scopes: Vec<(String, usize)>,
+        /// Terminal color depth for highlighted code: 24-bit truecolor or xterm 256-color
+        #[arg(long, value_parser = ["truecolor", "256"], default_value = "truecolor")]
+        color_depth: String,
     },
 }
 
@@ -265,9 +765,12 @@ fn main() {
     match cli.command {
         Commands::Scheme { action } => handle_scheme(action),
         Commands::Palette { action } => handle_palette(action),
-        Commands::Image { colors, scheme_yaml, out, width, height, label, viz } => {
-            handle_image(colors, scheme_yaml, out, width, height, label, viz)
+        Commands::Color { action } => handle_color(action),
+        Commands::Contrast { fg, bg } => handle_contrast(fg, bg),
+        Commands::Gradient { from, to, stops, steps, space, out } => {
+            handle_gradient(from, to, stops, steps, space, out)
         }
+        Commands::Image(args) => handle_image(args),
         Commands::VimScheme { scheme_yaml, name, output_colors, update_vimrc } => {
             handle_vim_scheme(scheme_yaml, name, output_colors, update_vimrc)
         }
@@ -275,16 +778,88 @@ fn main() {
     }
 }
 
+fn handle_color(action: ColorAction) {
+    match action {
+        ColorAction::FromHash { input } => {
+            let color = random::color_from_hash(input.as_bytes());
+            syntax::display_palette_in_terminal(&[color], Some(&[input]));
+        }
+        ColorAction::Name { color } => match parse_hex_color(&color) {
+            Ok(color) => {
+                let (name, delta) = diffs::nearest_css_name(color);
+                println!("{name} (ΔE {delta:.1})");
+            }
+            Err(err) => eprintln!("{err}"),
+        },
+    }
+}
+
 fn handle_scheme(action: SchemeAction) {
     match action {
-        SchemeAction::Generate { format, name, author, variant, accent, harmony, neutral_depth, output } => {
-            let accent_color = match parse_hex_color(&accent) {
-                Ok(color) => color,
+        SchemeAction::Generate {
+            format,
+            name,
+            author,
+            variant,
+            accent,
+            accents,
+            harmony,
+            harmony_angle,
+            harmony_count,
+            neutral_depth,
+            neutral_hue,
+            neutral_hue_range,
+            accent_lightness,
+            accent_saturation,
+            monochrome,
+            oklch,
+            output,
+        } => {
+            if monochrome && format != "base16" {
+                eprintln!("--monochrome is only supported for base16 schemes");
+                return;
+            }
+            if oklch && format == "ansi16" {
+                eprintln!("--oklch is only supported for base16 and base24 schemes");
+                return;
+            }
+            if (neutral_hue.is_some() || neutral_hue_range.is_some()) && format == "ansi16" {
+                eprintln!("--neutral-hue and --neutral-hue-range are only supported for base16 and base24 schemes");
+                return;
+            }
+            if (accent_lightness.is_some() || accent_saturation.is_some()) && format == "ansi16" {
+                eprintln!(
+                    "--accent-lightness and --accent-saturation are only supported for base16 and base24 schemes"
+                );
+                return;
+            }
+
+            if accents.is_some() && format != "base16" {
+                eprintln!("--accents is only supported for base16 schemes");
+                return;
+            }
+
+            let seeds = match accents.as_deref().map(parse_color_list).transpose() {
+                Ok(seeds) => seeds.unwrap_or_default(),
                 Err(err) => {
                     eprintln!("{err}");
                     return;
                 }
             };
+            let accent_color = match (accent.as_deref(), seeds.first()) {
+                (Some(accent), _) => match parse_hex_color(accent) {
+                    Ok(color) => color,
+                    Err(err) => {
+                        eprintln!("{err}");
+                        return;
+                    }
+                },
+                (None, Some(&seed)) => seed,
+                (None, None) => {
+                    eprintln!("--accents needs at least one color");
+                    return;
+                }
+            };
 
             let variant = match variant.as_str() {
                 "dark" => Variant::Dark,
@@ -295,7 +870,7 @@ fn handle_scheme(action: SchemeAction) {
                 }
             };
 
-            let harmony_kind = match parse_harmony_kind(&harmony) {
+            let harmony_kind = match parse_harmony_kind(&harmony, harmony_angle, harmony_count) {
                 Some(kind) => kind,
                 None => {
                     eprintln!("Unsupported harmony: {harmony}");
@@ -308,12 +883,31 @@ fn handle_scheme(action: SchemeAction) {
                 format!("{sanitized}.yml")
             });
             let neutral_depth = neutral_depth.clamp(0.0, 1.0);
+            let accent_lightness = accent_lightness.map(|l| l.clamp(0.0, 1.0));
+            let accent_saturation = accent_saturation.map(|s| s.clamp(0.0, 1.0));
 
             match format.as_str() {
                 "base16" => {
-                    let config =
-                        Base16Config { name, author, variant, accent_color, harmony: harmony_kind, neutral_depth };
-                    let scheme = base16_builder::generate_base16_scheme(config);
+                    let config = Base16Config {
+                        name,
+                        author,
+                        variant,
+                        accent_color,
+                        harmony: harmony_kind,
+                        neutral_depth,
+                        use_oklch: oklch,
+                        neutral_hue,
+                        neutral_hue_range,
+                        accent_lightness,
+                        accent_saturation,
+                    };
+                    let scheme = if monochrome {
+                        base16_builder::generate_monochrome_scheme(config)
+                    } else if !seeds.is_empty() {
+                        base16_builder::generate_base16_from_accents(config, &seeds)
+                    } else {
+                        base16_builder::generate_base16_scheme(config)
+                    };
 
                     if let Err(err) = tinted_theming::write_base16_scheme(&scheme, &output_path) {
                         eprintln!("Failed to write scheme: {err}");
@@ -327,8 +921,19 @@ fn handle_scheme(action: SchemeAction) {
                     syntax::display_palette_in_terminal(scheme.colors(), Some(&base16_labels(16)));
                 }
                 "base24" => {
-                    let config =
-                        Base24Config { name, author, variant, accent_color, harmony: harmony_kind, neutral_depth };
+                    let config = Base24Config {
+                        name,
+                        author,
+                        variant,
+                        accent_color,
+                        harmony: harmony_kind,
+                        neutral_depth,
+                        use_oklch: oklch,
+                        neutral_hue,
+                        neutral_hue_range,
+                        accent_lightness,
+                        accent_saturation,
+                    };
                     let scheme = base16_builder::generate_base24_scheme(config);
 
                     if let Err(err) = tinted_theming::write_base24_scheme(&scheme, &output_path) {
@@ -342,12 +947,29 @@ fn handle_scheme(action: SchemeAction) {
                     println!("\nPreview:");
                     syntax::display_palette_in_terminal(scheme.colors(), Some(&base16_labels(24)));
                 }
+                "ansi16" => {
+                    let colors = ansi::generate_ansi16_with(accent_color, variant, harmony_kind, neutral_depth);
+
+                    if let Err(err) =
+                        ansi::write_ansi16_palette(&colors, &name, author.as_deref(), variant, &output_path)
+                    {
+                        eprintln!("Failed to write scheme: {err}");
+                        return;
+                    }
+
+                    println!("Generated ANSI 16-color scheme: {name}");
+                    println!("  Variant: {}", variant.as_str());
+                    println!("  Output: {output_path}");
+                    println!("\nPreview:");
+                    let labels: Vec<String> = ansi::ANSI16_NAMES.iter().map(|n| n.to_string()).collect();
+                    syntax::display_palette_in_terminal(&colors, Some(&labels));
+                }
                 _ => {
                     eprintln!("Invalid format: {format}");
                 }
             }
         }
-        SchemeAction::Show { scheme, format, output, width, height, demo, file } => {
+        SchemeAction::Show { scheme, format, output, width, height, demo, file, color_depth } => {
             let schemes_base16 = tinted_theming::load_base16_schemes(&scheme);
             let schemes_base24 = tinted_theming::load_base24_schemes(&scheme);
 
@@ -367,42 +989,59 @@ fn handle_scheme(action: SchemeAction) {
                     syntax::display_palette_in_terminal(&colors, Some(&labels));
 
                     if let Some(lang) = demo {
-                        if let Some(file_path) = file {
-                            println!("\nSyntax demo ({lang}):");
-
-                            let theme = if colors.len() == 16 {
-                                if let Ok(schemes) = tinted_theming::load_base16_schemes(&scheme) {
-                                    syntax::base16_to_theme(&schemes[0])
-                                } else {
-                                    eprintln!("Failed to load Base16 scheme");
-                                    return;
-                                }
+                        let theme = if colors.len() == 16 {
+                            if let Ok(schemes) = tinted_theming::load_base16_schemes(&scheme) {
+                                syntax::base16_to_theme(&schemes[0])
+                            } else {
+                                eprintln!("Failed to load Base16 scheme");
+                                return;
+                            }
+                        } else {
+                            if let Ok(schemes) = tinted_theming::load_base24_schemes(&scheme) {
+                                syntax::base24_to_theme(&schemes[0])
                             } else {
-                                if let Ok(schemes) = tinted_theming::load_base24_schemes(&scheme) {
-                                    syntax::base24_to_theme(&schemes[0])
-                                } else {
-                                    eprintln!("Failed to load Base24 scheme");
+                                eprintln!("Failed to load Base24 scheme");
+                                return;
+                            }
+                        };
+
+                        let syntax_set = syntax::load_syntax_set();
+                        let Some(syntax_ref) = syntax::find_syntax_by_name(syntax_set, &lang) else {
+                            eprintln!("Unknown language: {lang}");
+                            return;
+                        };
+                        if let Some(file_path) = file {
+                            let code = match fs::read_to_string(&file_path) {
+                                Ok(code) => code,
+                                Err(err) => {
+                                    eprintln!("Failed to open file {file_path}: {err}");
                                     return;
                                 }
                             };
 
-                            let syntax_set = syntax::load_syntax_set();
-                            if let Some(syntax_ref) = syntax::find_syntax_by_name(&syntax_set, &lang) {
-                                if let Ok(file_handle) = File::open(&file_path) {
-                                    let reader = BufReader::new(file_handle);
-                                    let _ = syntax::highlight_code_to_terminal(
-                                        reader,
-                                        syntax_ref,
-                                        &theme,
-                                        Some(&file_path),
-                                        Some(&scheme_name),
-                                    );
-                                } else {
-                                    eprintln!("Failed to open file: {file_path}");
-                                }
-                            } else {
-                                eprintln!("Unknown language: {lang}");
-                            }
+                            println!("\nSyntax demo ({lang}):");
+                            let _ = syntax::highlight_code_to_terminal(
+                                code.as_bytes(),
+                                syntax_ref,
+                                &theme,
+                                Some(&file_path),
+                                Some(&scheme_name),
+                                parse_color_depth(&color_depth),
+                            );
+                        } else {
+                            let Some(sample) = syntax::builtin_sample(&lang) else {
+                                eprintln!("No built-in sample for {lang}; pass --file");
+                                return;
+                            };
+
+                            println!("\nSyntax demo ({lang}):");
+                            let _ = syntax::highlight_string_to_terminal(
+                                sample,
+                                syntax_ref,
+                                &theme,
+                                Some(&scheme_name),
+                                parse_color_depth(&color_depth),
+                            );
                         }
                     }
                 }
@@ -422,83 +1061,533 @@ fn handle_scheme(action: SchemeAction) {
                 }
             }
         }
-        SchemeAction::Validate { scheme } => {
-            let schemes_base16 = tinted_theming::load_base16_schemes(&scheme);
-            let schemes_base24 = tinted_theming::load_base24_schemes(&scheme);
+        SchemeAction::Diff { a, b, threshold } => handle_scheme_diff(&a, &b, threshold),
+        SchemeAction::ContrastMatrix { scheme } => handle_contrast_matrix(&scheme),
+        SchemeAction::ContrastSheet { scheme, output, cell_width, cell_height } => {
+            handle_contrast_sheet(&scheme, &output, (cell_width, cell_height))
+        }
+        SchemeAction::Edit { scheme, set, output } => handle_scheme_edit(&scheme, &set, output.as_deref()),
+        SchemeAction::SetMeta { scheme, name, author, variant, output } => handle_scheme_set_meta(
+            &scheme,
+            name.as_deref(),
+            author.as_deref(),
+            variant.as_deref(),
+            output.as_deref(),
+        ),
+        SchemeAction::Rotate { scheme, degrees, preserve_luminance, output } => {
+            handle_scheme_rotate(&scheme, degrees, preserve_luminance, &output)
+        }
+        SchemeAction::Invert { scheme, output } => handle_scheme_invert(&scheme, &output),
+        SchemeAction::Export { scheme, format, name, output } => {
+            handle_scheme_export(&scheme, &format, name.as_deref(), output.as_deref())
+        }
+        SchemeAction::Merge { neutrals, accents, output } => handle_scheme_merge(&neutrals, &accents, &output),
+        SchemeAction::Validate { scheme, min_accent_delta_e, syntax } => {
+            handle_scheme_validate(&scheme, min_accent_delta_e, syntax)
+        }
+        SchemeAction::Lint { scheme } => handle_scheme_lint(&scheme),
+    }
+}
 
-            let (colors, scheme_name, system) = if let Ok(schemes) = schemes_base16 {
-                (schemes[0].colors().to_vec(), schemes[0].metadata.name.clone(), "Base16")
-            } else if let Ok(schemes) = schemes_base24 {
-                (schemes[0].colors().to_vec(), schemes[0].metadata.name.clone(), "Base24")
-            } else {
-                eprintln!("Failed to load scheme: {scheme}");
-                return;
-            };
+/// Prints each check of a validation or lint report with its severity tag.
+fn print_report_checks(report: &tinted_theming::ValidationReport) {
+    for check in &report.checks {
+        let tag = match check.severity {
+            Severity::Ok => "[OK]",
+            Severity::Warning => "[WARN]",
+            Severity::Error => "[ERROR]",
+        };
+        println!("  {tag} {}", check.message);
+    }
+}
 
-            println!("Validating {system} scheme: {scheme_name}");
-            println!();
+fn handle_scheme_lint(path: &str) {
+    let (colors, metadata) = if let Ok(schemes) = tinted_theming::load_base16_schemes(path) {
+        (schemes[0].colors().to_vec(), schemes[0].metadata.clone())
+    } else if let Ok(schemes) = tinted_theming::load_base24_schemes(path) {
+        (schemes[0].colors().to_vec(), schemes[0].metadata.clone())
+    } else {
+        eprintln!("Failed to load scheme: {path}");
+        return;
+    };
+
+    println!("Linting scheme: {}", metadata.name);
+    println!();
+    let report = tinted_theming::lint_scheme(&colors, metadata.variant.as_deref());
+    print_report_checks(&report);
+
+    println!();
+    match report.warnings() + report.errors() {
+        0 => println!("Lint passed: the scheme follows the style conventions."),
+        count => println!("Lint found {count} issue(s)."),
+    }
+}
+
+/// Validates one scheme file in detail, or every scheme in a directory with a summary table.
+fn handle_scheme_validate(path: &str, min_accent_delta_e: f32, syntax: bool) {
+    if !Path::new(path).is_dir()
+        && let Err(errors) = tinted_theming::validate_scheme_file(path)
+    {
+        println!("Validating scheme file: {path}");
+        println!();
+        for error in &errors {
+            println!("  [ERROR] {error}");
+        }
+        println!();
+        println!("Validation found {} error(s).", errors.len());
+        return;
+    }
 
-            let mut issues = 0;
+    let mut schemes: Vec<(Vec<Srgb8>, SchemeMetadata)> = if let Ok(schemes) = tinted_theming::load_base16_schemes(path)
+    {
+        schemes.into_iter().map(|s| (s.colors().to_vec(), s.metadata)).collect()
+    } else if let Ok(schemes) = tinted_theming::load_base24_schemes(path) {
+        schemes.into_iter().map(|s| (s.colors().to_vec(), s.metadata)).collect()
+    } else {
+        eprintln!("Failed to load scheme: {path}");
+        return;
+    };
+
+    if !Path::new(path).is_dir() {
+        let (colors, metadata) = &schemes[0];
+        let system = if metadata.system == "base24" { "Base24" } else { "Base16" };
+        println!("Validating {system} scheme: {}", metadata.name);
+        println!();
+
+        let mut report = tinted_theming::validate_scheme(
+            colors,
+            &metadata.system,
+            metadata.variant.as_deref(),
+            min_accent_delta_e,
+        );
+        if syntax {
+            push_scope_distinctness(&mut report, colors, metadata);
+        }
+        print_report_checks(&report);
+
+        println!();
+        if report.passed() {
+            println!("Validation passed with no errors.");
+        } else {
+            println!("Validation found {} error(s).", report.errors());
+        }
+        return;
+    }
+
+    schemes.sort_by(|(_, a), (_, b)| a.name.cmp(&b.name));
+    println!("Validating {} schemes in {path}", schemes.len());
+    println!();
+    let name_width = schemes
+        .iter()
+        .map(|(_, metadata)| metadata.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    println!(
+        "  {:<name_width$}  {:>6}  {:>8}  Result",
+        "Scheme", "Errors", "Warnings"
+    );
+
+    let mut failed = 0;
+    for (colors, metadata) in &schemes {
+        let mut report = tinted_theming::validate_scheme(
+            colors,
+            &metadata.system,
+            metadata.variant.as_deref(),
+            min_accent_delta_e,
+        );
+        if syntax {
+            push_scope_distinctness(&mut report, colors, metadata);
+        }
+        if !report.passed() {
+            failed += 1;
+        }
+        println!(
+            "  {:<name_width$}  {:>6}  {:>8}  {}",
+            metadata.name,
+            report.errors(),
+            report.warnings(),
+            if report.passed() { "PASS" } else { "FAIL" }
+        );
+    }
+
+    println!();
+    println!("{} passed, {failed} failed", schemes.len() - failed);
+}
+
+/// Adds a warning for each related syntax scope pair whose colors are hard to tell apart.
+///
+/// Syntax themes only use the Base16 slots, so Base24 schemes are checked on their first 16 colors.
+fn push_scope_distinctness(report: &mut tinted_theming::ValidationReport, colors: &[Srgb8], metadata: &SchemeMetadata) {
+    let Ok(base16) = <[Srgb8; 16]>::try_from(&colors[..colors.len().min(16)]) else {
+        return;
+    };
+    let theme = syntax::base16_to_theme(&Base16Scheme::new(metadata.clone(), base16));
+    for (a, b, delta) in syntax::check_scope_distinctness(&theme) {
+        report.checks.push(tinted_theming::ValidationCheck {
+            severity: Severity::Warning,
+            message: format!("Syntax scopes {a} and {b} are hard to distinguish (ΔE2000 {delta:.1})"),
+        });
+    }
+}
+
+fn handle_scheme_diff(a: &str, b: &str, threshold: f32) {
+    let Some((colors_a, name_a)) = load_scheme_colors(a) else {
+        eprintln!("Failed to load scheme: {a}");
+        return;
+    };
+    let Some((colors_b, name_b)) = load_scheme_colors(b) else {
+        eprintln!("Failed to load scheme: {b}");
+        return;
+    };
+
+    println!("Comparing {name_a} -> {name_b}");
+    if colors_a.len() != colors_b.len() {
+        println!(
+            "  [WARN] Slot counts differ ({} vs {}); comparing shared slots",
+            colors_a.len(),
+            colors_b.len()
+        );
+    }
+    println!();
+
+    let diff = tinted_theming::diff_palettes(&colors_a, &colors_b);
+    let mut flagged = 0;
+    for (key, before, after, delta) in &diff {
+        let marker = if *delta > threshold {
+            flagged += 1;
+            "  [CHANGED]"
+        } else {
+            ""
+        };
+        println!("  {key}  {before}  {after}  ΔE {delta:>6.2}{marker}");
+    }
+
+    println!();
+    println!("{flagged} of {} slot(s) exceed ΔE {threshold:.2}.", diff.len());
+}
+
+fn handle_contrast_matrix(path: &str) {
+    let Some((colors, name)) = load_scheme_colors(path) else {
+        eprintln!("Failed to load scheme: {path}");
+        return;
+    };
 
-            let expected_count = if system == "Base16" { 16 } else { 24 };
-            if colors.len() != expected_count {
-                println!("  [ERROR] Expected {expected_count} colors, found {}", colors.len());
-                issues += 1;
+    let matrix = wcag::contrast_matrix(&colors, &CONTRAST_FOREGROUNDS, &CONTRAST_BACKGROUNDS);
+    println!("Contrast matrix for {name} (rows: foreground, columns: background)");
+    println!();
+    print!("        ");
+    for bg in CONTRAST_BACKGROUNDS {
+        print!("  base{bg:02X}");
+    }
+    println!();
+
+    for (fg, row) in CONTRAST_FOREGROUNDS.iter().zip(&matrix) {
+        print!("  base{fg:02X}");
+        for &ratio in row {
+            let cell = format!("{ratio:>8.2}");
+            if wcag::meets_aa_normal(ratio) {
+                print!("{}", cell.green());
+            } else if wcag::meets_aa_large(ratio) {
+                print!("{}", cell.yellow());
             } else {
-                println!("  [OK] Color count: {}", colors.len());
+                print!("{}", cell.red());
             }
+        }
+        println!();
+    }
 
-            let mut high_saturation_neutrals = Vec::new();
-            for (i, &color) in colors.iter().take(8).enumerate() {
-                let hsl: colorizer::colors::Hsl = colorizer::colors::Rgb::from(color).into();
-                if hsl.s > base16_builder::NEUTRAL_MAX_SATURATION + NEUTRAL_SATURATION_TOLERANCE {
-                    high_saturation_neutrals.push((i, hsl.s));
-                }
-            }
+    println!();
+    println!(
+        "green: AA ({:.1}:1), yellow: AA large only ({:.1}:1), red: fail",
+        wcag::WCAG_AA_NORMAL,
+        wcag::WCAG_AA_LARGE
+    );
+}
+
+/// Maps a `--metric` value to its ΔE formula and display label.
+fn parse_delta_e_metric(metric: &str) -> (diffs::DeltaEMetric, &'static str) {
+    match metric {
+        "76" => (diffs::DeltaEMetric::Cie76, "ΔE76"),
+        "94" => (diffs::DeltaEMetric::Cie94, "ΔE94"),
+        "cmc" => (diffs::DeltaEMetric::Cmc, "ΔE CMC(2:1)"),
+        _ => (diffs::DeltaEMetric::Ciede2000, "ΔE2000"),
+    }
+}
 
-            if high_saturation_neutrals.is_empty() {
-                println!("  [OK] Neutrals (base00-base07) have low saturation");
+fn handle_palette_distances(colors: &[Srgb8], metric: &str) {
+    let (metric, label) = parse_delta_e_metric(metric);
+    let matrix = diffs::distance_matrix(colors, metric);
+    println!("{label} distance matrix ({} colors)", colors.len());
+    println!();
+    print!("         ");
+    for color in colors {
+        print!("  {color}");
+    }
+    println!();
+
+    for (i, (color, row)) in colors.iter().zip(&matrix).enumerate() {
+        print!("  {color}");
+        for (j, &delta) in row.iter().enumerate() {
+            let cell = format!("{delta:>9.2}");
+            if i != j && !diffs::is_just_noticeable(delta, diffs::DEFAULT_JND_THRESHOLD) {
+                print!("{}", cell.red());
             } else {
-                println!(
-                    "  [WARN] Some neutrals have high saturation: {}",
-                    high_saturation_neutrals
-                        .iter()
-                        .map(|(i, s)| format!("{i:02X} ({s:.2})"))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                );
+                print!("{cell}");
             }
+        }
+        println!();
+    }
 
-            let background = colors[0];
-            let mut low_contrast_accents = Vec::new();
-            for (i, &color) in colors.iter().enumerate().skip(8).take(8) {
-                let ratio = colorizer::wcag::contrast_ratio(background, color);
-                if ratio < 4.5 {
-                    low_contrast_accents.push((i, ratio));
-                }
-            }
+    println!();
+    println!(
+        "red: below the just-noticeable difference ({:.1})",
+        diffs::DEFAULT_JND_THRESHOLD
+    );
+}
 
-            if low_contrast_accents.is_empty() {
-                println!("  [OK] All accent colors meet WCAG AA contrast (4.5:1) against base00");
-            } else {
-                println!(
-                    "  [ERROR] Low contrast accents: {}",
-                    low_contrast_accents
-                        .iter()
-                        .map(|(i, r)| format!("{i:02X} ({r:.2}:1)"))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                );
-                issues += 1;
+fn handle_contrast_sheet(path: &str, output: &str, cell: (u32, u32)) {
+    let Some((colors, name)) = load_scheme_colors(path) else {
+        eprintln!("Failed to load scheme: {path}");
+        return;
+    };
+
+    let image = contrast_sheet_image(&colors, &CONTRAST_FOREGROUNDS, &CONTRAST_BACKGROUNDS, cell);
+    if let Err(err) = image.save(output) {
+        eprintln!("Failed to save contrast sheet to {output}: {err}");
+    } else {
+        println!("Saved contrast sheet for {name} to {output}");
+    }
+}
+
+/// Reports `path` on stderr and returns true when it is a directory, for scheme subcommands that rewrite one file.
+fn is_directory_arg(path: &str, command: &str) -> bool {
+    let is_dir = Path::new(path).is_dir();
+    if is_dir {
+        eprintln!("{path} is a directory; scheme {command} works on a single scheme file.");
+    }
+    is_dir
+}
+
+fn handle_scheme_edit(path: &str, assignments: &[String], output: Option<&str>) {
+    let mut edits = Vec::with_capacity(assignments.len());
+    for assignment in assignments {
+        match parse_color_assignment(assignment) {
+            Ok(edit) => edits.push(edit),
+            Err(err) => {
+                eprintln!("{err}");
+                return;
             }
+        }
+    }
 
-            println!();
-            if issues == 0 {
-                println!("Validation passed with no errors.");
-            } else {
-                println!("Validation found {issues} error(s).");
+    if is_directory_arg(path, "edit") {
+        return;
+    }
+
+    let output = output.unwrap_or(path);
+    let result = if let Ok(mut schemes) = tinted_theming::load_base16_schemes(path) {
+        let scheme = &mut schemes[0];
+        edits
+            .iter()
+            .try_for_each(|(key, color)| scheme.set_color(key, *color))
+            .and_then(|_| tinted_theming::write_base16_scheme(scheme, output))
+    } else if let Ok(mut schemes) = tinted_theming::load_base24_schemes(path) {
+        let scheme = &mut schemes[0];
+        edits
+            .iter()
+            .try_for_each(|(key, color)| scheme.set_color(key, *color))
+            .and_then(|_| tinted_theming::write_base24_scheme(scheme, output))
+    } else {
+        eprintln!("Failed to load scheme: {path}");
+        return;
+    };
+
+    match result {
+        Ok(()) => {
+            for (key, color) in &edits {
+                println!("  {key} -> {color}");
             }
+            println!("Scheme written to: {output}");
+        }
+        Err(err) => eprintln!("Failed to edit scheme: {err}"),
+    }
+}
+
+fn handle_scheme_set_meta(
+    path: &str, name: Option<&str>, author: Option<&str>, variant: Option<&str>, output: Option<&str>,
+) {
+    if name.is_none() && author.is_none() && variant.is_none() {
+        eprintln!("Nothing to change: pass at least one of --name, --author, or --variant.");
+        return;
+    }
+    if is_directory_arg(path, "set-meta") {
+        return;
+    }
+
+    let output = output.unwrap_or(path);
+    let result = if let Ok(mut schemes) = tinted_theming::load_base16_schemes(path) {
+        let scheme = &mut schemes[0];
+        scheme.metadata.update(name, author, variant);
+        tinted_theming::write_base16_scheme(scheme, output).map(|()| scheme.metadata.clone())
+    } else if let Ok(mut schemes) = tinted_theming::load_base24_schemes(path) {
+        let scheme = &mut schemes[0];
+        scheme.metadata.update(name, author, variant);
+        tinted_theming::write_base24_scheme(scheme, output).map(|()| scheme.metadata.clone())
+    } else {
+        eprintln!("Failed to load scheme: {path}");
+        return;
+    };
+
+    match result {
+        Ok(metadata) => {
+            println!("  Name: {}", metadata.name);
+            println!("  Author: {}", metadata.author.as_deref().unwrap_or("unknown"));
+            println!("  Variant: {}", metadata.variant.as_deref().unwrap_or("unknown"));
+            println!("Scheme written to: {output}");
         }
+        Err(err) => eprintln!("Failed to update scheme: {err}"),
+    }
+}
+
+fn handle_scheme_rotate(path: &str, degrees: f32, preserve_luminance: bool, output: &str) {
+    if is_directory_arg(path, "rotate") {
+        return;
+    }
+    let result = if let Ok(schemes) = tinted_theming::load_base16_schemes(path) {
+        let scheme = &schemes[0];
+        let mut colors = [Srgb8::new(0, 0, 0); 16];
+        colors.copy_from_slice(scheme.colors());
+        rotate_accents(&mut colors, degrees, preserve_luminance);
+        tinted_theming::write_base16_scheme(&Base16Scheme::new(scheme.metadata.clone(), colors), output)
+    } else if let Ok(schemes) = tinted_theming::load_base24_schemes(path) {
+        let scheme = &schemes[0];
+        let mut colors = [Srgb8::new(0, 0, 0); 24];
+        colors.copy_from_slice(scheme.colors());
+        rotate_accents(&mut colors, degrees, preserve_luminance);
+        tinted_theming::write_base24_scheme(&Base24Scheme::new(scheme.metadata.clone(), colors), output)
+    } else {
+        eprintln!("Failed to load scheme: {path}");
+        return;
+    };
+
+    match result {
+        Ok(()) => println!("Rotated accents by {degrees}° and wrote scheme to: {output}"),
+        Err(err) => eprintln!("Failed to write scheme: {err}"),
+    }
+}
+
+fn handle_scheme_merge(neutrals_path: &str, accents_path: &str, output: &str) {
+    if is_directory_arg(neutrals_path, "merge") || is_directory_arg(accents_path, "merge") {
+        return;
+    }
+    let load = |path: &str| match tinted_theming::load_base16_schemes(path) {
+        Ok(mut schemes) => Some(schemes.swap_remove(0)),
+        Err(err) => {
+            eprintln!("Failed to load Base16 scheme {path}: {err}");
+            None
+        }
+    };
+    let (Some(neutrals), Some(accents)) = (load(neutrals_path), load(accents_path)) else {
+        return;
+    };
+
+    let scheme = base16_builder::merge_schemes(&neutrals, &accents);
+    match tinted_theming::write_base16_scheme(&scheme, output) {
+        Ok(()) => println!("Wrote {} to: {output}", scheme.metadata.name),
+        Err(err) => eprintln!("Failed to write scheme: {err}"),
+    }
+}
+
+fn handle_scheme_invert(path: &str, output: &str) {
+    if is_directory_arg(path, "invert") {
+        return;
+    }
+    let scheme = match tinted_theming::load_base16_schemes(path) {
+        Ok(schemes) => base16_builder::invert_variant(&schemes[0]),
+        Err(err) => {
+            eprintln!("Failed to load Base16 scheme {path}: {err}");
+            return;
+        }
+    };
+
+    match tinted_theming::write_base16_scheme(&scheme, output) {
+        Ok(()) => println!(
+            "Wrote {} variant of {} to: {output}",
+            scheme.metadata.variant.as_deref().unwrap_or("inverted"),
+            scheme.metadata.name
+        ),
+        Err(err) => eprintln!("Failed to write scheme: {err}"),
+    }
+}
+
+fn handle_scheme_export(path: &str, format: &str, name: Option<&str>, output: Option<&str>) {
+    let scheme = if let Ok(mut schemes) = tinted_theming::load_base16_schemes(path) {
+        schemes.remove(0)
+    } else if let Ok(schemes) = tinted_theming::load_base24_schemes(path) {
+        let mut colors = [Srgb8::new(0, 0, 0); 16];
+        colors.copy_from_slice(&schemes[0].colors()[..16]);
+        Base16Scheme::new(schemes[0].metadata.clone(), colors)
+    } else {
+        eprintln!("Failed to load scheme: {path}");
+        return;
+    };
+
+    let name = name.unwrap_or(&scheme.metadata.name);
+    let theme = match format {
+        "vscode" => exporters::to_vscode_theme(&scheme, name),
+        "iterm" => exporters::to_itermcolors(&scheme),
+        "markdown" => exporters::to_markdown_table(&scheme),
+        _ => {
+            eprintln!("Unsupported export format: {format}");
+            return;
+        }
+    };
+
+    match output {
+        Some(output) => match fs::write(output, theme) {
+            Ok(()) => println!("Wrote {format} export of '{name}' to: {output}"),
+            Err(err) => eprintln!("Failed to write {output}: {err}"),
+        },
+        None => println!("{theme}"),
+    }
+}
+
+/// Rotates the accent slots (base08-base0F) in Lch space, leaving neutrals and extended slots alone.
+///
+/// With `preserve_luminance`, each accent keeps its WCAG luminance via [`rotate_hue_preserve_luminance`].
+fn rotate_accents(colors: &mut [Srgb8], degrees: f32, preserve_luminance: bool) {
+    let accents = &mut colors[8..16];
+    if preserve_luminance {
+        for slot in accents.iter_mut() {
+            *slot = rotate_hue_preserve_luminance(*slot, degrees);
+        }
+        return;
+    }
+
+    let mut lch: Vec<Lch> = accents.iter().copied().map(Lch::from).collect();
+    rotate_hue_lch(&mut lch, degrees);
+    for (slot, color) in accents.iter_mut().zip(lch) {
+        *slot = Srgb8::from(color);
+    }
+}
+
+/// Parses a `KEY=HEX` slot assignment such as `base08=#ff0000`.
+fn parse_color_assignment(value: &str) -> Result<(String, Srgb8), String> {
+    let (key, hex) = value
+        .split_once('=')
+        .ok_or_else(|| format!("Invalid assignment (expected KEY=HEX): {value}"))?;
+    Ok((key.trim().to_string(), parse_hex_color(hex.trim())?))
+}
+
+/// Loads the first scheme at `path` as Base16, falling back to Base24.
+fn load_scheme_colors(path: &str) -> Option<(Vec<Srgb8>, String)> {
+    if let Ok(schemes) = tinted_theming::load_base16_schemes(path) {
+        Some((schemes[0].colors().to_vec(), schemes[0].metadata.name.clone()))
+    } else if let Ok(schemes) = tinted_theming::load_base24_schemes(path) {
+        Some((schemes[0].colors().to_vec(), schemes[0].metadata.name.clone()))
+    } else {
+        None
     }
 }
 
@@ -507,14 +1596,20 @@ fn handle_palette(action: PaletteAction) {
         PaletteAction::FromBase {
             base,
             harmony,
+            harmony_angle,
+            harmony_count,
+            space,
             count,
             min_contrast,
             background,
             format,
+            compact,
             save_image,
             image_width,
             image_height,
             label,
+            font,
+            preview,
         } => {
             let base_color = match parse_hex_color(&base) {
                 Ok(color) => color,
@@ -524,7 +1619,7 @@ fn handle_palette(action: PaletteAction) {
                 }
             };
 
-            let harmony_kind = match parse_harmony_kind(&harmony) {
+            let harmony_kind = match parse_harmony_kind(&harmony, harmony_angle, harmony_count) {
                 Some(kind) => kind,
                 None => {
                     eprintln!("Unsupported harmony kind: {harmony}");
@@ -541,14 +1636,31 @@ fn handle_palette(action: PaletteAction) {
                 None => None,
             };
 
-            let palette = palette_from_base(base_color, harmony_kind, count, None, background_color, min_contrast);
+            let palette = if space == "hsv" {
+                palette_from_base_hsv(base_color, harmony_kind, count, None, background_color, min_contrast)
+            } else {
+                palette_from_base(base_color, harmony_kind, count, None, background_color, min_contrast)
+            };
             if palette.is_empty() {
                 eprintln!("No colors meet the requested constraints.");
             } else {
-                output_palette(&palette, &format);
+                if palette.len() < count {
+                    eprintln!(
+                        "Only {} of {count} colors could be generated from this base.",
+                        palette.len()
+                    );
+                }
+                output_palette(&palette, &format, compact);
 
                 if let Some(image_path) = save_image {
-                    generate_palette_image(&palette, &image_path, image_width, image_height, &label);
+                    generate_palette_image(
+                        &palette,
+                        &image_path,
+                        (image_width, image_height),
+                        &label,
+                        font.as_deref().map(Path::new),
+                        preview,
+                    );
                 }
             }
         }
@@ -556,98 +1668,550 @@ fn handle_palette(action: PaletteAction) {
             count,
             method,
             min_delta_e,
+            spread,
             theme,
+            base,
+            harmony,
+            harmony_angle,
+            harmony_count,
+            sat_range,
+            light_range,
+            max_iterations,
+            candidates,
             format,
+            compact,
             save_image,
             image_width,
             image_height,
             label,
-        } => {
-            let palette = match method.as_str() {
-                "golden" => {
-                    let (s_range, l_range) = golden_theme_ranges(theme.as_deref());
-                    golden_ratio_palette(count, s_range, l_range, min_delta_e)
-                }
-                "uniform" => {
-                    let constraints = PaletteConstraints { min_delta_e, ..Default::default() };
-                    random::random_palette_with_constraints(count, constraints)
-                }
-                "poisson" => {
-                    let config = PoissonConfig { radius: min_delta_e.unwrap_or(5.0), ..Default::default() };
-                    random::poisson_palette(config, count)
-                        .into_iter()
-                        .map(Srgb8::from)
-                        .collect()
-                }
-                other => {
-                    eprintln!("Random method '{other}' is not implemented yet.");
-                    Vec::new()
-                }
-            };
+            font,
+            preview,
+        } => {
+            if spread && method != "golden" {
+                eprintln!("--spread is only supported with --method golden");
+                return;
+            }
+            if candidates > 1 && method == "golden" {
+                eprintln!("--candidates has no effect with --method golden, which is deterministic");
+                return;
+            }
+            if method != "uniform" {
+                let uniform_only = [
+                    ("--base", base.is_some()),
+                    ("--sat-range", sat_range.is_some()),
+                    ("--light-range", light_range.is_some()),
+                    ("--max-iterations", max_iterations.is_some()),
+                ];
+                if let Some((flag, _)) = uniform_only.iter().find(|(_, given)| *given) {
+                    eprintln!("{flag} is only supported with --method uniform");
+                    return;
+                }
+            }
+
+            let palette = match method.as_str() {
+                "golden" => {
+                    let (s_range, l_range) = golden_theme_ranges(theme.as_deref());
+                    match min_delta_e {
+                        Some(min_delta_e) if spread => {
+                            golden_ratio_palette_spread(count, s_range, l_range, min_delta_e)
+                        }
+                        Some(min_delta_e) => random::fill_to_count(count, min_delta_e, |threshold| {
+                            golden_ratio_palette(count, s_range.clone(), l_range.clone(), Some(threshold))
+                        }),
+                        None => golden_ratio_palette(count, s_range, l_range, None),
+                    }
+                }
+                "uniform" => {
+                    let mut builder = PaletteConstraints::builder();
+                    if let Some(base) = base {
+                        match parse_hex_color(&base) {
+                            Ok(color) => builder = builder.base(color),
+                            Err(err) => {
+                                eprintln!("{err}");
+                                return;
+                            }
+                        }
+                    }
+                    if let Some(harmony) = harmony {
+                        let Some(kind) = parse_harmony_kind(&harmony, harmony_angle, harmony_count) else {
+                            eprintln!("Unknown harmony: {harmony}");
+                            return;
+                        };
+                        builder = builder.harmony(kind);
+                    }
+                    if let Some(range) = sat_range {
+                        builder = builder.saturation_range(range);
+                    }
+                    if let Some(range) = light_range {
+                        builder = builder.lightness_range(range);
+                    }
+                    if let Some(max_iterations) = max_iterations {
+                        builder = builder.max_iterations(max_iterations);
+                    }
+
+                    let constraints = match builder.clone().build() {
+                        Ok(constraints) => constraints,
+                        Err(err) => {
+                            eprintln!("{err}");
+                            return;
+                        }
+                    };
+                    best_of_candidates(candidates, || match min_delta_e {
+                        Some(min_delta_e) => random::fill_to_count(count, min_delta_e, |threshold| {
+                            let spaced = builder.clone().min_delta_e(threshold).build();
+                            random::random_palette_with_constraints(count, spaced.expect("ranges validated above"))
+                        }),
+                        None => random::random_palette_with_constraints(count, constraints.clone()),
+                    })
+                }
+                "poisson" => best_of_candidates(candidates, || {
+                    random::fill_to_count(count, min_delta_e.unwrap_or(5.0), |radius| {
+                        let config = PoissonConfig { radius, ..Default::default() };
+                        random::poisson_palette(config, count)
+                            .into_iter()
+                            .map(Srgb8::from)
+                            .collect()
+                    })
+                }),
+                other => {
+                    eprintln!("Random method '{other}' is not implemented yet.");
+                    Vec::new()
+                }
+            };
+
+            if palette.is_empty() {
+                eprintln!("No colors generated.");
+            } else {
+                output_palette(&palette, &format, compact);
+
+                if let Some(image_path) = save_image {
+                    generate_palette_image(
+                        &palette,
+                        &image_path,
+                        (image_width, image_height),
+                        &label,
+                        font.as_deref().map(Path::new),
+                        preview,
+                    );
+                }
+            }
+        }
+        PaletteAction::Quantize { colors, to, format, compact } => {
+            let target = if to.eq_ignore_ascii_case("websafe") {
+                Ok(diffs::web_safe_palette())
+            } else {
+                parse_color_list(&to)
+            };
+            match (resolve_color_arg(&colors), target) {
+                (Ok(colors), Ok(target)) => output_palette(&diffs::quantize(&colors, &target), &format, compact),
+                (Err(err), _) | (_, Err(err)) => eprintln!("{err}"),
+            }
+        }
+        PaletteAction::Partition { colors, format } => match resolve_color_arg(&colors) {
+            Ok(colors) => output_partition(&palette::partition_temperature(&colors), &format),
+            Err(err) => eprintln!("{err}"),
+        },
+        PaletteAction::Distances { colors, metric } => match resolve_color_arg(&colors) {
+            Ok(colors) => handle_palette_distances(&colors, &metric),
+            Err(err) => eprintln!("{err}"),
+        },
+        PaletteAction::Sort { colors, by, reverse, format, compact } => match resolve_color_arg(&colors) {
+            Ok(mut colors) => {
+                let key = match by.as_str() {
+                    "lightness" => SortKey::Lightness,
+                    "hue" => SortKey::Hue,
+                    "chroma" => SortKey::Chroma,
+                    _ => SortKey::Warmth,
+                };
+                palette::sort_palette(&mut colors, key);
+                if reverse {
+                    colors.reverse();
+                }
+                output_palette(&colors, &format, compact);
+            }
+            Err(err) => eprintln!("{err}"),
+        },
+        PaletteAction::Resample { colors, count, space, format, compact } => match resolve_color_arg(&colors) {
+            Ok(colors) => output_palette(
+                &resample_palette(&colors, count, parse_gradient_space(&space)),
+                &format,
+                compact,
+            ),
+            Err(err) => eprintln!("{err}"),
+        },
+        PaletteAction::Scale { base, steps, format, compact } => match parse_hex_color(&base) {
+            Ok(base) => {
+                let colors: Vec<Srgb8> = colorizer::scale(Rgb::from(base), steps)
+                    .into_iter()
+                    .map(Srgb8::from)
+                    .collect();
+                output_palette(&colors, &format, compact)
+            }
+            Err(err) => eprintln!("{err}"),
+        },
+        PaletteAction::Tonal { hue, chroma, format, compact } => {
+            output_palette(&hct::tonal_palette(hue, chroma), &format, compact)
+        }
+        PaletteAction::Histogram { path, bins, by, on_alpha, alpha_bg } => {
+            handle_histogram(&path, bins, &by, &on_alpha, alpha_bg.as_deref())
+        }
+        PaletteAction::Extract { path, count, metric, on_alpha, alpha_bg, format, compact } => {
+            handle_extract(&path, count, &metric, &on_alpha, alpha_bg.as_deref(), &format, compact)
+        }
+        PaletteAction::BestPair { colors, base16 } => handle_best_pair(colors, base16),
+        PaletteAction::SuggestAccent { background, min_contrast, count, seed, format, compact } => {
+            let bg = match parse_hex_color(&background) {
+                Ok(color) => color,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return;
+                }
+            };
+
+            let seed = seed.unwrap_or_else(rand::random);
+            let accents = random::suggest_accents(bg, min_contrast, count, seed);
+            if accents.is_empty() {
+                eprintln!(
+                    "No accents reach a {min_contrast:.1}:1 contrast against {}.",
+                    bg.to_hex()
+                );
+            } else {
+                output_palette(&accents, &format, compact);
+            }
+        }
+        PaletteAction::Base16 { scheme_yaml, format, brightness, contrast, gamma, boost_chroma } => {
+            match tinted_theming::load_base16_schemes(&scheme_yaml) {
+                Ok(schemes) => {
+                    let palettes: Vec<Vec<Srgb8>> = schemes
+                        .iter()
+                        .map(|s| adjusted_colors(s.colors(), brightness, contrast, gamma, boost_chroma))
+                        .collect();
+                    let entries: Vec<_> = schemes
+                        .iter()
+                        .zip(&palettes)
+                        .map(|(s, c)| (&s.metadata, c.as_slice()))
+                        .collect();
+                    output_scheme_palettes(&entries, &format);
+                }
+                Err(err) => eprintln!("Failed to load Base16 scheme: {err}"),
+            }
+        }
+        PaletteAction::Base24 { scheme_yaml, format, brightness, contrast, gamma, boost_chroma } => {
+            match tinted_theming::load_base24_schemes(&scheme_yaml) {
+                Ok(schemes) => {
+                    let palettes: Vec<Vec<Srgb8>> = schemes
+                        .iter()
+                        .map(|s| adjusted_colors(s.colors(), brightness, contrast, gamma, boost_chroma))
+                        .collect();
+                    let entries: Vec<_> = schemes
+                        .iter()
+                        .zip(&palettes)
+                        .map(|(s, c)| (&s.metadata, c.as_slice()))
+                        .collect();
+                    output_scheme_palettes(&entries, &format);
+                }
+                Err(err) => eprintln!("Failed to load Base24 scheme: {err}"),
+            }
+        }
+    }
+}
+
+/// Parses a single color: hex, a CSS color name, or `rgb()`/`rgba()`/`hsl()`/`hsla()` syntax.
+fn parse_hex_color(value: &str) -> Result<Srgb8, String> {
+    Color::try_parse(value)
+        .map(|color| color.to_srgb8())
+        .map_err(|err| format!("Invalid color value '{value}': {err}."))
+}
+
+/// Parses a harmony name, applying `angle` to the harmonies that take an offset (analogous and rectangle).
+///
+/// `count` sets how many colors the analogous harmony returns. Both are ignored for fixed harmonies.
+fn parse_harmony_kind(value: &str, angle: Option<f32>, count: Option<usize>) -> Option<HarmonyKind> {
+    match value {
+        "complementary" => Some(HarmonyKind::Complementary),
+        "split-complementary" => Some(HarmonyKind::SplitComplementary),
+        "analogous" => Some(HarmonyKind::Analogous { angle: angle.unwrap_or(30.0), count: count.unwrap_or(3) }),
+        "triadic" => Some(HarmonyKind::Triadic),
+        "tetradic" => Some(HarmonyKind::Tetradic),
+        "square" => Some(HarmonyKind::Square),
+        "rectangle" => Some(HarmonyKind::Rectangle(angle.unwrap_or(60.0))),
+        _ => None,
+    }
+}
+
+/// Returns a copy of `colors` with the brightness/contrast/gamma and chroma-boost post-processing applied.
+fn adjusted_colors(colors: &[Srgb8], brightness: f32, contrast: f32, gamma: f32, boost_chroma: f32) -> Vec<Srgb8> {
+    let mut adjusted = colors.to_vec();
+    palette::adjust(&mut adjusted, brightness, contrast, gamma);
+    if boost_chroma != 0.0 {
+        palette::boost_chroma(&mut adjusted, boost_chroma);
+    }
+    adjusted
+}
+
+/// Prints the highest- and lowest-contrast pairs of a color list or scheme, labelled by index or Base16 slot.
+fn handle_best_pair(colors: Option<String>, scheme: Option<String>) {
+    let (palette, labels) = if let Some(path) = scheme {
+        let Some((colors, _)) = load_scheme_colors(&path) else {
+            eprintln!("Failed to load scheme: {path}");
+            return;
+        };
+        let labels = base16_labels(colors.len());
+        (colors, labels)
+    } else {
+        match resolve_color_arg(colors.as_deref().unwrap_or_default()) {
+            Ok(colors) => {
+                let labels = (0..colors.len()).map(|i| format!("{i:02}")).collect();
+                (colors, labels)
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                return;
+            }
+        }
+    };
+
+    let (Some(max), Some(min)) = (wcag::max_contrast_pair(&palette), wcag::min_contrast_pair(&palette)) else {
+        eprintln!("Need at least two colors to compare.");
+        return;
+    };
+    for (title, (i, j, ratio)) in [("Highest contrast", max), ("Lowest contrast", min)] {
+        println!(
+            "{title}: {} {} / {} {} ({ratio:.2}:1)",
+            labels[i],
+            palette[i].to_hex(),
+            labels[j],
+            palette[j].to_hex()
+        );
+    }
+}
+
+const HISTOGRAM_BAR_WIDTH: usize = 40;
+
+/// Maximum width, in terminal columns, of `--preview` image renderings.
+const PREVIEW_MAX_COLS: u32 = 80;
+
+/// Builds the alpha policy for `--on-alpha` and `--alpha-bg`, rejecting a background without `composite`.
+fn parse_alpha_policy(on_alpha: &str, alpha_bg: Option<&str>) -> Result<extract::AlphaPolicy, String> {
+    match (on_alpha, alpha_bg) {
+        ("composite", background) => {
+            parse_hex_color(background.unwrap_or("#ffffff")).map(extract::AlphaPolicy::CompositeOver)
+        }
+        (_, Some(_)) => Err("--alpha-bg only applies with --on-alpha composite.".to_string()),
+        ("ignore", None) => Ok(extract::AlphaPolicy::Ignore),
+        _ => Ok(extract::AlphaPolicy::SkipTransparent),
+    }
+}
+
+/// Loads an image's pixels under the `--on-alpha`/`--alpha-bg` policy, reporting errors on stderr.
+fn load_image_pixels(path: &str, on_alpha: &str, alpha_bg: Option<&str>) -> Option<Vec<Srgb8>> {
+    let alpha = match parse_alpha_policy(on_alpha, alpha_bg) {
+        Ok(alpha) => alpha,
+        Err(err) => {
+            eprintln!("{err}");
+            return None;
+        }
+    };
+    match extract::load_pixels(path, alpha) {
+        Ok(pixels) => Some(pixels),
+        Err(err) => {
+            eprintln!("Failed to load image {path}: {err}");
+            None
+        }
+    }
+}
+
+fn handle_extract(
+    path: &str, count: usize, metric: &str, on_alpha: &str, alpha_bg: Option<&str>, format: &str, compact: bool,
+) {
+    if count == 0 {
+        eprintln!("--count must be at least 1.");
+        return;
+    }
+    let Some(pixels) = load_image_pixels(path, on_alpha, alpha_bg) else {
+        return;
+    };
+    if pixels.is_empty() {
+        eprintln!("{path} has no pixels to extract colors from.");
+        return;
+    }
+
+    let (metric, _) = parse_delta_e_metric(metric);
+    let colors: Vec<Srgb8> = extract::kmeans(&pixels, count, metric)
+        .into_iter()
+        .map(|cluster| cluster.color)
+        .collect();
+    if colors.len() < count {
+        eprintln!(
+            "Found only {} distinct clusters in {path} ({count} requested).",
+            colors.len()
+        );
+    }
+    output_palette(&colors, format, compact);
+}
+
+fn handle_histogram(path: &str, bins: usize, by: &str, on_alpha: &str, alpha_bg: Option<&str>) {
+    let Some(pixels) = load_image_pixels(path, on_alpha, alpha_bg) else {
+        return;
+    };
+    if bins == 0 {
+        eprintln!("--bins must be at least 1.");
+        return;
+    }
+
+    let by_lightness = by == "lightness";
+    let histogram = if by_lightness {
+        extract::lightness_histogram(&pixels, bins)
+    } else {
+        extract::hue_histogram(&pixels, bins)
+    };
+
+    let counted: usize = histogram.iter().map(|&(_, count)| count).sum();
+    let max = histogram.iter().map(|&(_, count)| count).max().unwrap_or(0).max(1);
+    println!("{path}: {} pixels", pixels.len());
+    if !by_lightness {
+        println!("  ({} achromatic pixels skipped)", pixels.len() - counted);
+    }
+
+    for (center, count) in histogram {
+        let (color, label) = if by_lightness {
+            (Srgb8::from(Lab::new(center, 0.0, 0.0)), format!("L{center:>5.1}"))
+        } else {
+            (Srgb8::from(Hsl::new(center, 0.7, 0.5)), format!("{center:>5.0}°"))
+        };
+        let bar = "█".repeat((count * HISTOGRAM_BAR_WIDTH).div_ceil(max));
+        let share = if counted == 0 { 0.0 } else { count as f32 / counted as f32 * 100.0 };
+        println!(
+            "  {} {} {:<width$} {count} ({share:.1}%)",
+            "  ".on_truecolor(color.r, color.g, color.b),
+            label,
+            bar.truecolor(color.r, color.g, color.b),
+            width = HISTOGRAM_BAR_WIDTH
+        );
+    }
+}
 
-            if palette.is_empty() {
-                eprintln!("No colors generated.");
+/// Prints a palette as comma-separated hex codes, CSS/SCSS variables, or JSON/YAML.
+///
+/// JSON/YAML output is a [`PaletteExport`] object unless `compact` is set, which keeps the bare array of hex codes.
+fn output_palette(colors: &[Srgb8], format: &str, compact: bool) {
+    let hex_values: Vec<String> = colors.iter().map(|c| c.to_hex()).collect();
+    let export = PaletteExport::new(colors);
+    match format {
+        "json" => {
+            let serialized = if compact {
+                serde_json::to_string_pretty(&hex_values)
             } else {
-                output_palette(&palette, &format);
-
-                if let Some(image_path) = save_image {
-                    generate_palette_image(&palette, &image_path, image_width, image_height, &label);
-                }
+                serde_json::to_string_pretty(&export)
+            };
+            match serialized {
+                Ok(serialized) => println!("{serialized}"),
+                Err(err) => eprintln!("Failed to serialize palette to JSON: {err}"),
             }
         }
-        // TODO: add combined JSON/YAML output when directory inputs produce multiple schemes.
-        PaletteAction::Base16 { scheme_yaml, format } => match tinted_theming::load_base16_schemes(&scheme_yaml) {
-            Ok(schemes) => {
-                for scheme in schemes {
-                    print_scheme_header(&scheme.metadata);
-                    output_palette(scheme.colors(), &format);
-                }
+        "yaml" => {
+            let serialized = if compact { serde_yml::to_string(&hex_values) } else { serde_yml::to_string(&export) };
+            match serialized {
+                Ok(serialized) => print!("{serialized}"),
+                Err(err) => eprintln!("Failed to serialize palette to YAML: {err}"),
             }
-            Err(err) => eprintln!("Failed to load Base16 scheme: {err}"),
+        }
+        "css" | "scss" => {
+            let names: Vec<String> = (0..colors.len()).map(|i| format!("color-{i:02}")).collect();
+            print!("{}", palette_variables(colors, &names, format));
+        }
+        _ => println!("{}", hex_values.join(", ")),
+    }
+}
+
+/// Prints the warm, cool, and neutral groups as labelled hex lines or a JSON/YAML object.
+fn output_partition(partition: &palette::TemperaturePartition, format: &str) {
+    match format {
+        "json" => match serde_json::to_string_pretty(partition) {
+            Ok(serialized) => println!("{serialized}"),
+            Err(err) => eprintln!("Failed to serialize partition to JSON: {err}"),
         },
-        // TODO: add combined JSON/YAML output when directory inputs produce multiple schemes.
-        PaletteAction::Base24 { scheme_yaml, format } => match tinted_theming::load_base24_schemes(&scheme_yaml) {
-            Ok(schemes) => {
-                for scheme in schemes {
-                    print_scheme_header(&scheme.metadata);
-                    output_palette(scheme.colors(), &format);
-                }
-            }
-            Err(err) => eprintln!("Failed to load Base24 scheme: {err}"),
+        "yaml" => match serde_yml::to_string(partition) {
+            Ok(serialized) => print!("{serialized}"),
+            Err(err) => eprintln!("Failed to serialize partition to YAML: {err}"),
         },
+        _ => {
+            let hex = |colors: &[Srgb8]| colors.iter().map(|c| c.to_hex()).collect::<Vec<_>>().join(", ");
+            println!("Warm: {}", hex(&partition.warm));
+            println!("Cool: {}", hex(&partition.cool));
+            println!("Neutral: {}", hex(&partition.neutral));
+        }
     }
 }
 
-fn parse_hex_color(value: &str) -> Result<Srgb8, String> {
-    Srgb8::from_hex(value).ok_or_else(|| format!("Invalid color value: {value}"))
+/// Renders a palette as CSS custom properties (`css`) or SCSS variables (`scss`).
+fn palette_variables(colors: &[Srgb8], names: &[String], format: &str) -> String {
+    let lines = names.iter().zip(colors).map(|(name, color)| match format {
+        "scss" => format!("${name}: {};\n", color.to_hex()),
+        _ => format!("  --{name}: {};\n", color.to_hex()),
+    });
+
+    match format {
+        "scss" => lines.collect(),
+        _ => format!(":root {{\n{}}}\n", lines.collect::<String>()),
+    }
 }
 
-/// TODO: allow custom angle input
-fn parse_harmony_kind(value: &str) -> Option<HarmonyKind> {
-    match value {
-        "complementary" => Some(HarmonyKind::Complementary),
-        "split-complementary" => Some(HarmonyKind::SplitComplementary),
-        "analogous" => Some(HarmonyKind::Analogous(30.0)),
-        "triadic" => Some(HarmonyKind::Triadic),
-        "tetradic" => Some(HarmonyKind::Tetradic),
-        "square" => Some(HarmonyKind::Square),
-        _ => None,
+/// Palette export entry used when a directory input yields several schemes.
+#[derive(Serialize)]
+struct SchemePaletteExport<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<&'a str>,
+    colors: Vec<String>,
+}
+
+/// Prints one palette per scheme for `hex`/`css`/`scss` (or a single scheme), and a combined document for JSON/YAML.
+///
+/// Scheme exports always list bare hex codes, matching the `colors` field of the combined document.
+fn output_scheme_palettes(entries: &[(&SchemeMetadata, &[Srgb8])], format: &str) {
+    if matches!(format, "css" | "scss") {
+        for (metadata, colors) in entries {
+            println!("/* {} */", metadata.name);
+            print!("{}", palette_variables(colors, &base16_labels(colors.len()), format));
+        }
+        return;
+    }
+
+    if entries.len() <= 1 || !matches!(format, "json" | "yaml") {
+        for (metadata, colors) in entries {
+            print_scheme_header(metadata);
+            output_palette(colors, format, true);
+        }
+        return;
+    }
+
+    match scheme_palettes_to_string(entries, format) {
+        Ok(serialized) if format == "json" => println!("{serialized}"),
+        Ok(serialized) => print!("{serialized}"),
+        Err(err) => eprintln!("{err}"),
     }
 }
 
-/// TODO: consider richer CLI output (labels, indexes) once UX spec is defined.
-fn output_palette(colors: &[Srgb8], format: &str) {
-    let hex_values: Vec<String> = colors.iter().map(|c| c.to_hex()).collect();
+/// Serializes several scheme palettes into a single JSON/YAML array.
+fn scheme_palettes_to_string(entries: &[(&SchemeMetadata, &[Srgb8])], format: &str) -> Result<String, String> {
+    let exports: Vec<SchemePaletteExport> = entries
+        .iter()
+        .map(|(metadata, colors)| SchemePaletteExport {
+            name: &metadata.name,
+            author: metadata.author.as_deref(),
+            variant: metadata.variant.as_deref(),
+            colors: colors.iter().map(|c| c.to_hex()).collect(),
+        })
+        .collect();
+
     match format {
-        "json" => match serde_json::to_string_pretty(&hex_values) {
-            Ok(serialized) => println!("{serialized}"),
-            Err(err) => eprintln!("Failed to serialize palette to JSON: {err}"),
-        },
-        "yaml" => match serde_yml::to_string(&hex_values) {
-            Ok(serialized) => print!("{serialized}"),
-            Err(err) => eprintln!("Failed to serialize palette to YAML: {err}"),
-        },
-        _ => println!("{}", hex_values.join(", ")),
+        "json" => {
+            serde_json::to_string_pretty(&exports).map_err(|err| format!("Failed to serialize palettes to JSON: {err}"))
+        }
+        _ => serde_yml::to_string(&exports).map_err(|err| format!("Failed to serialize palettes to YAML: {err}")),
     }
 }
 
@@ -659,13 +2223,141 @@ fn golden_theme_ranges(theme: Option<&str>) -> (Range<f32>, Range<f32>) {
     }
 }
 
+/// Parses a `--colors` argument, reading from stdin when it is `-` or empty.
+fn resolve_color_arg(value: &str) -> Result<Vec<Srgb8>, String> {
+    match value.trim() {
+        "" | "-" => read_colors_from_stdin(),
+        list => parse_color_list(list),
+    }
+}
+
+fn read_colors_from_stdin() -> Result<Vec<Srgb8>, String> {
+    let mut input = String::new();
+    io::stdin()
+        .lock()
+        .read_to_string(&mut input)
+        .map_err(|err| format!("Failed to read colors from stdin: {err}"))?;
+    parse_color_lines(&input)
+}
+
+/// Parses newline- or comma-separated hex colors, skipping blank lines and `# comments`.
+///
+/// A `#` only starts a comment when followed by whitespace, another `#`, or the end of the line, so `#ff0000` stays a color.
+fn parse_color_lines(input: &str) -> Result<Vec<Srgb8>, String> {
+    let colors: Vec<Srgb8> = input
+        .lines()
+        .map(strip_color_comment)
+        .flat_map(|line| split_outside_parens(line, &[',', ' ', '\t']))
+        .filter(|segment| !segment.trim().is_empty())
+        .map(|segment| parse_hex_color(segment.trim()))
+        .collect::<Result<_, _>>()?;
+
+    if colors.is_empty() {
+        return Err("No colors found in input".to_string());
+    }
+    Ok(colors)
+}
+
+fn strip_color_comment(line: &str) -> &str {
+    line.match_indices('#')
+        .find(|(i, _)| {
+            let rest = &line[i + 1..];
+            rest.is_empty() || rest.starts_with(char::is_whitespace) || rest.starts_with('#')
+        })
+        .map_or(line, |(i, _)| &line[..i])
+}
+
+/// Parses a `lo..hi` range within [0, 1], rejecting reversed or empty ranges.
+fn parse_unit_range(value: &str) -> Result<Range<f32>, String> {
+    let (start, end) = value
+        .split_once("..")
+        .ok_or_else(|| format!("Invalid range (expected lo..hi): {value}"))?;
+    let parse = |part: &str| {
+        part.trim()
+            .parse::<f32>()
+            .map_err(|_| format!("Invalid range bound '{part}' in {value}"))
+    };
+    let (start, end) = (parse(start)?, parse(end)?);
+
+    if !(0.0..=1.0).contains(&start) || !(0.0..=1.0).contains(&end) {
+        return Err(format!("Range bounds must be within 0..1: {value}"));
+    }
+    if start >= end {
+        return Err(format!("Range start must be below its end: {value}"));
+    }
+    Ok(start..end)
+}
+
+/// Parses `--harmony-count`, which needs at least one color.
+fn parse_harmony_count(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("Harmony count must be at least 1".to_string()),
+        Ok(count) => Ok(count),
+        Err(_) => Err(format!("Invalid harmony count: {value}")),
+    }
+}
+
+/// Parses a `start..end` hue range in degrees; either order is allowed since hues wrap.
+fn parse_hue_range(value: &str) -> Result<(f32, f32), String> {
+    let (start, end) = value
+        .split_once("..")
+        .ok_or_else(|| format!("Invalid hue range (expected start..end): {value}"))?;
+    let parse = |part: &str| {
+        part.trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|hue| hue.is_finite())
+            .ok_or_else(|| format!("Invalid hue '{part}' in {value}"))
+    };
+    Ok((parse(start)?, parse(end)?))
+}
+
+/// Parses a `scope=slot` override, where the slot is a Base24 key such as `base0B` or its bare hex index (`0B`).
+fn parse_scope_override(value: &str) -> Result<(String, usize), String> {
+    let (scope, slot) = value
+        .split_once('=')
+        .ok_or_else(|| format!("Invalid scope override (expected scope=baseXX): {value}"))?;
+    let digits = slot.trim().trim_start_matches("base");
+    let index = usize::from_str_radix(digits, 16)
+        .ok()
+        .filter(|&index| digits.len() == 2 && index < 24)
+        .ok_or_else(|| format!("Invalid slot '{slot}' in {value} (expected base00 through base17)"))?;
+    Ok((scope.trim().to_string(), index))
+}
+
 fn parse_color_list(value: &str) -> Result<Vec<Srgb8>, String> {
-    value
-        .split(',')
+    split_outside_parens(value, &[','])
+        .into_iter()
         .map(|segment| parse_hex_color(segment.trim()))
         .collect()
 }
 
+/// Splits on any of `separators`, except inside parentheses, so `rgb(1, 2, 3)` stays one segment.
+fn split_outside_parens<'a>(value: &'a str, separators: &[char]) -> Vec<&'a str> {
+    let mut segments = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 && separators.contains(&c) => {
+                segments.push(&value[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    segments.push(&value[start..]);
+    segments
+}
+
+fn parse_color_depth(value: &str) -> syntax::ColorDepth {
+    match value {
+        "256" => syntax::ColorDepth::Xterm256,
+        _ => syntax::ColorDepth::TrueColor,
+    }
+}
+
 fn base16_labels(len: usize) -> Vec<String> {
     const BASE16_KEYS: [&str; 24] = [
         "base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07", "base08", "base09", "base0A",
@@ -676,22 +2368,42 @@ fn base16_labels(len: usize) -> Vec<String> {
 }
 
 /// Generate and save a palette image with the specified parameters
-fn generate_palette_image(palette: &[Srgb8], path: &str, width: u32, height: u32, label_style: &str) {
-    let image = match label_style {
-        "hex" => palette_to_image(palette, PaletteLabelStyle::Hex, (width, height)),
-        "index" => palette_to_image(palette, PaletteLabelStyle::Index, (width, height)),
-        "base16" => {
-            let labels = base16_labels(palette.len());
-            palette_to_image(palette, PaletteLabelStyle::Custom(&labels), (width, height))
-        }
-        _ => palette_to_image(palette, PaletteLabelStyle::None, (width, height)),
-    };
+fn generate_palette_image(
+    palette: &[Srgb8], path: &str, size: (u32, u32), label_style: &str, font: Option<&Path>, preview: bool,
+) {
+    let labels = base16_labels(palette.len());
+    let font = checked_font(font);
+    let image = palette_to_image_with_font(palette, label_style_for(label_style, &labels), size, font.as_ref());
 
     if let Err(err) = image.save(path) {
         eprintln!("Failed to save palette image to {path}: {err}");
     } else {
         println!("Saved palette image to {path}");
+        if preview {
+            print!("{}", syntax::render_image_halfblocks(&image, PREVIEW_MAX_COLS));
+        }
+    }
+}
+
+/// Maps a `--label` value to a label style; `labels` backs the "base16" style.
+fn label_style_for<'a>(label: &str, labels: &'a [String]) -> PaletteLabelStyle<'a> {
+    match label {
+        "hex" => PaletteLabelStyle::Hex,
+        "index" => PaletteLabelStyle::Index,
+        "role" => PaletteLabelStyle::Role,
+        "base16" => PaletteLabelStyle::Custom(labels),
+        _ => PaletteLabelStyle::None,
+    }
+}
+
+/// Loads the `--font` file, warning and falling back to system fonts if it can't be read.
+fn checked_font(font: Option<&Path>) -> Option<Font<'static>> {
+    let path = font?;
+    let loaded = palette::load_font_file(path);
+    if loaded.is_none() {
+        eprintln!("Failed to load font {}; falling back to system fonts", path.display());
     }
+    loaded
 }
 
 fn print_scheme_header(meta: &SchemeMetadata) {
@@ -704,12 +2416,102 @@ fn print_scheme_header(meta: &SchemeMetadata) {
     }
 }
 
-fn handle_image(
-    colors: Option<String>, scheme_yaml: Option<String>, out: String, width: Option<u32>, height: Option<u32>,
-    label: String, viz: bool,
+fn handle_contrast(fg: String, bg: String) {
+    let (fg_color, bg_color) = match (parse_hex_color(&fg), parse_hex_color(&bg)) {
+        (Ok(fg_color), Ok(bg_color)) => (fg_color, bg_color),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("{err}");
+            return;
+        }
+    };
+
+    let report = wcag::grade_contrast(fg_color, bg_color);
+    println!("Foreground: {fg_color}");
+    println!("Background: {bg_color}");
+    println!("Contrast ratio: {:.2}:1", report.ratio);
+    println!();
+
+    let levels = [
+        ("AA normal", wcag::WCAG_AA_NORMAL, report.aa_normal),
+        ("AA large", wcag::WCAG_AA_LARGE, report.aa_large),
+        ("AAA normal", wcag::WCAG_AAA_NORMAL, report.aaa_normal),
+        ("AAA large", wcag::WCAG_AAA_LARGE, report.aaa_large),
+    ];
+    for (level, threshold, passed) in levels {
+        let status = if passed { "PASS" } else { "FAIL" };
+        println!("  [{status}] {level:<10} ({threshold:.1}:1)");
+    }
+
+    if !report.aa_normal {
+        println!();
+        match wcag::nearest_accessible_foreground(fg_color, bg_color, wcag::WCAG_AA_NORMAL) {
+            Some(suggested) => println!(
+                "Suggested AA foreground: {suggested} ({:.2}:1)",
+                wcag::contrast_ratio(suggested, bg_color)
+            ),
+            None => println!("No lightness adjustment of {fg_color} reaches AA against {bg_color}."),
+        }
+    }
+}
+
+fn handle_gradient(
+    from: Option<String>, to: Option<String>, stops: Option<String>, steps: usize, space: String, out: Option<String>,
 ) {
+    let stops = match (from, to, stops) {
+        (Some(from), Some(to), None) => parse_color_list(&format!("{from},{to}")),
+        (None, None, Some(stops)) => parse_color_list(&stops),
+        _ => Err("Provide either --from and --to, or --stops.".to_string()),
+    };
+    let stops = match stops {
+        Ok(stops) => stops,
+        Err(err) => {
+            eprintln!("{err}");
+            return;
+        }
+    };
+
+    let gradient = gradient_colors(&stops, steps, parse_gradient_space(&space));
+    if gradient.is_empty() {
+        eprintln!("A gradient needs at least two stops and two steps.");
+        return;
+    }
+
+    let labels: Vec<String> = gradient.iter().map(|c| c.to_hex().to_uppercase()).collect();
+    syntax::display_palette_in_terminal(&gradient, Some(&labels));
+
+    if let Some(out) = out {
+        let image = palette_to_image(&gradient, PaletteLabelStyle::None, (960, 160));
+        if let Err(err) = image.save(&out) {
+            eprintln!("Failed to write {out}: {err}");
+        } else {
+            println!("Wrote gradient image to {out}");
+        }
+    }
+}
+
+fn parse_gradient_space(value: &str) -> GradientSpace {
+    match value {
+        "rgb" => GradientSpace::Rgb,
+        "lab" => GradientSpace::Lab,
+        "oklch" => GradientSpace::Oklch,
+        _ => GradientSpace::Lch,
+    }
+}
+
+/// Interpolates `steps` colors through `stops` in the requested space.
+fn gradient_colors(stops: &[Srgb8], steps: usize, space: GradientSpace) -> Vec<Srgb8> {
+    let stops: Vec<Rgb> = stops.iter().copied().map(Rgb::from).collect();
+    gradient_stops(&stops, steps, space)
+        .into_iter()
+        .map(Srgb8::from)
+        .collect()
+}
+
+fn handle_image(args: ImageArgs) {
+    let ImageArgs { colors, scheme_yaml, out, width, height, label, font, orientation, gutter, bg, viz, preview } =
+        args;
     let palette = if let Some(list) = colors {
-        match parse_color_list(&list) {
+        match resolve_color_arg(&list) {
             Ok(colors) => colors,
             Err(err) => {
                 eprintln!("{err}");
@@ -730,20 +2532,42 @@ fn handle_image(
     }
 
     let size = (width.unwrap_or(960), height.unwrap_or(320));
-    let image = match label.as_str() {
-        "hex" => palette_to_image(&palette, PaletteLabelStyle::Hex, size),
-        "index" => palette_to_image(&palette, PaletteLabelStyle::Index, size),
-        "base16" => {
-            let labels = base16_labels(palette.len());
-            palette_to_image(&palette, PaletteLabelStyle::Custom(&labels), size)
-        }
-        _ => palette_to_image(&palette, PaletteLabelStyle::None, size),
+    let orientation = match orientation.as_str() {
+        "horizontal" => PaletteOrientation::Horizontal,
+        _ => PaletteOrientation::Vertical,
+    };
+    let background = match bg.as_deref().map(parse_hex_color).transpose() {
+        Ok(color) => color.unwrap_or(PaletteLayout::default().background),
+        Err(err) => {
+            eprintln!("{err}");
+            return;
+        }
+    };
+    let layout = PaletteLayout { orientation, gutter, background };
+    let role_labels = base16_labels(palette.len());
+    let style = label_style_for(&label, &role_labels);
+    let mut rendered = None;
+    let result = if out.to_lowercase().ends_with(".svg") {
+        if preview {
+            eprintln!("--preview is only available for raster images; skipping preview for {out}");
+        }
+        fs::write(&out, palette_to_svg_with_layout(&palette, style, size, layout)).map_err(|err| err.to_string())
+    } else {
+        let font = checked_font(font.as_deref().map(Path::new));
+        let image = palette_to_image_with_layout(&palette, style, size, font.as_ref(), layout);
+        let saved = image.save(&out).map_err(|err| err.to_string());
+        rendered = Some(image);
+        saved
     };
-    if let Err(err) = image.save(&out) {
+    if let Err(err) = result {
         eprintln!("Failed to write {out}: {err}");
     } else {
         println!("Wrote palette image to {out}");
 
+        if let Some(image) = rendered.filter(|_| preview) {
+            print!("{}", syntax::render_image_halfblocks(&image, PREVIEW_MAX_COLS));
+        }
+
         if viz {
             println!();
             let labels: Vec<String> = match label.as_str() {
@@ -766,11 +2590,19 @@ fn handle_vim_scheme(scheme_yaml: String, name: String, output_colors: String, u
     }
 }
 
+/// Generates `candidates` palettes (at least one) and keeps the one with the highest [`palette::harmony_score`].
+fn best_of_candidates(candidates: usize, mut generate: impl FnMut() -> Vec<Srgb8>) -> Vec<Srgb8> {
+    (0..candidates.max(1))
+        .map(|_| generate())
+        .max_by(|a, b| palette::harmony_score(a).total_cmp(&palette::harmony_score(b)))
+        .unwrap_or_default()
+}
+
 fn handle_preview(preview_type: PreviewType) {
     match preview_type {
-        PreviewType::Palette { colors, scheme_yaml } => {
+        PreviewType::Palette { colors, scheme_yaml, columns, list } => {
             let palette = if let Some(color_list) = colors {
-                match parse_color_list(&color_list) {
+                match resolve_color_arg(&color_list) {
                     Ok(colors) => colors,
                     Err(err) => {
                         eprintln!("{err}");
@@ -792,16 +2624,40 @@ fn handle_preview(preview_type: PreviewType) {
             };
 
             let labels: Vec<String> = (0..palette.len()).map(|i| format!("{i:02X}")).collect();
-            syntax::display_palette_in_terminal(&palette, Some(&labels));
+            if list {
+                syntax::display_palette_in_terminal(&palette, Some(&labels));
+            } else {
+                syntax::display_palette_grid(&palette, Some(&labels), columns);
+            }
         }
-        PreviewType::Code { language, theme_yaml, base, harmony, file } => {
+        PreviewType::Code {
+            language,
+            theme_yaml,
+            base,
+            harmony,
+            harmony_angle,
+            harmony_count,
+            file,
+            italic_comments,
+            bold_keywords,
+            scopes,
+            color_depth,
+        } => {
+            let depth = parse_color_depth(&color_depth);
+            let style = syntax::StyleOptions { italic_comments, bold_keywords, ..Default::default() };
             let (theme, theme_name) = if let Some(theme_path) = &theme_yaml {
                 if let Ok(schemes) = tinted_theming::load_base16_schemes(theme_path) {
                     let name = schemes[0].metadata.name.clone();
-                    (syntax::base16_to_theme(&schemes[0]), Some(name))
+                    (
+                        syntax::base16_to_theme_with_scopes(&schemes[0], style, &scopes),
+                        Some(name),
+                    )
                 } else if let Ok(schemes) = tinted_theming::load_base24_schemes(theme_path) {
                     let name = schemes[0].metadata.name.clone();
-                    (syntax::base24_to_theme(&schemes[0]), Some(name))
+                    (
+                        syntax::base24_to_theme_with_scopes(&schemes[0], style, &scopes),
+                        Some(name),
+                    )
                 } else {
                     eprintln!("Failed to load theme from {theme_path}");
                     return;
@@ -817,7 +2673,7 @@ fn handle_preview(preview_type: PreviewType) {
 
                 let harmony_kind = harmony
                     .as_ref()
-                    .and_then(|h| parse_harmony_kind(h))
+                    .and_then(|h| parse_harmony_kind(h, harmony_angle, harmony_count))
                     .unwrap_or(HarmonyKind::Complementary);
 
                 let palette = palette_from_base(base_srgb, harmony_kind, 16, None, None, None);
@@ -839,40 +2695,53 @@ fn handle_preview(preview_type: PreviewType) {
                 };
 
                 let scheme = tinted_theming::Base16Scheme::new(metadata, colors);
-                (syntax::base16_to_theme(&scheme), Some("Generated".to_string()))
+                (
+                    syntax::base16_to_theme_with_scopes(&scheme, style, &scopes),
+                    Some("Generated".to_string()),
+                )
             } else {
                 eprintln!("Provide either --theme-yaml or --base");
                 return;
             };
 
             let syntax_set = syntax::load_syntax_set();
-            let syntax = match syntax::find_syntax_by_name(&syntax_set, &language) {
-                Some(syn) => syn,
-                None => {
-                    eprintln!("Unknown language: {language}");
-                    return;
-                }
-            };
 
             if let Some(file_path) = &file {
-                match File::open(file_path) {
-                    Ok(file_handle) => {
-                        let reader = BufReader::new(file_handle);
-                        if let Err(err) = syntax::highlight_code_to_terminal(
-                            reader,
-                            syntax,
-                            &theme,
-                            Some(file_path.as_str()),
-                            theme_name.as_deref(),
-                        ) {
-                            eprintln!("Failed to highlight code: {err}");
-                        }
-                    }
+                let code = match fs::read_to_string(file_path) {
+                    Ok(code) => code,
                     Err(err) => {
                         eprintln!("Failed to open {file_path}: {err}");
+                        return;
                     }
+                };
+                let first_line = code.lines().next().unwrap_or_default();
+                let syntax = match &language {
+                    Some(lang) => syntax::find_syntax_by_name(syntax_set, lang),
+                    None => syntax::find_syntax_for_file(syntax_set, Path::new(file_path), first_line)
+                        .or_else(|| syntax::find_syntax_by_name(syntax_set, "rust")),
+                };
+                let Some(syntax) = syntax else {
+                    eprintln!("Unknown language: {}", language.as_deref().unwrap_or_default());
+                    return;
+                };
+
+                if let Err(err) = syntax::highlight_code_to_terminal(
+                    code.as_bytes(),
+                    syntax,
+                    &theme,
+                    Some(file_path.as_str()),
+                    theme_name.as_deref(),
+                    depth,
+                ) {
+                    eprintln!("Failed to highlight code: {err}");
                 }
             } else {
+                let language = language.as_deref().unwrap_or("rust");
+                let Some(syntax) = syntax::find_syntax_by_name(syntax_set, language) else {
+                    eprintln!("Unknown language: {language}");
+                    return;
+                };
+
                 let stdin = io::stdin();
                 let mut code = String::new();
                 if let Err(err) = stdin.lock().read_to_string(&mut code) {
@@ -880,7 +2749,9 @@ fn handle_preview(preview_type: PreviewType) {
                     return;
                 }
 
-                if let Err(err) = syntax::highlight_string_to_terminal(&code, syntax, &theme, theme_name.as_deref()) {
+                if let Err(err) =
+                    syntax::highlight_string_to_terminal(&code, syntax, &theme, theme_name.as_deref(), depth)
+                {
                     eprintln!("Failed to highlight code: {err}");
                 }
             }
@@ -933,9 +2804,11 @@ mod tests {
         }
 
         #[test]
-        fn rejects_short_hex() {
-            let result = parse_hex_color("#fff");
-            assert!(result.is_err());
+        fn expands_short_hex_and_rejects_other_lengths() {
+            assert_eq!(parse_hex_color("#fff"), Ok(Srgb8::new(255, 255, 255)));
+            assert_eq!(parse_hex_color("#f80"), Ok(Srgb8::new(0xff, 0x88, 0x00)));
+            assert!(parse_hex_color("#ff").is_err());
+            assert!(parse_hex_color("#fffff").is_err());
         }
 
         #[test]
@@ -945,64 +2818,212 @@ mod tests {
         }
     }
 
+    mod parse_color_assignment_tests {
+        use super::*;
+
+        #[test]
+        fn parses_key_and_color() {
+            let (key, color) = parse_color_assignment("base08 = #ff0000").unwrap();
+            assert_eq!(key, "base08");
+            assert_eq!(color, Srgb8::new(255, 0, 0));
+        }
+
+        #[test]
+        fn rejects_missing_separator_or_bad_color() {
+            assert!(parse_color_assignment("base08").is_err());
+            assert!(parse_color_assignment("base08=nothex").is_err());
+        }
+    }
+
+    mod rotate_accents_tests {
+        use super::*;
+
+        #[test]
+        fn rotates_only_accent_slots() {
+            let scheme = &tinted_theming::load_base16_schemes("../examples/base16/oxocarbon-dark.yml").unwrap()[0];
+            let mut colors = scheme.colors().to_vec();
+            rotate_accents(&mut colors, 40.0, false);
+
+            assert_eq!(&colors[..8], &scheme.colors()[..8]);
+            assert_ne!(&colors[8..], &scheme.colors()[8..]);
+
+            let mut preserved = scheme.colors().to_vec();
+            rotate_accents(&mut preserved, 40.0, true);
+            assert_eq!(&preserved[..8], &scheme.colors()[..8]);
+            for (rotated, original) in preserved[8..].iter().zip(&scheme.colors()[8..16]) {
+                let delta = wcag::relative_luminance(*rotated) - wcag::relative_luminance(*original);
+                assert!(delta.abs() < 0.005);
+            }
+        }
+    }
+
+    mod gradient_colors_tests {
+        use super::*;
+
+        #[test]
+        fn produces_requested_steps_with_exact_endpoints() {
+            let stops = parse_color_list("#ff0000,#00ff00,#0000ff").unwrap();
+            for space in [GradientSpace::Rgb, GradientSpace::Lab, GradientSpace::Lch] {
+                let gradient = gradient_colors(&stops, 12, space);
+                assert_eq!(gradient.len(), 12);
+                assert_eq!(gradient[0], stops[0]);
+                assert_eq!(gradient[11], stops[2]);
+            }
+        }
+    }
+
+    mod parse_unit_range_tests {
+        use super::*;
+
+        #[test]
+        fn parses_ordered_range() {
+            assert_eq!(parse_unit_range("0.3..0.8").unwrap(), 0.3..0.8);
+            assert_eq!(parse_unit_range(" 0 .. 1 ").unwrap(), 0.0..1.0);
+        }
+
+        #[test]
+        fn rejects_reversed_or_malformed_ranges() {
+            assert!(parse_unit_range("0.8..0.3").is_err());
+            assert!(parse_unit_range("0.5..0.5").is_err());
+            assert!(parse_unit_range("0.2-0.4").is_err());
+            assert!(parse_unit_range("0.2..1.4").is_err());
+        }
+    }
+
     mod parse_harmony_kind_tests {
         use super::*;
 
         #[test]
         fn parses_complementary() {
-            let result = parse_harmony_kind("complementary");
+            let result = parse_harmony_kind("complementary", None, None);
             assert!(matches!(result, Some(HarmonyKind::Complementary)));
         }
 
         #[test]
         fn parses_split_complementary() {
-            let result = parse_harmony_kind("split-complementary");
+            let result = parse_harmony_kind("split-complementary", None, None);
             assert!(matches!(result, Some(HarmonyKind::SplitComplementary)));
         }
 
         #[test]
         fn parses_analogous() {
-            let result = parse_harmony_kind("analogous");
-            assert!(matches!(result, Some(HarmonyKind::Analogous(_))));
+            let result = parse_harmony_kind("analogous", None, None);
+            assert_eq!(result, Some(HarmonyKind::Analogous { angle: 30.0, count: 3 }));
         }
 
         #[test]
         fn parses_triadic() {
-            let result = parse_harmony_kind("triadic");
+            let result = parse_harmony_kind("triadic", None, None);
             assert!(matches!(result, Some(HarmonyKind::Triadic)));
         }
 
         #[test]
         fn parses_tetradic() {
-            let result = parse_harmony_kind("tetradic");
+            let result = parse_harmony_kind("tetradic", None, None);
             assert!(matches!(result, Some(HarmonyKind::Tetradic)));
         }
 
         #[test]
         fn parses_square() {
-            let result = parse_harmony_kind("square");
+            let result = parse_harmony_kind("square", None, None);
             assert!(matches!(result, Some(HarmonyKind::Square)));
         }
 
+        #[test]
+        fn parses_rectangle_with_default_angle() {
+            let result = parse_harmony_kind("rectangle", None, None);
+            assert_eq!(result, Some(HarmonyKind::Rectangle(60.0)));
+        }
+
+        #[test]
+        fn applies_custom_angle() {
+            assert_eq!(
+                parse_harmony_kind("rectangle", Some(45.0), None),
+                Some(HarmonyKind::Rectangle(45.0))
+            );
+            assert_eq!(
+                parse_harmony_kind("analogous", Some(15.0), None),
+                Some(HarmonyKind::Analogous { angle: 15.0, count: 3 })
+            );
+            assert_eq!(
+                parse_harmony_kind("analogous", Some(20.0), Some(5)),
+                Some(HarmonyKind::Analogous { angle: 20.0, count: 5 })
+            );
+            assert_eq!(
+                parse_harmony_kind("square", Some(45.0), None),
+                Some(HarmonyKind::Square)
+            );
+        }
+
         #[test]
         fn returns_none_for_invalid() {
-            let result = parse_harmony_kind("invalid");
+            let result = parse_harmony_kind("invalid", None, None);
             assert!(result.is_none());
         }
 
         #[test]
         fn returns_none_for_empty() {
-            let result = parse_harmony_kind("");
+            let result = parse_harmony_kind("", None, None);
             assert!(result.is_none());
         }
 
         #[test]
         fn case_sensitive() {
-            let result = parse_harmony_kind("COMPLEMENTARY");
+            let result = parse_harmony_kind("COMPLEMENTARY", None, None);
             assert!(result.is_none());
         }
     }
 
+    mod parse_alpha_policy_tests {
+        use super::*;
+
+        #[test]
+        fn composite_defaults_to_white() {
+            assert_eq!(
+                parse_alpha_policy("composite", None),
+                Ok(extract::AlphaPolicy::CompositeOver(Srgb8::new(255, 255, 255)))
+            );
+        }
+
+        #[test]
+        fn rejects_background_without_composite() {
+            assert!(parse_alpha_policy("skip", Some("#000000")).is_err());
+            assert_eq!(parse_alpha_policy("ignore", None), Ok(extract::AlphaPolicy::Ignore));
+        }
+    }
+
+    mod parse_harmony_count_tests {
+        use super::*;
+
+        #[test]
+        fn rejects_zero() {
+            assert!(parse_harmony_count("0").is_err());
+            assert!(parse_harmony_count("three").is_err());
+            assert_eq!(parse_harmony_count("5"), Ok(5));
+        }
+    }
+
+    mod italic_comments_flag_tests {
+        use super::*;
+
+        fn italic_comments(args: &[&str]) -> bool {
+            let cli = Cli::try_parse_from(["colorizer", "preview", "code"].iter().chain(args)).unwrap();
+            match cli.command {
+                Commands::Preview { preview_type: PreviewType::Code { italic_comments, .. } } => italic_comments,
+                _ => unreachable!("parsed a preview code command"),
+            }
+        }
+
+        #[test]
+        fn accepts_bare_flag_and_explicit_value() {
+            assert!(italic_comments(&[]));
+            assert!(italic_comments(&["--italic-comments"]));
+            assert!(italic_comments(&["--italic-comments", "true"]));
+            assert!(!italic_comments(&["--italic-comments", "false"]));
+            assert!(!italic_comments(&["--italic-comments=false", "--bold-keywords"]));
+        }
+    }
+
     mod golden_theme_ranges_tests {
         use super::*;
 
@@ -1095,6 +3116,39 @@ mod tests {
             let colors = result.unwrap();
             assert_eq!(colors.len(), 3);
         }
+
+        #[test]
+        fn keeps_css_functions_whole() {
+            let colors = parse_color_list("rgb(255, 0, 0), hsl(120, 100%, 50%),navy").unwrap();
+            assert_eq!(
+                colors,
+                vec![Srgb8::new(255, 0, 0), Srgb8::new(0, 255, 0), Srgb8::new(0, 0, 128)]
+            );
+
+            let colors = parse_color_lines("rgb(0 0 255) # blue\n#ffffff, rgba(0, 0, 0, 0.5)\n").unwrap();
+            assert_eq!(
+                colors,
+                vec![Srgb8::new(0, 0, 255), Srgb8::new(255, 255, 255), Srgb8::new(0, 0, 0)]
+            );
+        }
+
+        #[test]
+        fn parses_multiline_input_with_comments() {
+            let input = "# exported palette\n#ff0000  # red\n\n00ff00, #0000ff\n   \n## accents\n#ffffff\n";
+            let colors = parse_color_lines(input).unwrap();
+            assert_eq!(
+                colors,
+                vec![
+                    Srgb8::new(255, 0, 0),
+                    Srgb8::new(0, 255, 0),
+                    Srgb8::new(0, 0, 255),
+                    Srgb8::new(255, 255, 255)
+                ]
+            );
+
+            assert!(parse_color_lines("# only comments\n\n").is_err());
+            assert!(parse_color_lines("#ff0000\nnot-a-color").is_err());
+        }
     }
 
     mod base16_labels_tests {
@@ -1133,7 +3187,7 @@ mod tests {
         #[test]
         fn labels_have_correct_format() {
             let labels = base16_labels(16);
-            for (_, label) in labels.iter().enumerate() {
+            for label in labels.iter() {
                 assert!(label.starts_with("base"));
                 assert_eq!(label.len(), 6);
             }
@@ -1153,15 +3207,65 @@ mod tests {
         fn output_palette_does_not_panic() {
             let palette = vec![Srgb8::new(255, 0, 0), Srgb8::new(0, 255, 0), Srgb8::new(0, 0, 255)];
 
-            output_palette(&palette, "hex");
-            output_palette(&palette, "json");
-            output_palette(&palette, "yaml");
+            output_palette(&palette, "hex", false);
+            output_palette(&palette, "json", false);
+            output_palette(&palette, "json", true);
+            output_palette(&palette, "yaml", false);
+            output_palette(&palette, "yaml", true);
+            output_palette(&palette, "css", false);
+            output_palette(&palette, "scss", false);
+        }
+
+        #[test]
+        fn css_output_has_one_property_per_color() {
+            let schemes = tinted_theming::load_base16_schemes("../examples/base16/oxocarbon-dark.yml").unwrap();
+            let colors = schemes[0].colors();
+            let css = palette_variables(colors, &base16_labels(colors.len()), "css");
+
+            assert!(css.starts_with(":root {"));
+            assert_eq!(
+                css.lines()
+                    .filter(|line| line.trim_start().starts_with("--base"))
+                    .count(),
+                16
+            );
+            assert!(css.contains("  --base00: #161616;"));
+
+            let scss = palette_variables(colors, &base16_labels(colors.len()), "scss");
+            assert_eq!(scss.lines().count(), 16);
+            assert!(scss.starts_with("$base00: #161616;"));
         }
 
         #[test]
         fn output_palette_handles_empty() {
             let palette = vec![];
-            output_palette(&palette, "hex");
+            output_palette(&palette, "hex", false);
+        }
+    }
+
+    mod scheme_palettes_tests {
+        use super::*;
+
+        #[test]
+        fn directory_export_is_single_json_array() {
+            let schemes = tinted_theming::load_base16_schemes("../examples/base16").unwrap();
+            assert_eq!(schemes.len(), 2);
+
+            let entries: Vec<_> = schemes.iter().map(|s| (&s.metadata, s.colors())).collect();
+            let serialized = scheme_palettes_to_string(&entries, "json").unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+            let array = parsed.as_array().expect("expected a JSON array");
+            assert_eq!(array.len(), 2);
+            assert_eq!(array[0]["colors"].as_array().unwrap().len(), 16);
+        }
+
+        #[test]
+        fn directory_export_is_single_yaml_sequence() {
+            let schemes = tinted_theming::load_base16_schemes("../examples/base16").unwrap();
+            let entries: Vec<_> = schemes.iter().map(|s| (&s.metadata, s.colors())).collect();
+            let serialized = scheme_palettes_to_string(&entries, "yaml").unwrap();
+            let parsed: Vec<serde_yml::Value> = serde_yml::from_str(&serialized).unwrap();
+            assert_eq!(parsed.len(), 2);
         }
     }
 
@@ -1171,7 +3275,7 @@ mod tests {
         #[test]
         fn full_workflow_from_base_to_palette() {
             let base_color = parse_hex_color("#ff5500").unwrap();
-            let harmony_kind = parse_harmony_kind("triadic").unwrap();
+            let harmony_kind = parse_harmony_kind("triadic", None, None).unwrap();
             let palette = palette_from_base(base_color, harmony_kind, 5, None, None, None);
 
             assert!(!palette.is_empty());
@@ -1,22 +1,39 @@
 //! Palette generation helpers and visualization utilities.
 
 use crate::GoldenPalette;
-use crate::colors::{Hsl, Rgb, Srgb8};
-use crate::diffs::ensure_min_distance;
-use crate::harmonies::{HarmonyKind, harmonies};
+use crate::base16_builder::closest_role;
+use crate::colors::{Hsl, Hsv, Lab, Lch, Rgb, Srgb8, Srgb16, map_to_gamut, warmth};
+use crate::diffs::{DeltaEMetric, distance_matrix, ensure_min_distance, spread_min_distance};
+use crate::harmonies::{HarmonyKind, harmonies, harmonies_hsv};
 use crate::shades::{darken_hsl, lighten_hsl};
-use crate::wcag::contrast_ratio;
+use crate::wcag::{WCAG_AA_NORMAL, best_foreground, contrast_ratio, contrast_ratio_rgb};
 
-use image::{Rgb as ImgRgb, RgbImage};
+use image::{ImageBuffer, Rgb as ImgRgb, RgbImage};
 use rusttype::{Font, Scale, point};
+use serde::{Deserialize, Serialize};
 use std::cmp::max;
+use std::collections::HashSet;
+use std::fs;
 use std::ops::Range;
+use std::path::Path;
 
 const VARIATION_STEP: f32 = 0.08;
+const MAX_VARIATION_ROUNDS: usize = 32;
+/// Relaxation rounds [`golden_ratio_palette_spread`] spends nudging crowded colors apart.
+const SPREAD_ITERATIONS: usize = 200;
 const FONT_WIDTH: u32 = 5;
 const FONT_HEIGHT: u32 = 7;
 const TRUETYPE_FONT_SIZE: f32 = 24.0;
 const MIN_HEIGHT_WITH_TRUETYPE: u32 = 40;
+const FAIL_MARKER_SIZE: u32 = 6;
+/// Lch chroma below which [`partition_temperature`] treats a color as neutral rather than warm or cool.
+pub const NEUTRAL_CHROMA: f32 = 10.0;
+/// Mean pairwise ΔE2000 at which [`harmony_score`] rates spacing as ideal.
+const HARMONY_SPACING_REFERENCE: f32 = 40.0;
+/// Standard deviation of Lab lightness at which [`harmony_score`] rates range usage as ideal.
+const HARMONY_LIGHTNESS_REFERENCE: f32 = 20.0;
+/// Weights of spacing, lightness range, and hue evenness in [`harmony_score`]; they sum to 1.
+const HARMONY_WEIGHTS: (f32, f32, f32) = (0.5, 0.25, 0.25);
 
 /// Label styles supported during palette-to-image rendering.
 #[derive(Debug, Clone, Copy)]
@@ -25,12 +42,109 @@ pub enum PaletteLabelStyle<'a> {
     Index,
     None,
     Custom(&'a [String]),
+    /// Nearest Base16 role for each swatch (e.g. `base08` for reds), via [`closest_role`].
+    Role,
+}
+
+/// Direction palette swatches are laid out in images.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PaletteOrientation {
+    /// Side-by-side columns, each spanning the full height.
+    #[default]
+    Vertical,
+    /// Stacked rows, each spanning the full width; suits wide banners.
+    Horizontal,
+}
+
+/// How swatches are arranged in palette images: their direction and the gutter separating them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaletteLayout {
+    pub orientation: PaletteOrientation,
+    /// Pixels of `background` between neighboring swatches (none at the outer edges).
+    pub gutter: u32,
+    /// Color showing through the gutters.
+    pub background: Srgb8,
+}
+
+impl Default for PaletteLayout {
+    fn default() -> Self {
+        Self { orientation: PaletteOrientation::Vertical, gutter: 0, background: Srgb8::new(0, 0, 0) }
+    }
+}
+
+impl PaletteLayout {
+    /// Horizontal and vertical pixel spans of swatch `index` out of `count` in an image of `size`.
+    fn swatch_bounds(&self, index: usize, count: usize, size: (u32, u32)) -> ((u32, u32), (u32, u32)) {
+        match self.orientation {
+            PaletteOrientation::Vertical => (band_bounds(index, count, size.0, self.gutter), (0, size.1)),
+            PaletteOrientation::Horizontal => ((0, size.0), band_bounds(index, count, size.1, self.gutter)),
+        }
+    }
+
+    /// Pixels taken by the gutters between `count` swatches.
+    fn gutter_total(&self, count: usize) -> u32 {
+        count.saturating_sub(1) as u32 * self.gutter
+    }
+
+    /// Image size for `count` unlabeled swatches: `size`, grown along the layout direction so every swatch and gutter
+    /// gets at least one pixel.
+    fn unlabeled_size(&self, count: usize, size: (u32, u32)) -> (u32, u32) {
+        let span = count as u32 + self.gutter_total(count);
+        match self.orientation {
+            PaletteOrientation::Vertical => (max(size.0, span).max(1), size.1.max(1)),
+            PaletteOrientation::Horizontal => (size.0.max(1), max(size.1, span).max(1)),
+        }
+    }
+}
+
+/// A single palette color with its position and common channel representations, used for JSON/YAML exports.
+///
+/// `hsl` is computed on gamma-encoded sRGB (hue in degrees, saturation and lightness in [0, 1]) so it matches
+/// the values shown by CSS and design tools.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaletteEntry {
+    pub index: usize,
+    pub hex: String,
+    pub rgb: [u8; 3],
+    pub hsl: [f32; 3],
+}
+
+impl PaletteEntry {
+    /// Describes `color` at position `index` of its palette.
+    pub fn new(index: usize, color: Srgb8) -> Self {
+        let hsl = Hsl::from(color);
+        Self { index, hex: color.to_hex(), rgb: color.into(), hsl: [hsl.h, hsl.s, hsl.l] }
+    }
+}
+
+/// Structured palette export: every color as a [`PaletteEntry`] plus the total count.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaletteExport {
+    pub colors: Vec<PaletteEntry>,
+    pub count: usize,
+}
+
+impl PaletteExport {
+    /// Builds an export for `colors`, preserving their order.
+    pub fn new(colors: &[Srgb8]) -> Self {
+        let colors: Vec<PaletteEntry> = colors
+            .iter()
+            .enumerate()
+            .map(|(index, &color)| PaletteEntry::new(index, color))
+            .collect();
+        Self { count: colors.len(), colors }
+    }
 }
 
 /// Generates a palette derived from `base` using the requested harmony.
 ///
-/// The harmony colors are expanded by iteratively lightening/darkening rounds until `count` colors are produced.
+/// The harmony colors are expanded by iteratively lightening/darkening rounds until `count` unique colors are produced
+/// (or the variations are exhausted).
 /// Optional perceptual distance and contrast filters are applied if requested.
+///
+/// The result can hold fewer than `count` colors: expansion stops after a fixed number of variation rounds (variations
+/// of near-black or near-white bases collapse into duplicates), and the distance and contrast filters drop colors
+/// without replacing them. Callers that need an exact count should check the length.
 pub fn palette_from_base(
     base: Srgb8, harmony: HarmonyKind, count: usize, min_delta_e: Option<f32>, background: Option<Srgb8>,
     min_contrast: Option<f32>,
@@ -38,45 +152,285 @@ pub fn palette_from_base(
     let base_hsl: Hsl = Rgb::from(base).into();
     let harmony_colors = harmonies(base_hsl, harmony);
 
+    let generated = expand_variations(count, |round| {
+        harmony_colors
+            .iter()
+            .map(|&color| Srgb8::from(Rgb::from(apply_variation(color, round))))
+            .collect()
+    });
+
+    let filtered = enforce_min_delta_e(generated, min_delta_e);
+    filter_by_contrast(filtered, background, min_contrast)
+}
+
+/// Generates a palette derived from `base` using the requested harmony computed in HSV.
+///
+/// Mirrors [`palette_from_base`], including possibly returning fewer than `count` colors, but harmony colors keep the
+/// base's saturation and value and the variation rounds step value (brightness) instead of HSL lightness, which keeps
+/// vivid bases vivid.
+pub fn palette_from_base_hsv(
+    base: Srgb8, harmony: HarmonyKind, count: usize, min_delta_e: Option<f32>, background: Option<Srgb8>,
+    min_contrast: Option<f32>,
+) -> Vec<Srgb8> {
+    let base_hsv: Hsv = Rgb::from(base).into();
+    let harmony_colors = harmonies_hsv(base_hsv, harmony);
+
+    let generated = expand_variations(count, |round| {
+        harmony_colors
+            .iter()
+            .map(|&color| Srgb8::from(Rgb::from(apply_variation_hsv(color, round))))
+            .collect()
+    });
+
+    let filtered = enforce_min_delta_e(generated, min_delta_e);
+    filter_by_contrast(filtered, background, min_contrast)
+}
+
+/// Collects colors from successive variation rounds until `count` unique colors exist or the rounds run out.
+fn expand_variations(count: usize, round_colors: impl Fn(usize) -> Vec<Srgb8>) -> Vec<Srgb8> {
     let mut generated: Vec<Srgb8> = Vec::with_capacity(count);
     let mut round = 0;
 
-    while generated.len() < count {
-        for &color in &harmony_colors {
+    while generated.len() < count && round < MAX_VARIATION_ROUNDS {
+        for color in round_colors(round) {
             if generated.len() == count {
                 break;
             }
-            let adjusted = apply_variation(color, round);
-            let rgb: Rgb = adjusted.into();
-            generated.push(Srgb8::from(rgb));
+            generated.push(color);
         }
+        dedup_exact(&mut generated);
         round += 1;
     }
 
-    let filtered = enforce_min_delta_e(generated, min_delta_e);
-    filter_by_contrast(filtered, background, min_contrast)
+    generated
 }
 
 /// Creates a palette by stepping hue via the golden ratio conjugate and sampling saturation/lightness from provided ranges.
 pub fn golden_ratio_palette(
     count: usize, saturation_range: Range<f32>, lightness_range: Range<f32>, min_delta_e: Option<f32>,
 ) -> Vec<Srgb8> {
-    let mut generator = GoldenPalette::hsl_ranged(0.0, saturation_range, lightness_range);
-    let mut colors = Vec::with_capacity(count);
-    for _ in 0..count {
-        let hsl = generator.next_hsl();
-        let rgb: Rgb = hsl.into();
-        colors.push(Srgb8::from(rgb));
-    }
+    let colors = GoldenPalette::hsl_ranged(0.0, saturation_range, lightness_range)
+        .iter_hsl()
+        .take(count)
+        .map(|hsl| Srgb8::from(Rgb::from(hsl)))
+        .collect();
     enforce_min_delta_e(colors, min_delta_e)
 }
 
+/// Like [`golden_ratio_palette`], but nudges crowded colors apart instead of dropping them, so exactly `count` colors
+/// come back.
+///
+/// Colors that still miss `min_delta_e` after the relaxation rounds (e.g. a large count with a tight threshold) are
+/// kept as close as they got.
+pub fn golden_ratio_palette_spread(
+    count: usize, saturation_range: Range<f32>, lightness_range: Range<f32>, min_delta_e: f32,
+) -> Vec<Srgb8> {
+    let mut labs: Vec<Lab> = GoldenPalette::hsl_ranged(0.0, saturation_range, lightness_range)
+        .iter_hsl()
+        .take(count)
+        .map(|hsl| Lab::from(Srgb8::from(Rgb::from(hsl))))
+        .collect();
+    spread_min_distance(&mut labs, min_delta_e, SPREAD_ITERATIONS);
+    labs.into_iter().map(Srgb8::from).collect()
+}
+
+/// Applies brightness, contrast, and gamma post-processing to every color of a palette in place.
+///
+/// Each channel is decoded to linear light and mapped through `out = in^gamma * contrast + brightness`, then clamped
+/// to [0, 1] and re-encoded. Identity parameters (`brightness = 0`, `contrast = 1`, `gamma = 1`) leave colors unchanged;
+/// `gamma < 1` lifts midtones and `gamma > 1` deepens them.
+pub fn adjust(colors: &mut [Srgb8], brightness: f32, contrast: f32, gamma: f32) {
+    let gamma = gamma.max(f32::EPSILON);
+    let map = |c: f32| c.powf(gamma) * contrast + brightness;
+    for color in colors {
+        let rgb = Rgb::from(*color);
+        *color = Srgb8::from(Rgb::new(map(rgb.r), map(rgb.g), map(rgb.b)));
+    }
+}
+
+/// Scales every color's Lch chroma by `1 + amount`, mapping the results back into sRGB without clipping.
+///
+/// Raising HSL saturation pushes vivid colors past the gamut, where channel clipping shifts hue and flattens detail.
+/// Here each boosted color keeps its lightness and hue and gives up only as much chroma as it must (see
+/// [`map_to_gamut`]). Negative amounts mute the palette; chroma never drops below zero.
+pub fn boost_chroma(colors: &mut [Srgb8], amount: f32) {
+    for color in colors {
+        let mut lch = Lch::from(Lab::from(*color));
+        lch.c = (lch.c * (1.0 + amount)).max(0.0);
+        *color = map_to_gamut(lch);
+    }
+}
+
+/// Shifts the perceptual lightness of all colors in a palette by a fixed offset in Lch space.
+///
+/// Lch counterpart of [`shift_lightness`](crate::harmonies::shift_lightness): `delta` is in Lab L units (0-100) and results are clamped to [0, 100].
+/// Hue and chroma are preserved.
+pub fn shift_lightness_lch(colors: &mut [Lch], delta: f32) {
+    for color in colors {
+        color.l = (color.l + delta).clamp(0.0, 100.0);
+    }
+}
+
+/// Sets all colors in a palette to the same perceptual lightness in Lch space.
+///
+/// Unlike [`set_lightness`](crate::harmonies::set_lightness), equal Lch lightness reads as equal brightness across hues, which is what
+/// "make every accent equally bright" usually means. `lightness` is in Lab L units and is clamped to [0, 100];
+/// chroma is preserved, so very saturated colors may still clip when converted back to sRGB.
+pub fn scale_lightness_lch(colors: &mut [Lch], lightness: f32) {
+    let l = lightness.clamp(0.0, 100.0);
+    for color in colors {
+        color.l = l;
+    }
+}
+
+/// Removes exact duplicate colors while preserving first-seen order.
+///
+/// Unlike [`ensure_min_distance`], this only drops identical values and never touches perceptually close colors.
+pub fn dedup_exact(colors: &mut Vec<Srgb8>) {
+    let mut seen = HashSet::with_capacity(colors.len());
+    colors.retain(|&color| seen.insert(color));
+}
+
+/// Returns the perceptual average of a palette, or None when it is empty.
+///
+/// Colors are averaged in Lab rather than sRGB: sRGB values are gamma-encoded, so their arithmetic mean skews light and
+/// muddies mixed hues, while Lab distances track perceived difference and the mean lands where the eye expects.
+pub fn average_color(colors: &[Srgb8]) -> Option<Srgb8> {
+    if colors.is_empty() {
+        return None;
+    }
+
+    let n = colors.len() as f32;
+    let (l, a, b) = colors
+        .iter()
+        .map(|&c| Lab::from(c))
+        .fold((0.0, 0.0, 0.0), |(l, a, b), lab| (l + lab.l, a + lab.a, b + lab.b));
+    Some(Srgb8::from(Lab::new(l / n, a / n, b / n)))
+}
+
+/// Returns the most colorful entry (highest Lch chroma), or None when the palette is empty.
+pub fn dominant_by_chroma(colors: &[Srgb8]) -> Option<Srgb8> {
+    colors
+        .iter()
+        .copied()
+        .max_by(|&a, &b| Lch::from(a).c.total_cmp(&Lch::from(b).c))
+}
+
+/// A palette split by color temperature; see [`partition_temperature`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemperaturePartition {
+    pub warm: Vec<Srgb8>,
+    pub cool: Vec<Srgb8>,
+    pub neutral: Vec<Srgb8>,
+}
+
+/// Splits a palette into warm, cool, and neutral colors, keeping the input order within each group.
+///
+/// Colors with Lch chroma below [`NEUTRAL_CHROMA`] are neutral. The rest are warm when their (CSS) HSL hue falls in
+/// [0°, 60°] or [300°, 360°) — reds, oranges, yellows, and magenta-reds — and cool otherwise.
+pub fn partition_temperature(colors: &[Srgb8]) -> TemperaturePartition {
+    let mut partition = TemperaturePartition::default();
+    for &color in colors {
+        let hue = Hsl::from(color).h;
+        let group = if Lch::from(color).c < NEUTRAL_CHROMA {
+            &mut partition.neutral
+        } else if hue <= 60.0 || hue >= 300.0 {
+            &mut partition.warm
+        } else {
+            &mut partition.cool
+        };
+        group.push(color);
+    }
+    partition
+}
+
+/// Scores how well-spaced and balanced a palette is, from 0 (indistinct) to 1.
+///
+/// The score is a weighted sum of three terms, each normalized to [0, 1]:
+///
+/// - spacing (weight 0.5): mean pairwise ΔE2000, saturating at 40;
+/// - lightness range (weight 0.25): standard deviation of Lab lightness, saturating at 20;
+/// - hue evenness (weight 0.25): one minus the mean resultant length of the hue angles of chromatic colors (Lch
+///   chroma of at least [`NEUTRAL_CHROMA`]), so hues spread evenly around the wheel score 1 and a single hue scores 0.
+///
+/// Palettes with fewer than two colors score 0.
+pub fn harmony_score(colors: &[Srgb8]) -> f32 {
+    if colors.len() < 2 {
+        return 0.0;
+    }
+
+    let matrix = distance_matrix(colors, DeltaEMetric::Ciede2000);
+    let total_delta: f32 = matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| row[i + 1..].iter().sum::<f32>())
+        .sum();
+    let pairs = colors.len() * (colors.len() - 1) / 2;
+    let labs: Vec<Lab> = colors.iter().copied().map(Lab::from).collect();
+    let spacing = (total_delta / pairs as f32 / HARMONY_SPACING_REFERENCE).min(1.0);
+
+    let mean_l = labs.iter().map(|lab| lab.l).sum::<f32>() / labs.len() as f32;
+    let variance = labs.iter().map(|lab| (lab.l - mean_l).powi(2)).sum::<f32>() / labs.len() as f32;
+    let range = (variance.sqrt() / HARMONY_LIGHTNESS_REFERENCE).min(1.0);
+
+    let hues: Vec<f32> = colors
+        .iter()
+        .map(|&color| Lch::from(color))
+        .filter(|lch| lch.c >= NEUTRAL_CHROMA)
+        .map(|lch| lch.h.to_radians())
+        .collect();
+    let evenness = if hues.is_empty() {
+        0.0
+    } else {
+        let (sin, cos) = hues
+            .iter()
+            .fold((0.0, 0.0), |(sin, cos), h| (sin + h.sin(), cos + h.cos()));
+        1.0 - (sin * sin + cos * cos).sqrt() / hues.len() as f32
+    };
+
+    let (w_spacing, w_range, w_evenness) = HARMONY_WEIGHTS;
+    (w_spacing * spacing + w_range * range + w_evenness * evenness).clamp(0.0, 1.0)
+}
+
+/// Orderings available to [`sort_palette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// Coolest to warmest, by [`warmth`].
+    Warmth,
+    /// Darkest to lightest, by Lab lightness.
+    Lightness,
+    /// Around the Lch hue wheel, starting at 0° (pinkish red).
+    Hue,
+    /// Least to most colorful, by Lch chroma.
+    Chroma,
+}
+
+/// Sorts a palette in ascending order of `key`; ties keep their input order.
+pub fn sort_palette(colors: &mut [Srgb8], key: SortKey) {
+    let value = |color: Srgb8| match key {
+        SortKey::Warmth => warmth(color),
+        SortKey::Lightness => Lab::from(color).l,
+        SortKey::Hue => Lch::from(color).h,
+        SortKey::Chroma => Lch::from(color).c,
+    };
+    colors.sort_by(|&a, &b| value(a).total_cmp(&value(b)));
+}
+
 fn apply_variation(color: Hsl, round: usize) -> Hsl {
     if round == 0 {
         return color;
     }
     let amount = VARIATION_STEP * round as f32;
-    if round % 2 == 0 { lighten_hsl(color, amount) } else { darken_hsl(color, amount) }
+    if round.is_multiple_of(2) { lighten_hsl(color, amount) } else { darken_hsl(color, amount) }
+}
+
+fn apply_variation_hsv(color: Hsv, round: usize) -> Hsv {
+    if round == 0 {
+        return color;
+    }
+    let amount = VARIATION_STEP * round as f32;
+    let value = if round.is_multiple_of(2) { color.v + amount } else { color.v - amount };
+    Hsv::new(color.h, color.s, value)
 }
 
 fn enforce_min_delta_e(colors: Vec<Srgb8>, min_delta_e: Option<f32>) -> Vec<Srgb8> {
@@ -88,33 +442,40 @@ fn enforce_min_delta_e(colors: Vec<Srgb8>, min_delta_e: Option<f32>) -> Vec<Srgb
         return colors;
     }
 
-    let mut labs: Vec<_> = colors.iter().copied().map(crate::colors::Lab::from).collect();
+    let mut labs: Vec<_> = colors.iter().copied().map(Lab::from).collect();
     ensure_min_distance(&mut labs, threshold);
     labs.into_iter().map(Srgb8::from).collect()
 }
 
 fn filter_by_contrast(colors: Vec<Srgb8>, background: Option<Srgb8>, min_contrast: Option<f32>) -> Vec<Srgb8> {
     match (background, min_contrast) {
-        (Some(bg), Some(required)) if required > 0.0 => colors
-            .into_iter()
-            .filter(|&color| contrast_ratio(bg, color) >= required)
-            .collect(),
+        (Some(bg), Some(required)) if required > 0.0 => {
+            let bg = Rgb::from(bg);
+            colors
+                .into_iter()
+                .filter(|&color| contrast_ratio_rgb(bg, Rgb::from(color)) >= required)
+                .collect()
+        }
         _ => colors,
     }
 }
 
-/// Attempts to load a TrueType font from the system.
+/// Loads a TrueType font directly from a file, bypassing the system font lookup.
 ///
-/// TODO: Allow users to pass in a custom font family via CLI flag (e.g., --font "FontName").
+/// Returns `None` when the file can't be read or isn't a font `rusttype` understands.
+pub fn load_font_file(path: &Path) -> Option<Font<'static>> {
+    Font::try_from_vec(fs::read(path).ok()?)
+}
+
+/// Attempts to load a TrueType font from the system.
 fn load_system_font() -> Option<Font<'static>> {
     if let Some((data, _)) = font_loader::system_fonts::get(
         &font_loader::system_fonts::FontPropertyBuilder::new()
             .family("0xProto Nerd Font")
             .build(),
-    ) {
-        if let Some(font) = Font::try_from_vec(data) {
-            return Some(font);
-        }
+    ) && let Some(font) = Font::try_from_vec(data)
+    {
+        return Some(font);
     }
 
     for family in &[
@@ -128,10 +489,9 @@ fn load_system_font() -> Option<Font<'static>> {
             &font_loader::system_fonts::FontPropertyBuilder::new()
                 .family(family)
                 .build(),
-        ) {
-            if let Some(font) = Font::try_from_vec(data) {
-                return Some(font);
-            }
+        ) && let Some(font) = Font::try_from_vec(data)
+        {
+            return Some(font);
         }
     }
 
@@ -140,39 +500,216 @@ fn load_system_font() -> Option<Font<'static>> {
 
 /// Renders the palette into an RGB image with vertical bars and optional labels.
 pub fn palette_to_image<'a>(colors: &[Srgb8], labels: PaletteLabelStyle<'a>, size: (u32, u32)) -> RgbImage {
-    let system_font = load_system_font();
-    let min_height = if system_font.is_some() { MIN_HEIGHT_WITH_TRUETYPE } else { FONT_HEIGHT + 8 };
+    palette_to_image_with_font(colors, labels, size, None)
+}
 
-    let width = max(size.0, colors.len() as u32).max(1);
-    let height = max(size.1, min_height);
-    let mut image = RgbImage::from_pixel(width, height, ImgRgb([0, 0, 0]));
+/// Renders the palette like [`palette_to_image`], labelling with `font` (see [`load_font_file`]) when given.
+///
+/// Without a font, falls back to the system font search and then the built-in bitmap font.
+pub fn palette_to_image_with_font<'a>(
+    colors: &[Srgb8], labels: PaletteLabelStyle<'a>, size: (u32, u32), font: Option<&Font<'static>>,
+) -> RgbImage {
+    palette_to_image_with_layout(colors, labels, size, font, PaletteLayout::default())
+}
+
+/// Renders the palette like [`palette_to_image_with_font`], arranging swatches according to `layout`.
+///
+/// Horizontal bands are stacked top to bottom with labels centered along each band's bottom edge; the image is made
+/// tall enough that every band fits a label. Labels stay centered within their swatch when there is a gutter.
+pub fn palette_to_image_with_layout<'a>(
+    colors: &[Srgb8], labels: PaletteLabelStyle<'a>, size: (u32, u32), font: Option<&Font<'static>>,
+    layout: PaletteLayout,
+) -> RgbImage {
+    let system_font = if font.is_none() { load_system_font() } else { None };
+    render_palette(colors, labels, size, font.or(system_font.as_ref()), layout)
+}
+
+fn render_palette<'a>(
+    colors: &[Srgb8], labels: PaletteLabelStyle<'a>, size: (u32, u32), font: Option<&Font<'static>>,
+    layout: PaletteLayout,
+) -> RgbImage {
+    let min_height = if font.is_some() { MIN_HEIGHT_WITH_TRUETYPE } else { FONT_HEIGHT + 8 };
+
+    let count = colors.len() as u32;
+    let gutters = layout.gutter_total(colors.len());
+    let (width, height) = match layout.orientation {
+        PaletteOrientation::Vertical => (max(size.0, count + gutters).max(1), max(size.1, min_height)),
+        PaletteOrientation::Horizontal => (size.0.max(1), max(size.1, count.max(1) * min_height + gutters)),
+    };
+    let background = layout.background;
+    let mut image = RgbImage::from_pixel(width, height, ImgRgb(background.into()));
 
     if colors.is_empty() {
         return image;
     }
 
     let label_strings = build_labels(colors, labels);
-    let segments = colors.len() as u32;
-    let mut start_x = 0;
-    let base_width = max(width / segments, 1);
+    for (index, &color) in colors.iter().enumerate() {
+        let ((start_x, end_x), (start_y, end_y)) = layout.swatch_bounds(index, colors.len(), (width, height));
+        fill_rect(&mut image, (start_x, start_y), (end_x, end_y), color);
+
+        if let Some(text) = label_strings.get(index) {
+            let text_color = best_foreground(color, WCAG_AA_NORMAL);
+            if let Some(font) = font {
+                draw_label_truetype(&mut image, text, start_x, end_x, end_y, text_color, font);
+            } else {
+                draw_label_bitmap(&mut image, text, start_x, end_x, end_y, text_color);
+            }
+        }
+    }
+
+    image
+}
+
+/// Start and end of swatch `index` when `count` swatches, separated by `gutter` pixels, share `extent` pixels.
+///
+/// The last swatch takes the remainder.
+fn band_bounds(index: usize, count: usize, extent: u32, gutter: u32) -> (u32, u32) {
+    let gutters = count.saturating_sub(1) as u32 * gutter;
+    let size = max(extent.saturating_sub(gutters) / count as u32, 1);
+    let start = (index as u32 * (size + gutter)).min(extent);
+    let end = if index == count - 1 { extent } else { (start + size).min(extent) };
+    (start, end)
+}
+
+/// Renders the palette as 16-bit swatches, without labels, for banding-free exports of fine gradients.
+///
+/// Swatches are arranged by `layout` like [`palette_to_image_with_layout`]. Save as PNG or TIFF to keep the extra
+/// precision.
+pub fn palette_to_image16(
+    colors: &[Srgb16], size: (u32, u32), layout: PaletteLayout,
+) -> ImageBuffer<ImgRgb<u16>, Vec<u16>> {
+    let (width, height) = layout.unlabeled_size(colors.len(), size);
+    let background = Srgb16::from(layout.background);
+    let mut image = ImageBuffer::from_pixel(width, height, ImgRgb(background.into()));
 
     for (index, &color) in colors.iter().enumerate() {
-        let mut end_x = start_x + base_width;
-        if index == colors.len() - 1 {
-            end_x = width;
+        let ((start_x, end_x), (start_y, end_y)) = layout.swatch_bounds(index, colors.len(), (width, height));
+        for y in start_y..end_y {
+            for x in start_x..end_x {
+                image.put_pixel(x, y, ImgRgb(color.into()));
+            }
         }
-        fill_bar(&mut image, start_x, end_x, color);
+    }
 
-        if let Some(text) = label_strings.get(index) {
-            let text_color = pick_label_color(color);
+    image
+}
+
+/// Renders the palette as an SVG document with one `<rect>` bar per color and optional `<text>` labels.
+///
+/// Uses the same bar layout and contrast-aware label colors as [`palette_to_image`].
+pub fn palette_to_svg<'a>(colors: &[Srgb8], labels: PaletteLabelStyle<'a>, size: (u32, u32)) -> String {
+    palette_to_svg_with_layout(colors, labels, size, PaletteLayout::default())
+}
+
+/// Renders the palette as an SVG document like [`palette_to_svg`], arranging swatches according to `layout`.
+///
+/// With a gutter, a full-size background `<rect>` comes first so it shows between the swatches.
+pub fn palette_to_svg_with_layout<'a>(
+    colors: &[Srgb8], labels: PaletteLabelStyle<'a>, size: (u32, u32), layout: PaletteLayout,
+) -> String {
+    let count = colors.len() as u32;
+    let gutters = layout.gutter_total(colors.len());
+    let (width, height) = layout.unlabeled_size(colors.len(), size);
+    let band_height = match layout.orientation {
+        PaletteOrientation::Vertical => height,
+        PaletteOrientation::Horizontal => height.saturating_sub(gutters) / count.max(1),
+    };
+    let font_size = (band_height / 8).clamp(FONT_HEIGHT, TRUETYPE_FONT_SIZE as u32);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    );
+    if colors.is_empty() {
+        svg.push_str("</svg>\n");
+        return svg;
+    }
+    if layout.gutter > 0 {
+        svg.push_str(&format!(
+            "  <rect x=\"0\" y=\"0\" width=\"{width}\" height=\"{height}\" fill=\"{}\"/>\n",
+            layout.background.to_hex()
+        ));
+    }
+
+    let label_strings = build_labels(colors, labels);
+    for (index, &color) in colors.iter().enumerate() {
+        let ((start_x, end_x), (start_y, end_y)) = layout.swatch_bounds(index, colors.len(), (width, height));
+        svg.push_str(&format!(
+            "  <rect x=\"{start_x}\" y=\"{start_y}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+            end_x - start_x,
+            end_y - start_y,
+            color.to_hex()
+        ));
+
+        if let Some(text) = label_strings.get(index).filter(|text| !text.is_empty()) {
+            let text_color = best_foreground(color, WCAG_AA_NORMAL);
+            svg.push_str(&format!(
+                "  <text x=\"{}\" y=\"{}\" fill=\"{}\" font-family=\"monospace\" font-size=\"{font_size}\" text-anchor=\"middle\">{}</text>\n",
+                (start_x + end_x) / 2,
+                end_y.saturating_sub(font_size / 2),
+                text_color.to_hex(),
+                escape_xml(text)
+            ));
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders a contrast sheet: one cell per foreground/background pair showing the ratio written in the foreground color.
+///
+/// Rows follow `fg_idx` and columns follow `bg_idx` (the same layout as [`crate::wcag::contrast_matrix`]), so the
+/// image is `bg_idx.len() * cell.0` pixels wide and `fg_idx.len() * cell.1` tall. Cell height is raised to the label
+/// font's minimum when needed. Pairs below WCAG AA for normal text get a small marker in the top-left corner.
+///
+/// # Panics
+///
+/// Panics if any index is out of bounds for `colors`.
+pub fn contrast_sheet_image(colors: &[Srgb8], fg_idx: &[usize], bg_idx: &[usize], cell: (u32, u32)) -> RgbImage {
+    let system_font = load_system_font();
+    let min_height = if system_font.is_some() { MIN_HEIGHT_WITH_TRUETYPE } else { FONT_HEIGHT + 8 };
+    let (cell_width, cell_height) = (cell.0.max(1), cell.1.max(min_height));
+
+    let width = (bg_idx.len() as u32 * cell_width).max(1);
+    let height = (fg_idx.len() as u32 * cell_height).max(1);
+    let mut image = RgbImage::from_pixel(width, height, ImgRgb([0, 0, 0]));
+
+    for (row, &fg) in fg_idx.iter().enumerate() {
+        let top = row as u32 * cell_height;
+        for (col, &bg) in bg_idx.iter().enumerate() {
+            let (fg_color, bg_color) = (colors[fg], colors[bg]);
+            let start_x = col as u32 * cell_width;
+            let end_x = start_x + cell_width;
+            fill_rect(&mut image, (start_x, top), (end_x, top + cell_height), bg_color);
+
+            let ratio = contrast_ratio(fg_color, bg_color);
+            let text = format!("{ratio:.1}");
+            let bottom = top + cell_height;
             if let Some(ref font) = system_font {
-                draw_label_truetype(&mut image, text, start_x, end_x, text_color, font);
+                draw_label_truetype(&mut image, &text, start_x, end_x, bottom, fg_color, font);
             } else {
-                draw_label_bitmap(&mut image, text, start_x, end_x, text_color);
+                draw_label_bitmap(&mut image, &text, start_x, end_x, bottom, fg_color);
             }
-        }
 
-        start_x = end_x;
+            if ratio < WCAG_AA_NORMAL {
+                let marker = best_foreground(bg_color, WCAG_AA_NORMAL);
+                let size = FAIL_MARKER_SIZE.min(cell_width).min(cell_height);
+                fill_rect(
+                    &mut image,
+                    (start_x + 2, top + 2),
+                    (start_x + 2 + size, top + 2 + size),
+                    marker,
+                );
+            }
+        }
     }
 
     image
@@ -183,6 +720,10 @@ fn build_labels<'a>(colors: &[Srgb8], labels: PaletteLabelStyle<'a>) -> Vec<Stri
         PaletteLabelStyle::None => Vec::new(),
         PaletteLabelStyle::Hex => colors.iter().map(|c| c.to_hex().to_uppercase()).collect(),
         PaletteLabelStyle::Index => colors.iter().enumerate().map(|(i, _)| format!("{i:02}")).collect(),
+        PaletteLabelStyle::Role => colors
+            .iter()
+            .map(|&color| format!("base{:02X}", closest_role(color)))
+            .collect(),
         PaletteLabelStyle::Custom(values) => colors
             .iter()
             .enumerate()
@@ -191,21 +732,17 @@ fn build_labels<'a>(colors: &[Srgb8], labels: PaletteLabelStyle<'a>) -> Vec<Stri
     }
 }
 
-fn fill_bar(image: &mut RgbImage, start_x: u32, end_x: u32, color: Srgb8) {
-    for y in 0..image.height() {
-        for x in start_x..end_x {
-            image.put_pixel(x, y, ImgRgb([color.r, color.g, color.b]));
+fn fill_rect(image: &mut RgbImage, top_left: (u32, u32), bottom_right: (u32, u32), color: Srgb8) {
+    for y in top_left.1..bottom_right.1.min(image.height()) {
+        for x in top_left.0..bottom_right.0.min(image.width()) {
+            image.put_pixel(x, y, ImgRgb(color.into()));
         }
     }
 }
 
-fn pick_label_color(bg: Srgb8) -> Srgb8 {
-    let white = Srgb8::new(255, 255, 255);
-    let black = Srgb8::new(0, 0, 0);
-    if contrast_ratio(bg, white) >= contrast_ratio(bg, black) { white } else { black }
-}
-
-fn draw_label_truetype(image: &mut RgbImage, text: &str, start_x: u32, end_x: u32, color: Srgb8, font: &Font) {
+fn draw_label_truetype(
+    image: &mut RgbImage, text: &str, start_x: u32, end_x: u32, bottom: u32, color: Srgb8, font: &Font,
+) {
     if text.is_empty() {
         return;
     }
@@ -227,9 +764,7 @@ fn draw_label_truetype(image: &mut RgbImage, text: &str, start_x: u32, end_x: u3
 
     let available = end_x.saturating_sub(start_x);
     let x = start_x + available.saturating_sub(text_width) / 2;
-    let y = image
-        .height()
-        .saturating_sub((v_metrics.ascent - v_metrics.descent) as u32 + 6);
+    let y = bottom.saturating_sub((v_metrics.ascent - v_metrics.descent) as u32 + 6);
 
     for glyph in font.layout(sanitized, scale, point(x as f32, y as f32 + v_metrics.ascent)) {
         if let Some(bounding_box) = glyph.pixel_bounding_box() {
@@ -256,7 +791,7 @@ fn draw_label_truetype(image: &mut RgbImage, text: &str, start_x: u32, end_x: u3
     }
 }
 
-fn draw_label_bitmap(image: &mut RgbImage, text: &str, start_x: u32, end_x: u32, color: Srgb8) {
+fn draw_label_bitmap(image: &mut RgbImage, text: &str, start_x: u32, end_x: u32, bottom: u32, color: Srgb8) {
     if text.is_empty() {
         return;
     }
@@ -269,9 +804,9 @@ fn draw_label_bitmap(image: &mut RgbImage, text: &str, start_x: u32, end_x: u32,
     let text_width = sanitized.len() as u32 * (FONT_WIDTH + 1) - 1;
     let available = end_x.saturating_sub(start_x);
     let x = start_x + available.saturating_sub(text_width) / 2;
-    let y = image.height().saturating_sub(FONT_HEIGHT + 3);
+    let y = bottom.saturating_sub(FONT_HEIGHT + 3);
 
-    draw_text(image, sanitized, x, y, ImgRgb([color.r, color.g, color.b]));
+    draw_text(image, sanitized, x, y, ImgRgb(color.into()));
 }
 
 fn draw_text(image: &mut RgbImage, text: &str, mut cursor_x: u32, cursor_y: u32, color: ImgRgb<u8>) {
@@ -313,7 +848,9 @@ fn glyph_for(ch: char) -> Option<&'static [u8; FONT_HEIGHT as usize]> {
         'D' => Some(&[0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100]),
         'E' => Some(&[0b11111, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000, 0b11111]),
         'F' => Some(&[0b11111, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000, 0b10000]),
+        'S' => Some(&[0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110]),
         '#' => Some(&[0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010]),
+        '.' => Some(&[0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100]),
         ' ' => Some(&[0; FONT_HEIGHT as usize]),
         _ => None,
     }
@@ -323,6 +860,26 @@ fn glyph_for(ch: char) -> Option<&'static [u8; FONT_HEIGHT as usize]> {
 mod tests {
     use super::*;
     use crate::colors::Srgb8;
+    use crate::wcag::contrast_ratio;
+
+    #[test]
+    fn palette_export_round_trips_through_json() {
+        let colors = [Srgb8::new(255, 0, 0), Srgb8::from_hex("#336699").unwrap()];
+        let export = PaletteExport::new(&colors);
+        let json = serde_json::to_string(&export).unwrap();
+        let parsed: PaletteExport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, export);
+        assert_eq!(parsed.count, 2);
+        for (entry, &color) in parsed.colors.iter().zip(&colors) {
+            let hsl = Hsl::from(color);
+            assert_eq!(Srgb8::from_hex(&entry.hex), Some(color));
+            assert_eq!(entry.rgb, [color.r, color.g, color.b]);
+            assert_eq!(entry.hsl, [hsl.h, hsl.s, hsl.l]);
+        }
+        assert_eq!(parsed.colors[1].index, 1);
+        assert!((parsed.colors[1].hsl[0] - 210.0).abs() < 0.01);
+    }
 
     #[test]
     fn palette_from_base_generates_requested_count() {
@@ -331,16 +888,98 @@ mod tests {
         assert_eq!(palette.len(), 5);
     }
 
+    #[test]
+    fn palette_from_base_hsv_keeps_base_value_in_first_round() {
+        let base = Srgb8::new(255, 85, 0);
+        let palette = palette_from_base_hsv(base, HarmonyKind::Triadic, 3, None, None, None);
+
+        assert_eq!(palette.len(), 3);
+        assert_eq!(palette[0], base);
+        for &color in &palette {
+            let hsv: Hsv = Rgb::from(color).into();
+            assert!((hsv.v - 1.0).abs() < 0.01 && (hsv.s - 1.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn dedup_exact_preserves_first_seen_order() {
+        let red = Srgb8::new(255, 0, 0);
+        let green = Srgb8::new(0, 255, 0);
+        let mut colors = vec![red, green, red, red, green];
+        dedup_exact(&mut colors);
+        assert_eq!(colors, vec![red, green]);
+    }
+
+    #[test]
+    fn palette_from_base_collapses_repeated_harmony_colors() {
+        // Hue rotation leaves a gray unchanged, so every harmony member repeats the base.
+        let gray = Srgb8::new(128, 128, 128);
+        let palette = palette_from_base(gray, HarmonyKind::Square, 4, None, None, None);
+        assert_eq!(palette.len(), 4);
+        let mut unique = palette.clone();
+        dedup_exact(&mut unique);
+        assert_eq!(unique.len(), palette.len());
+    }
+
     #[test]
     fn palette_from_base_enforces_contrast_when_requested() {
         let base = Srgb8::new(200, 200, 200);
         let background = Srgb8::new(180, 180, 180);
-        let palette = palette_from_base(base, HarmonyKind::Analogous(20.0), 4, None, Some(background), Some(4.5));
+        let palette = palette_from_base(
+            base,
+            HarmonyKind::Analogous { angle: 20.0, count: 3 },
+            4,
+            None,
+            Some(background),
+            Some(4.5),
+        );
         for color in palette {
             assert!(contrast_ratio(background, color) >= 4.5);
         }
     }
 
+    #[test]
+    fn sort_palette_by_warmth_runs_cool_to_warm() {
+        let (red, gray, blue, orange) = (
+            Srgb8::new(220, 30, 30),
+            Srgb8::new(128, 128, 128),
+            Srgb8::new(30, 60, 230),
+            Srgb8::new(250, 150, 20),
+        );
+        let mut colors = [red, gray, blue, orange];
+        sort_palette(&mut colors, SortKey::Warmth);
+        assert_eq!(colors[0], blue);
+        assert_eq!(colors[1], gray);
+
+        sort_palette(&mut colors, SortKey::Lightness);
+        assert_eq!(colors[0], blue);
+        assert_eq!(colors[3], orange);
+    }
+
+    #[test]
+    fn harmony_score_prefers_spread_palettes() {
+        let spread = [
+            Srgb8::from_hex("#d62828").unwrap(),
+            Srgb8::from_hex("#f7b538").unwrap(),
+            Srgb8::from_hex("#2a9d8f").unwrap(),
+            Srgb8::from_hex("#1d3557").unwrap(),
+            Srgb8::from_hex("#e9d8fd").unwrap(),
+        ];
+        let clustered = [
+            Srgb8::from_hex("#3a7bd5").unwrap(),
+            Srgb8::from_hex("#3c7dd6").unwrap(),
+            Srgb8::from_hex("#3b7cd3").unwrap(),
+            Srgb8::from_hex("#397ad5").unwrap(),
+            Srgb8::from_hex("#3d7ed7").unwrap(),
+        ];
+
+        let (spread_score, clustered_score) = (harmony_score(&spread), harmony_score(&clustered));
+        assert!((0.0..=1.0).contains(&spread_score));
+        assert!(spread_score > 0.6, "spread palette scored {spread_score}");
+        assert!(clustered_score < 0.1, "clustered palette scored {clustered_score}");
+        assert_eq!(harmony_score(&spread[..1]), 0.0);
+    }
+
     #[test]
     fn golden_ratio_palette_respects_min_delta_e() {
         let palette = golden_ratio_palette(6, 0.5..0.8, 0.4..0.6, Some(2.0));
@@ -354,6 +993,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn golden_ratio_palette_spread_preserves_count() {
+        let dropped = golden_ratio_palette(12, 0.5..0.6, 0.45..0.55, Some(15.0));
+        let spread = golden_ratio_palette_spread(12, 0.5..0.6, 0.45..0.55, 15.0);
+        assert!(dropped.len() < 12);
+        assert_eq!(spread.len(), 12);
+    }
+
     #[test]
     fn palette_image_dimensions_match_request() {
         let colors = vec![Srgb8::new(255, 0, 0), Srgb8::new(0, 255, 0)];
@@ -361,4 +1008,286 @@ mod tests {
         assert_eq!(image.width(), 200);
         assert_eq!(image.height(), 80);
     }
+
+    #[test]
+    fn horizontal_orientation_stacks_full_width_bands() {
+        let colors = vec![Srgb8::new(255, 0, 0), Srgb8::new(0, 0, 255)];
+        let horizontal = PaletteLayout { orientation: PaletteOrientation::Horizontal, ..Default::default() };
+        let image = render_palette(&colors, PaletteLabelStyle::None, (200, 80), None, horizontal);
+        assert_eq!((image.width(), image.height()), (200, 80));
+
+        let (red, blue) = (ImgRgb([255, 0, 0]), ImgRgb([0, 0, 255]));
+        assert_eq!(*image.get_pixel(0, 0), red);
+        assert_eq!(*image.get_pixel(199, 0), red);
+        assert_eq!(*image.get_pixel(199, 39), red);
+        assert_eq!(*image.get_pixel(0, 40), blue);
+        assert_eq!(*image.get_pixel(199, 79), blue);
+
+        let vertical = render_palette(
+            &colors,
+            PaletteLabelStyle::None,
+            (200, 80),
+            None,
+            PaletteLayout::default(),
+        );
+        assert_eq!(*vertical.get_pixel(199, 0), blue);
+
+        let svg = palette_to_svg_with_layout(&colors, PaletteLabelStyle::None, (200, 80), horizontal);
+        assert!(svg.contains("<rect x=\"0\" y=\"40\" width=\"200\" height=\"40\" fill=\"#0000ff\"/>"));
+    }
+
+    #[test]
+    fn gutter_separates_swatches_with_background() {
+        let colors = vec![Srgb8::new(255, 0, 0), Srgb8::new(0, 255, 0), Srgb8::new(0, 0, 255)];
+        let background = Srgb8::new(250, 250, 250);
+        let layout = PaletteLayout { gutter: 10, background, ..Default::default() };
+        let image = render_palette(&colors, PaletteLabelStyle::None, (320, 40), None, layout);
+        assert_eq!(image.width(), 320);
+
+        // (320 - 2 * 10) / 3 = 100 px swatches at x = 0, 110, and 220.
+        let bg = ImgRgb([250, 250, 250]);
+        for x in (100..110).chain(210..220) {
+            for y in [0, 20, 39] {
+                assert_eq!(*image.get_pixel(x, y), bg, "gutter pixel at ({x}, {y})");
+            }
+        }
+        assert_eq!(*image.get_pixel(99, 0), ImgRgb([255, 0, 0]));
+        assert_eq!(*image.get_pixel(110, 0), ImgRgb([0, 255, 0]));
+        assert_eq!(*image.get_pixel(220, 0), ImgRgb([0, 0, 255]));
+        assert_eq!(*image.get_pixel(319, 39), ImgRgb([0, 0, 255]));
+
+        let svg = palette_to_svg_with_layout(&colors, PaletteLabelStyle::None, (320, 40), layout);
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"320\" height=\"40\" fill=\"#fafafa\"/>"));
+        assert!(svg.contains("<rect x=\"110\" y=\"0\" width=\"100\" height=\"40\" fill=\"#00ff00\"/>"));
+    }
+
+    #[test]
+    fn truetype_font_raises_minimum_label_height() {
+        // Any TTF works here; DejaVu is the one most Linux systems ship, so use it rather than vendoring a font.
+        let font = load_font_file(Path::new("/usr/share/fonts/truetype/dejavu/DejaVuSansMono.ttf"))
+            .expect("test needs DejaVu Sans Mono at /usr/share/fonts/truetype/dejavu");
+        let colors = vec![Srgb8::new(255, 0, 0), Srgb8::new(0, 0, 255)];
+
+        let bitmap = render_palette(
+            &colors,
+            PaletteLabelStyle::Hex,
+            (200, 10),
+            None,
+            PaletteLayout::default(),
+        );
+        let truetype = render_palette(
+            &colors,
+            PaletteLabelStyle::Hex,
+            (200, 10),
+            Some(&font),
+            PaletteLayout::default(),
+        );
+        assert_eq!(bitmap.height(), FONT_HEIGHT + 8);
+        assert_eq!(truetype.height(), MIN_HEIGHT_WITH_TRUETYPE);
+        assert!(load_font_file(Path::new("Cargo.toml")).is_none());
+    }
+
+    #[test]
+    fn palette_svg_has_one_rect_per_color() {
+        let colors = vec![Srgb8::new(255, 0, 0), Srgb8::new(0, 128, 255), Srgb8::new(20, 20, 20)];
+        let svg = palette_to_svg(&colors, PaletteLabelStyle::Hex, (300, 80));
+        assert_eq!(svg.matches("<rect").count(), 3);
+        assert_eq!(svg.matches("<text").count(), 3);
+        for color in &colors {
+            assert!(svg.contains(&format!("fill=\"{}\"", color.to_hex())));
+        }
+        assert!(svg.contains(">#0080FF</text>"));
+    }
+
+    #[test]
+    fn contrast_sheet_dimensions_follow_grid() {
+        let colors = vec![
+            Srgb8::new(20, 20, 20),
+            Srgb8::new(240, 240, 240),
+            Srgb8::new(200, 60, 60),
+        ];
+        let image = contrast_sheet_image(&colors, &[1, 2], &[0, 1, 2], (100, 60));
+        assert_eq!(image.width(), 300);
+        assert_eq!(image.height(), 120);
+
+        let failing = image.get_pixel(204, 64);
+        let background = image.get_pixel(299, 60);
+        assert_eq!(background.0, [200, 60, 60]);
+        assert_ne!(failing, background, "self-contrast cell should carry a fail marker");
+    }
+
+    #[test]
+    fn boost_chroma_raises_chroma_and_maps_edge_colors_into_gamut() {
+        let chroma = |color: Srgb8| Lch::from(Lab::from(color)).c;
+        let muted = Srgb8::new(0x80, 0x70, 0x60);
+        let mut colors = vec![muted];
+        boost_chroma(&mut colors, 0.3);
+        assert!(
+            chroma(colors[0]) > chroma(muted) * 1.25,
+            "{} -> {}",
+            chroma(muted),
+            chroma(colors[0])
+        );
+
+        // Nearly saturated red: a 50% boost is far outside sRGB, so it must be pulled back along its hue.
+        let edge = Srgb8::new(0xf0, 0x30, 0x30);
+        let original = Lch::from(Lab::from(edge));
+        let mut boosted = vec![edge];
+        boost_chroma(&mut boosted, 0.5);
+        let mapped = Lch::from(Lab::from(boosted[0]));
+        assert!(mapped.c > original.c, "chroma {} -> {}", original.c, mapped.c);
+        assert!(
+            (mapped.l - original.l).abs() < 1.5,
+            "lightness {} -> {}",
+            original.l,
+            mapped.l
+        );
+        assert!(
+            (mapped.h - original.h).abs() < 2.0,
+            "hue {} -> {}",
+            original.h,
+            mapped.h
+        );
+
+        let clipped = Lch::from(Lab::from(Srgb8::from(Lch::new(
+            original.l,
+            original.c * 1.5,
+            original.h,
+        ))));
+        assert!(
+            (clipped.h - original.h).abs() + (clipped.l - original.l).abs()
+                > (mapped.h - original.h).abs() + (mapped.l - original.l).abs(),
+            "naive clipping should drift further than gamut mapping"
+        );
+    }
+
+    #[test]
+    fn palette_to_image16_keeps_full_precision() {
+        let colors = [Srgb16::new(0x1234, 0x5678, 0x9abc), Srgb16::new(0xffff, 0, 0x0101)];
+        let image = palette_to_image16(&colors, (5, 2), PaletteLayout::default());
+
+        assert_eq!(image.dimensions(), (5, 2));
+        assert_eq!(image.get_pixel(0, 0).0, [0x1234, 0x5678, 0x9abc]);
+        assert_eq!(image.get_pixel(1, 1).0, [0x1234, 0x5678, 0x9abc]);
+        assert_eq!(
+            image.get_pixel(4, 0).0,
+            [0xffff, 0, 0x0101],
+            "last bar absorbs the remainder"
+        );
+
+        let layout = PaletteLayout {
+            orientation: PaletteOrientation::Horizontal,
+            gutter: 2,
+            background: Srgb8::new(255, 255, 255),
+        };
+        let image = palette_to_image16(&colors, (3, 8), layout);
+        assert_eq!(image.dimensions(), (3, 8));
+        assert_eq!(image.get_pixel(2, 0).0, [0x1234, 0x5678, 0x9abc]);
+        assert_eq!(image.get_pixel(0, 3).0, [0xffff; 3], "gutter shows the background");
+        assert_eq!(image.get_pixel(0, 7).0, [0xffff, 0, 0x0101]);
+    }
+
+    #[test]
+    fn partition_temperature_sorts_warm_cool_and_neutral() {
+        let red = Srgb8::new(220, 40, 40);
+        let orange = Srgb8::new(255, 150, 0);
+        let blue = Srgb8::new(40, 80, 220);
+        let green = Srgb8::new(40, 180, 90);
+        let gray = Srgb8::new(128, 128, 128);
+        let warm_gray = Srgb8::new(130, 126, 120);
+
+        let partition = partition_temperature(&[red, blue, gray, orange, green, warm_gray]);
+        assert_eq!(partition.warm, [red, orange]);
+        assert_eq!(partition.cool, [blue, green]);
+        assert_eq!(partition.neutral, [gray, warm_gray]);
+    }
+
+    #[test]
+    fn adjust_identity_leaves_colors_unchanged() {
+        let original: Vec<Srgb8> = (0..=255u8).map(|v| Srgb8::new(v, 255 - v, v / 2)).collect();
+        let mut colors = original.clone();
+        adjust(&mut colors, 0.0, 1.0, 1.0);
+        assert_eq!(colors, original);
+    }
+
+    #[test]
+    fn adjust_low_gamma_brightens_midtones() {
+        let mut colors = vec![
+            Srgb8::new(128, 128, 128),
+            Srgb8::new(0, 0, 0),
+            Srgb8::new(255, 255, 255),
+        ];
+        adjust(&mut colors, 0.0, 1.0, 0.5);
+
+        assert!(colors[0].r > 128);
+        assert_eq!(colors[1], Srgb8::new(0, 0, 0));
+        assert_eq!(colors[2], Srgb8::new(255, 255, 255));
+
+        let mut boosted = vec![Srgb8::new(250, 10, 128)];
+        adjust(&mut boosted, 0.5, 2.0, 1.0);
+        assert_eq!(boosted[0].r, 255);
+        assert!(boosted[0].g > 10);
+    }
+
+    #[test]
+    fn role_labels_name_nearest_base16_slot() {
+        let colors = [Srgb8::new(230, 30, 30), Srgb8::new(30, 210, 60)];
+        assert_eq!(build_labels(&colors, PaletteLabelStyle::Role), vec!["base08", "base0B"]);
+    }
+
+    #[test]
+    fn average_color_uses_lab_midpoint() {
+        let gray = average_color(&[Srgb8::new(0, 0, 0), Srgb8::new(255, 255, 255)]).unwrap();
+        assert_eq!(gray.r, gray.g);
+        assert_eq!(gray.g, gray.b);
+        assert!((Lab::from(gray).l - 50.0).abs() < 1.0);
+        // A naive sRGB average would give 127/128; L* 50 is noticeably darker.
+        assert!((116..=121).contains(&gray.r), "got {gray}");
+        assert_eq!(average_color(&[]), None);
+    }
+
+    #[test]
+    fn dominant_by_chroma_picks_most_colorful() {
+        let colors = [
+            Srgb8::new(128, 128, 128),
+            Srgb8::new(200, 60, 60),
+            Srgb8::new(255, 0, 255),
+        ];
+        assert_eq!(dominant_by_chroma(&colors), Some(Srgb8::new(255, 0, 255)));
+        assert_eq!(dominant_by_chroma(&[]), None);
+    }
+
+    #[test]
+    fn scale_lightness_lch_equalizes_perceived_lightness() {
+        let mut palette: Vec<Lch> = [
+            Srgb8::new(220, 50, 47),
+            Srgb8::new(181, 137, 0),
+            Srgb8::new(133, 153, 0),
+            Srgb8::new(42, 161, 152),
+            Srgb8::new(38, 139, 210),
+            Srgb8::new(108, 113, 196),
+        ]
+        .into_iter()
+        .map(|color| Lch::from(Lab::from(color)))
+        .collect();
+        let hues: Vec<f32> = palette.iter().map(|color| color.h).collect();
+
+        scale_lightness_lch(&mut palette, 60.0);
+
+        for (color, hue) in palette.into_iter().zip(hues) {
+            let lab = Lab::from(Srgb8::from(Lab::from(color)));
+            assert!((lab.l - 60.0).abs() < 1.0, "hue {hue} ended at L {}", lab.l);
+            assert!((color.h - hue).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn shift_lightness_lch_clamps() {
+        let mut palette = vec![Lch::new(95.0, 20.0, 40.0), Lch::new(30.0, 20.0, 200.0)];
+        shift_lightness_lch(&mut palette, 10.0);
+
+        assert!((palette[0].l - 100.0).abs() < 0.01);
+        assert!((palette[1].l - 40.0).abs() < 0.01);
+        assert!((palette[1].c - 20.0).abs() < 0.01 && (palette[1].h - 200.0).abs() < 0.01);
+    }
 }
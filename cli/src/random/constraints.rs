@@ -5,6 +5,7 @@ use crate::random::random_hsl;
 use crate::wcag::contrast_ratio;
 use rand::seq::IndexedRandom;
 use rand::{Rng, rngs::ThreadRng};
+use std::fmt;
 use std::ops::Range;
 
 /// Constraints applied during random palette construction.
@@ -35,6 +36,98 @@ impl Default for PaletteConstraints {
     }
 }
 
+impl PaletteConstraints {
+    /// Starts a builder initialized with the default constraints.
+    pub fn builder() -> PaletteConstraintsBuilder {
+        PaletteConstraintsBuilder { constraints: PaletteConstraints::default() }
+    }
+}
+
+/// Error returned by [`PaletteConstraintsBuilder::build`] when a constraint is inconsistent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintsError {
+    /// A range's start is not below its end, or it falls outside [0, 1].
+    InvalidRange { field: &'static str, start: f32, end: f32 },
+}
+
+impl fmt::Display for ConstraintsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintsError::InvalidRange { field, start, end } => {
+                write!(f, "invalid {field} {start}..{end}: expected 0 <= start < end <= 1")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConstraintsError {}
+
+/// Builder for [`PaletteConstraints`] that validates ranges on [`build`](Self::build).
+#[derive(Debug, Clone)]
+pub struct PaletteConstraintsBuilder {
+    constraints: PaletteConstraints,
+}
+
+impl PaletteConstraintsBuilder {
+    /// Seeds the palette with a base color (always accepted first when it passes the filters).
+    pub fn base(mut self, base: Srgb8) -> Self {
+        self.constraints.base = Some(base);
+        self
+    }
+
+    /// Samples candidates around the harmony of `base` instead of uniformly.
+    pub fn harmony(mut self, harmony: HarmonyKind) -> Self {
+        self.constraints.harmony = Some(harmony);
+        self
+    }
+
+    /// Requires every color to reach `min_ratio` contrast against `background`.
+    pub fn min_contrast(mut self, min_ratio: f32, background: Srgb8) -> Self {
+        self.constraints.min_contrast = Some(min_ratio);
+        self.constraints.background = Some(background);
+        self
+    }
+
+    /// Requires every pair of colors to be at least this far apart (ΔE2000).
+    pub fn min_delta_e(mut self, min_delta_e: f32) -> Self {
+        self.constraints.min_delta_e = Some(min_delta_e);
+        self
+    }
+
+    /// Limits sampled HSL saturation to `range` (default `0.2..0.9`); [`build`](Self::build) rejects ranges outside [0, 1].
+    pub fn saturation_range(mut self, range: Range<f32>) -> Self {
+        self.constraints.saturation_range = range;
+        self
+    }
+
+    /// Limits sampled HSL lightness to `range` (default `0.25..0.75`); [`build`](Self::build) rejects ranges outside [0, 1].
+    pub fn lightness_range(mut self, range: Range<f32>) -> Self {
+        self.constraints.lightness_range = range;
+        self
+    }
+
+    /// Caps the number of candidates sampled before giving up.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.constraints.max_iterations = max_iterations;
+        self
+    }
+
+    /// Validates the ranges and returns the finished constraints.
+    pub fn build(self) -> Result<PaletteConstraints, ConstraintsError> {
+        validate_unit_range("saturation range", &self.constraints.saturation_range)?;
+        validate_unit_range("lightness range", &self.constraints.lightness_range)?;
+        Ok(self.constraints)
+    }
+}
+
+fn validate_unit_range(field: &'static str, range: &Range<f32>) -> Result<(), ConstraintsError> {
+    if range.start < range.end && range.start >= 0.0 && range.end <= 1.0 {
+        Ok(())
+    } else {
+        Err(ConstraintsError::InvalidRange { field, start: range.start, end: range.end })
+    }
+}
+
 /// Generates a palette that satisfies the provided constraints (best effort).
 pub fn random_palette_with_constraints(count: usize, constraints: PaletteConstraints) -> Vec<Srgb8> {
     if count == 0 {
@@ -45,11 +138,11 @@ pub fn random_palette_with_constraints(count: usize, constraints: PaletteConstra
     let mut accepted: Vec<Srgb8> = Vec::with_capacity(count);
     let mut labs: Vec<Lab> = Vec::with_capacity(count);
 
-    if let Some(base) = constraints.base {
-        if passes_filters(base, &labs, &constraints) {
-            labs.push(Lab::from(base));
-            accepted.push(base);
-        }
+    if let Some(base) = constraints.base
+        && passes_filters(base, &labs, &constraints)
+    {
+        labs.push(Lab::from(base));
+        accepted.push(base);
     }
 
     let mut iterations = 0;
@@ -66,18 +159,18 @@ pub fn random_palette_with_constraints(count: usize, constraints: PaletteConstra
 }
 
 fn passes_filters(candidate: Srgb8, labs: &[Lab], constraints: &PaletteConstraints) -> bool {
-    if let (Some(bg), Some(min_ratio)) = (constraints.background, constraints.min_contrast) {
-        if contrast_ratio(bg, candidate) < min_ratio {
-            return false;
-        }
+    if let (Some(bg), Some(min_ratio)) = (constraints.background, constraints.min_contrast)
+        && contrast_ratio(bg, candidate) < min_ratio
+    {
+        return false;
     }
 
-    if let Some(threshold) = constraints.min_delta_e {
-        if threshold > 0.0 {
-            let candidate_lab = Lab::from(candidate);
-            if labs.iter().any(|&lab| delta_e_2000(lab, candidate_lab) < threshold) {
-                return false;
-            }
+    if let Some(threshold) = constraints.min_delta_e
+        && threshold > 0.0
+    {
+        let candidate_lab = Lab::from(candidate);
+        if labs.iter().any(|&lab| delta_e_2000(lab, candidate_lab) < threshold) {
+            return false;
         }
     }
 
@@ -137,4 +230,41 @@ mod tests {
             assert!(contrast_ratio(bg, color) >= 4.5);
         }
     }
+
+    #[test]
+    fn builder_sets_every_field() {
+        let base = Srgb8::new(200, 80, 40);
+        let bg = Srgb8::new(20, 20, 20);
+        let constraints = PaletteConstraints::builder()
+            .base(base)
+            .harmony(HarmonyKind::Triadic)
+            .min_contrast(4.5, bg)
+            .min_delta_e(8.0)
+            .saturation_range(0.3..0.8)
+            .lightness_range(0.4..0.6)
+            .max_iterations(250)
+            .build()
+            .unwrap();
+
+        assert_eq!(constraints.base, Some(base));
+        assert_eq!(constraints.harmony, Some(HarmonyKind::Triadic));
+        assert_eq!(
+            (constraints.min_contrast, constraints.background),
+            (Some(4.5), Some(bg))
+        );
+        assert_eq!(constraints.min_delta_e, Some(8.0));
+        assert_eq!(constraints.saturation_range, 0.3..0.8);
+        assert_eq!(constraints.lightness_range, 0.4..0.6);
+        assert_eq!(constraints.max_iterations, 250);
+    }
+
+    #[test]
+    fn builder_rejects_out_of_order_range() {
+        let result = PaletteConstraints::builder().saturation_range(0.8..0.3).build();
+        assert_eq!(
+            result.unwrap_err(),
+            ConstraintsError::InvalidRange { field: "saturation range", start: 0.8, end: 0.3 }
+        );
+        assert!(PaletteConstraints::builder().lightness_range(0.5..1.5).build().is_err());
+    }
 }
@@ -7,18 +7,39 @@
 //! - Poisson-disk sampling in perceptual color spaces
 //! - Noise-driven palette walks
 
-use crate::colors::{Hsl, Rgb, Srgb8, clamp01};
+use crate::base16_builder::DEFAULT_ACCENT_DELTA_E;
+use crate::colors::{Hsl, Lab, Lch, Rgb, Srgb8, clamp01};
+use crate::diffs::{delta_e_2000, ensure_min_distance};
 use crate::wcag::contrast_ratio;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::ops::Range;
 
+/// Number of Lch candidates drawn by [`suggest_accents`] before filtering.
+const ACCENT_SAMPLES: usize = 2048;
+
+/// ΔE2000 beyond which a sampled Lch color is considered clipped by the sRGB gamut and discarded.
+const GAMUT_TOLERANCE: f32 = 1.0;
+
+/// Saturation range for UI accents, shared by [`random_ui_accent_color`] and [`color_from_hash`].
+const UI_ACCENT_SATURATION: Range<f32> = 0.4..0.9;
+/// Lightness range for UI accents, shared by [`random_ui_accent_color`] and [`color_from_hash`].
+const UI_ACCENT_LIGHTNESS: Range<f32> = 0.35..0.7;
+
+/// Generator runs [`fill_to_count`] makes before settling for its largest result.
+const FILL_MAX_ATTEMPTS: usize = 16;
+/// Factor [`fill_to_count`] shrinks the spacing threshold by after each short result.
+const FILL_RELAXATION: f32 = 0.85;
+
 pub mod constraints;
 pub mod noise;
 pub mod poisson;
 
-pub use constraints::{PaletteConstraints, random_palette_with_constraints};
-pub use noise::{HashNoise, NoiseSource, noise_palette, random_walk_lch};
-pub use poisson::{PoissonConfig, poisson_palette};
+pub use constraints::{
+    ConstraintsError, PaletteConstraints, PaletteConstraintsBuilder, random_palette_with_constraints,
+};
+pub use noise::{FractalNoise, HashNoise, NoiseSource, noise_palette, random_walk_lch};
+pub use poisson::{PoissonConfig, PoissonSpace, poisson_palette};
 
 /// Simple theme hint used by helpers when sampling background colors.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,10 +59,38 @@ pub fn random_hsl<R: Rng + ?Sized>(rng: &mut R, s_range: Range<f32>, l_range: Ra
 /// Returns a saturated UI accent color sampled from visually pleasing ranges.
 pub fn random_ui_accent_color() -> Rgb {
     let mut rng = rand::rng();
-    let hsl = random_hsl(&mut rng, 0.4..0.9, 0.35..0.7);
+    let hsl = random_hsl(&mut rng, UI_ACCENT_SATURATION, UI_ACCENT_LIGHTNESS);
     Rgb::from(hsl)
 }
 
+/// Maps arbitrary bytes to a stable UI accent color, e.g. for avatars or tags keyed by username.
+///
+/// The input is hashed with 64-bit FNV-1a (fixed, so colors never change between releases or platforms). The hash
+/// picks the hue and places saturation and lightness within the ranges used by [`random_ui_accent_color`].
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::random::color_from_hash;
+///
+/// assert_eq!(color_from_hash(b"alice"), color_from_hash(b"alice"));
+/// assert_ne!(color_from_hash(b"alice"), color_from_hash(b"bob"));
+/// ```
+pub fn color_from_hash(input: &[u8]) -> Srgb8 {
+    let hash = input.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    let unit = |bits: u64| bits as f32 / 65536.0;
+    let lerp = |range: Range<f32>, t: f32| range.start + (range.end - range.start) * t;
+
+    let hsl = Hsl::new(
+        unit(hash & 0xffff) * 360.0,
+        lerp(UI_ACCENT_SATURATION, unit((hash >> 16) & 0xffff)),
+        lerp(UI_ACCENT_LIGHTNESS, unit((hash >> 32) & 0xffff)),
+    );
+    Srgb8::from(Rgb::from(hsl))
+}
+
 /// Samples a background color biased toward the requested theme brightness.
 pub fn random_background_color(theme: LightOrDark) -> Rgb {
     let mut rng = rand::rng();
@@ -67,6 +116,54 @@ pub fn sample_contrasting_color(bg: Srgb8, min_ratio: f32, max_attempts: usize)
     None
 }
 
+/// Suggests accent colors that stand out against `bg`.
+///
+/// Samples high-chroma Lch colors (deterministically from `seed`), keeps the in-gamut ones reaching `min_contrast`
+/// against the background, and drops candidates within [`DEFAULT_ACCENT_DELTA_E`] of a more saturated one.
+/// Returns up to `count` colors sorted by chroma, most vivid first; fewer are returned when not enough candidates pass.
+pub fn suggest_accents(bg: Srgb8, min_contrast: f32, count: usize, seed: u64) -> Vec<Srgb8> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut candidates: Vec<Lch> = (0..ACCENT_SAMPLES)
+        .filter_map(|_| {
+            let sampled = Lch::new(
+                rng.random_range(20.0..95.0),
+                rng.random_range(50.0..130.0),
+                rng.random_range(0.0..360.0),
+            );
+            let color = Srgb8::from(sampled);
+            let in_gamut = delta_e_2000(Lab::from(sampled), Lab::from(color)) < GAMUT_TOLERANCE;
+            (in_gamut && contrast_ratio(color, bg) >= min_contrast).then(|| Lch::from(Lab::from(color)))
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.c.total_cmp(&a.c));
+
+    let mut distinct: Vec<Lab> = candidates.into_iter().map(Lab::from).collect();
+    ensure_min_distance(&mut distinct, DEFAULT_ACCENT_DELTA_E);
+    distinct.into_iter().take(count).map(Srgb8::from).collect()
+}
+
+/// Re-runs a palette generator until it yields `count` colors, relaxing its spacing threshold between attempts.
+///
+/// `generate` receives the current threshold (a ΔE or Poisson radius), starting at `threshold` and shrinking by 15%
+/// after every short result. Extra colors are truncated. After 16 attempts the largest result so far is returned, so
+/// the palette can still come up short when the generator's other constraints cannot be met.
+pub fn fill_to_count(count: usize, threshold: f32, mut generate: impl FnMut(f32) -> Vec<Srgb8>) -> Vec<Srgb8> {
+    let mut best = Vec::new();
+    let mut threshold = threshold.max(0.0);
+    for _ in 0..FILL_MAX_ATTEMPTS {
+        let mut colors = generate(threshold);
+        if colors.len() >= count {
+            colors.truncate(count);
+            return colors;
+        }
+        if colors.len() > best.len() {
+            best = colors;
+        }
+        threshold *= FILL_RELAXATION;
+    }
+    best
+}
+
 fn sample_range_clamped<R: Rng + ?Sized>(rng: &mut R, range: Range<f32>) -> f32 {
     if range.end <= range.start {
         return clamp01(range.start);
@@ -86,6 +183,63 @@ mod tests {
         assert!(color.l >= 0.4 && color.l <= 0.5);
     }
 
+    #[test]
+    fn fill_to_count_relaxes_poisson_radius() {
+        for _ in 0..5 {
+            let colors = fill_to_count(12, 30.0, |radius| {
+                let config = PoissonConfig { radius, ..Default::default() };
+                poisson_palette(config, 12).into_iter().map(Srgb8::from).collect()
+            });
+            assert_eq!(colors.len(), 12);
+        }
+
+        let mut thresholds = Vec::new();
+        let colors = fill_to_count(4, 10.0, |threshold| {
+            thresholds.push(threshold);
+            vec![Srgb8::new(0, 0, 0); if threshold < 8.0 { 6 } else { 2 }]
+        });
+        assert_eq!(colors.len(), 4);
+        assert_eq!(thresholds.len(), 3);
+        assert!(thresholds.windows(2).all(|pair| pair[1] < pair[0]));
+
+        assert_eq!(fill_to_count(5, 10.0, |_| vec![Srgb8::new(1, 2, 3); 3]).len(), 3);
+    }
+
+    #[test]
+    fn color_from_hash_is_stable_and_spreads_inputs() {
+        let names = ["alice", "bob", "carol", "dave", "erin", "frank", "grace", "heidi"];
+        let colors: Vec<Srgb8> = names.iter().map(|name| color_from_hash(name.as_bytes())).collect();
+
+        for (name, &color) in names.iter().zip(&colors) {
+            assert_eq!(color_from_hash(name.as_bytes()), color);
+            let hsl = Hsl::from(Rgb::from(color));
+            assert!((0.35..=0.9).contains(&hsl.s), "{name} saturation {}", hsl.s);
+            assert!((0.34..=0.71).contains(&hsl.l), "{name} lightness {}", hsl.l);
+        }
+        for (i, a) in colors.iter().enumerate() {
+            assert!(colors[i + 1..].iter().all(|b| b != a), "{} collides", names[i]);
+        }
+        assert_ne!(color_from_hash(b""), color_from_hash(b" "));
+    }
+
+    #[test]
+    fn suggested_accents_pass_contrast_and_are_distinct() {
+        let bg = Srgb8::from_hex("#1e1e2e").unwrap();
+        let accents = suggest_accents(bg, 4.5, 5, 7);
+
+        assert_eq!(accents.len(), 5);
+        assert_eq!(accents, suggest_accents(bg, 4.5, 5, 7));
+        for (i, &a) in accents.iter().enumerate() {
+            assert!(contrast_ratio(a, bg) >= 4.5, "{} fails contrast", a.to_hex());
+            for &b in &accents[i + 1..] {
+                assert!(delta_e_2000(Lab::from(a), Lab::from(b)) >= DEFAULT_ACCENT_DELTA_E);
+            }
+        }
+
+        let chroma: Vec<f32> = accents.iter().map(|&c| Lch::from(Lab::from(c)).c).collect();
+        assert!(chroma.windows(2).all(|pair| pair[0] >= pair[1] - 0.5));
+    }
+
     #[test]
     fn contrasting_color_respects_ratio() {
         let bg = Srgb8::new(250, 250, 250);
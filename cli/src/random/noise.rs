@@ -35,6 +35,51 @@ impl NoiseSource for HashNoise {
     }
 }
 
+/// Fractal (fBm-style) noise that sums several octaves of [`HashNoise`] for smoother, more organic variation.
+#[derive(Debug, Clone)]
+pub struct FractalNoise {
+    seed: u32,
+    octaves: u32,
+    lacunarity: f32,
+    persistence: f32,
+}
+
+impl FractalNoise {
+    /// Creates a fractal noise source.
+    ///
+    /// - `octaves`: number of summed layers (at least 1)
+    /// - `lacunarity`: frequency multiplier between octaves (typically 2.0)
+    /// - `persistence`: amplitude multiplier between octaves (typically 0.5)
+    pub fn new(seed: u32, octaves: u32, lacunarity: f32, persistence: f32) -> Self {
+        Self { seed, octaves: octaves.max(1), lacunarity, persistence }
+    }
+}
+
+impl Default for FractalNoise {
+    fn default() -> Self {
+        Self::new(0xdecafbad, 4, 2.0, 0.5)
+    }
+}
+
+impl NoiseSource for FractalNoise {
+    fn noise(&self, x: f32) -> f32 {
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut max_amplitude = 0.0;
+
+        for octave in 0..self.octaves {
+            let layer = HashNoise::new(self.seed.wrapping_add(octave));
+            total += layer.noise(x * frequency) * amplitude;
+            max_amplitude += amplitude;
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+
+        if max_amplitude <= 0.0 { 0.0 } else { (total / max_amplitude).clamp(0.0, 1.0) }
+    }
+}
+
 fn hash(x: i32, seed: u32) -> f32 {
     let mut v = x as u32;
     v = v.wrapping_mul(0x45d9f3b);
@@ -110,6 +155,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fractal_noise_outputs_unit_interval() {
+        let noise = FractalNoise::new(7, 5, 2.0, 0.5);
+        for i in -200..200 {
+            let v = noise.noise(i as f32 * 0.137);
+            assert!((0.0..=1.0).contains(&v), "value {v} out of range");
+        }
+    }
+
+    #[test]
+    fn fractal_noise_drives_noise_palette() {
+        let base = Lch::new(60.0, 40.0, 200.0);
+        let palette = noise_palette(6, base, 0.3, 3.0, &FractalNoise::default());
+        assert_eq!(palette.len(), 6);
+    }
+
     #[test]
     fn random_walk_has_requested_length() {
         let mut rng = rand::rng();
@@ -3,19 +3,43 @@ use crate::diffs::delta_e_2000;
 use rand::Rng;
 use std::ops::Range;
 
-/// Configuration for Poisson-disk sampling in Lch space.
+/// Color space in which Poisson-disk candidates are generated and range-checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PoissonSpace {
+    /// Uniform in L/a/b, bounded by `l_range`, `a_range`, and `b_range`.
+    Lab,
+    /// Uniform in L/C/h, bounded by `l_range`, `c_range`, and `h_range`.
+    #[default]
+    Lch,
+}
+
+/// Configuration for Poisson-disk sampling in Lab or Lch space.
+///
+/// Only the ranges belonging to the selected `space` are consulted; `l_range` applies to both.
 #[derive(Debug, Clone)]
 pub struct PoissonConfig {
     pub radius: f32,
     pub k: usize,
+    pub space: PoissonSpace,
     pub l_range: Range<f32>,
     pub c_range: Range<f32>,
     pub h_range: Range<f32>,
+    pub a_range: Range<f32>,
+    pub b_range: Range<f32>,
 }
 
 impl Default for PoissonConfig {
     fn default() -> Self {
-        Self { radius: 10.0, k: 30, l_range: 0.0..100.0, c_range: 0.0..120.0, h_range: 0.0..360.0 }
+        Self {
+            radius: 10.0,
+            k: 30,
+            space: PoissonSpace::Lch,
+            l_range: 0.0..100.0,
+            c_range: 0.0..120.0,
+            h_range: 0.0..360.0,
+            a_range: -128.0..128.0,
+            b_range: -128.0..128.0,
+        }
     }
 }
 
@@ -24,43 +48,45 @@ pub fn distance_lab(a: Lab, b: Lab) -> f32 {
     delta_e_2000(a, b)
 }
 
-/// Generates a palette using Poisson-disk sampling in Lch space.
+/// Generates a palette using Poisson-disk sampling in the configured space.
 pub fn poisson_palette(config: PoissonConfig, max_samples: usize) -> Vec<Rgb> {
+    poisson_samples(&config, max_samples)
+        .into_iter()
+        .map(|lab| Rgb::from(Srgb8::from(lab)))
+        .collect()
+}
+
+fn poisson_samples(config: &PoissonConfig, max_samples: usize) -> Vec<Lab> {
     if max_samples == 0 {
         return Vec::new();
     }
 
     let mut rng = rand::rng();
     let mut samples_lab: Vec<Lab> = Vec::new();
-    let mut result: Vec<Rgb> = Vec::new();
     let mut active: Vec<usize> = Vec::new();
 
-    if let Some((lab, rgb)) = random_point(&mut rng, &config) {
+    if let Some(lab) = random_point(&mut rng, config) {
         samples_lab.push(lab);
-        result.push(rgb);
         active.push(0);
     } else {
         return Vec::new();
     }
 
-    while !active.is_empty() && result.len() < max_samples {
+    while !active.is_empty() && samples_lab.len() < max_samples {
         let idx = rng.random_range(0..active.len());
         let sample_index = active[idx];
         let mut found = false;
 
         for _ in 0..config.k {
-            if let Some(candidate_lab) = random_candidate_near(&mut rng, samples_lab[sample_index], &config) {
-                if samples_lab
+            if let Some(candidate_lab) = random_candidate_near(&mut rng, samples_lab[sample_index], config)
+                && samples_lab
                     .iter()
                     .all(|&lab| distance_lab(lab, candidate_lab) >= config.radius)
-                {
-                    let rgb = Srgb8::from(candidate_lab);
-                    samples_lab.push(candidate_lab);
-                    result.push(Rgb::from(rgb));
-                    active.push(samples_lab.len() - 1);
-                    found = true;
-                    break;
-                }
+            {
+                samples_lab.push(candidate_lab);
+                active.push(samples_lab.len() - 1);
+                found = true;
+                break;
             }
         }
 
@@ -68,25 +94,36 @@ pub fn poisson_palette(config: PoissonConfig, max_samples: usize) -> Vec<Rgb> {
             active.swap_remove(idx);
         }
 
-        if result.len() >= max_samples {
+        if samples_lab.len() >= max_samples {
             break;
         }
     }
 
-    result
+    samples_lab
 }
 
-fn random_point(rng: &mut impl Rng, config: &PoissonConfig) -> Option<(Lab, Rgb)> {
-    if !valid_range(&config.l_range) || !valid_range(&config.c_range) || !valid_range(&config.h_range) {
-        return None;
+fn random_point(rng: &mut impl Rng, config: &PoissonConfig) -> Option<Lab> {
+    let l_range = &config.l_range;
+    match config.space {
+        PoissonSpace::Lab => {
+            if !valid_range(l_range) || !valid_range(&config.a_range) || !valid_range(&config.b_range) {
+                return None;
+            }
+            let l = rng.random_range(l_range.start..l_range.end);
+            let a = rng.random_range(config.a_range.start..config.a_range.end);
+            let b = rng.random_range(config.b_range.start..config.b_range.end);
+            Some(Lab::new(l, a, b))
+        }
+        PoissonSpace::Lch => {
+            if !valid_range(l_range) || !valid_range(&config.c_range) || !valid_range(&config.h_range) {
+                return None;
+            }
+            let l = rng.random_range(l_range.start..l_range.end);
+            let c = rng.random_range(config.c_range.start..config.c_range.end);
+            let h = rng.random_range(config.h_range.start..config.h_range.end);
+            Some(Lab::from(Lch::new(l, c, h)))
+        }
     }
-    let l = rng.random_range(config.l_range.start..config.l_range.end);
-    let c = rng.random_range(config.c_range.start..config.c_range.end);
-    let h = rng.random_range(config.h_range.start..config.h_range.end);
-    let lch = Lch::new(l, c, h);
-    let lab = Lab::from(lch);
-    let rgb = Rgb::from(Srgb8::from(lab));
-    Some((lab, rgb))
 }
 
 fn random_candidate_near(rng: &mut impl Rng, base: Lab, config: &PoissonConfig) -> Option<Lab> {
@@ -103,23 +140,31 @@ fn random_candidate_near(rng: &mut impl Rng, base: Lab, config: &PoissonConfig)
     let db = dist * u;
 
     let candidate = Lab::new(base.l + dl, base.a + da, base.b + db);
-    let lch = Lch::from(candidate);
+    in_ranges(candidate, config).then_some(candidate)
+}
 
-    if !config.l_range.contains(&lch.l) || !config.c_range.contains(&lch.c) {
-        return None;
-    }
+fn in_ranges(candidate: Lab, config: &PoissonConfig) -> bool {
+    match config.space {
+        PoissonSpace::Lab => {
+            config.l_range.contains(&candidate.l)
+                && config.a_range.contains(&candidate.a)
+                && config.b_range.contains(&candidate.b)
+        }
+        PoissonSpace::Lch => {
+            let lch = Lch::from(candidate);
+            if !config.l_range.contains(&lch.l) || !config.c_range.contains(&lch.c) {
+                return false;
+            }
 
-    let mut hue = lch.h;
-    if hue < config.h_range.start {
-        hue += 360.0;
-    } else if hue > config.h_range.end {
-        hue -= 360.0;
-    }
-    if !config.h_range.contains(&hue) {
-        return None;
+            let mut hue = lch.h;
+            if hue < config.h_range.start {
+                hue += 360.0;
+            } else if hue > config.h_range.end {
+                hue -= 360.0;
+            }
+            config.h_range.contains(&hue)
+        }
     }
-
-    Some(candidate)
 }
 
 fn valid_range(range: &Range<f32>) -> bool {
@@ -137,4 +182,27 @@ mod tests {
         assert!(!palette.is_empty());
         assert!(palette.len() <= 5);
     }
+
+    #[test]
+    fn lab_space_respects_radius_and_ranges() {
+        let config = PoissonConfig {
+            radius: 8.0,
+            space: PoissonSpace::Lab,
+            l_range: 40.0..70.0,
+            a_range: -20.0..30.0,
+            b_range: 0.0..25.0,
+            ..Default::default()
+        };
+        let samples = poisson_samples(&config, 20);
+        assert!(samples.len() > 1);
+
+        for (i, &lab) in samples.iter().enumerate() {
+            assert!(config.l_range.contains(&lab.l));
+            assert!(config.a_range.contains(&lab.a));
+            assert!(config.b_range.contains(&lab.b));
+            for &other in &samples[i + 1..] {
+                assert!(distance_lab(lab, other) >= config.radius);
+            }
+        }
+    }
 }
@@ -1,9 +1,11 @@
 //! Tints, shades, and tones generation for color manipulation.
 //!
 //! Provides functions to create color variations by mixing with white (tints), black (shades), or gray (tones).
-//! Also includes HSL-based convenience functions for lightening, darkening, and desaturating colors.
+//! Also includes HSL-based convenience functions for lightening, darkening, and desaturating colors,
+//! and Lab/Lch mixing for perceptually even blends.
 
-use crate::colors::{Hsl, Rgb, clamp01};
+use crate::colors::{Hsl, Lab, Lch, Rgb, Srgb8, clamp01};
+use crate::interpolation::{lerp_lab, lerp_lch};
 
 /// Mixes two RGB colors using linear interpolation.
 ///
@@ -36,6 +38,43 @@ pub fn mix_rgb(a: Rgb, b: Rgb, t: f32) -> Rgb {
     Rgb::new(a.r + (b.r - a.r) * t, a.g + (b.g - a.g) * t, a.b + (b.b - a.b) * t)
 }
 
+/// Blends any number of RGB colors as a weighted average.
+///
+/// Weights are normalized to sum to 1, so `[(a, 3.0), (b, 1.0)]` matches `mix_rgb(a, b, 0.25)`. Negative weights count as
+/// zero; if no weight is positive the colors are averaged equally. An empty slice returns black.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Rgb;
+/// use colorizer::shades::mix_many;
+///
+/// let red = Rgb::new(1.0, 0.0, 0.0);
+/// let blue = Rgb::new(0.0, 0.0, 1.0);
+/// let mostly_red = mix_many(&[(red, 3.0), (blue, 1.0)]);
+/// assert!((mostly_red.r - 0.75).abs() < 1e-6 && (mostly_red.b - 0.25).abs() < 1e-6);
+/// ```
+pub fn mix_many(colors: &[(Rgb, f32)]) -> Rgb {
+    let (mut r, mut g, mut b) = (0.0, 0.0, 0.0);
+    for (&(color, _), weight) in colors.iter().zip(normalized_weights(colors)) {
+        r += color.r * weight;
+        g += color.g * weight;
+        b += color.b * weight;
+    }
+    Rgb::new(r, g, b)
+}
+
+/// Weights scaled to sum to 1, falling back to equal weights when none is positive.
+fn normalized_weights<T>(colors: &[(T, f32)]) -> Vec<f32> {
+    let weights: Vec<f32> = colors.iter().map(|&(_, weight)| weight.max(0.0)).collect();
+    let total: f32 = weights.iter().sum();
+    if total > 0.0 {
+        weights.iter().map(|weight| weight / total).collect()
+    } else {
+        vec![1.0 / colors.len() as f32; colors.len()]
+    }
+}
+
 /// Creates a tint by mixing a color with white.
 ///
 /// Tints lighten a color by blending it with white:
@@ -94,6 +133,47 @@ pub fn shade(color: Rgb, t: f32) -> Rgb {
     mix_rgb(color, black, t)
 }
 
+/// Generates `n` tints of a color, ordered from lightest to closest to the base.
+///
+/// Tint amounts are evenly spaced at `i / (n + 1)`, so the ramp approaches white without reaching it and never
+/// repeats the base color itself.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Rgb;
+/// use colorizer::shades::tint_ramp;
+///
+/// let ramp = tint_ramp(Rgb::new(0.0, 0.0, 1.0), 3);
+/// assert_eq!(ramp.len(), 3);
+/// assert!(ramp[0].r > ramp[2].r);
+/// ```
+pub fn tint_ramp(color: Rgb, n: usize) -> Vec<Rgb> {
+    (1..=n).rev().map(|i| tint(color, i as f32 / (n + 1) as f32)).collect()
+}
+
+/// Generates `n` shades of a color, ordered from closest to the base to darkest.
+///
+/// Shade amounts are evenly spaced at `i / (n + 1)`, so the ramp approaches black without reaching it.
+pub fn shade_ramp(color: Rgb, n: usize) -> Vec<Rgb> {
+    (1..=n).map(|i| shade(color, i as f32 / (n + 1) as f32)).collect()
+}
+
+/// Builds an `n`-step color scale (like Tailwind's 50-900) running from a light tint through the base to a dark shade.
+///
+/// The base sits in the middle, with any odd step going to the shades.
+pub fn scale(color: Rgb, n: usize) -> Vec<Rgb> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let tints = (n - 1) / 2;
+    let mut colors = tint_ramp(color, tints);
+    colors.push(color);
+    colors.extend(shade_ramp(color, n - 1 - tints));
+    colors
+}
+
 /// Creates a tone by mixing a color with a gray value.
 ///
 /// Tones reduce the intensity/saturation of a color by blending it with gray:
@@ -198,12 +278,120 @@ pub fn desaturate_hsl(color: Hsl, amount: f32) -> Hsl {
     Hsl::new(color.h, clamp01(color.s - amount), color.l)
 }
 
+/// Mixes two colors in CIE Lab space.
+///
+/// Lightness changes evenly between the endpoints: the midpoint's L* sits halfway between theirs, whereas a linear-RGB
+/// blend like [`mix_rgb`] skews toward the lighter color. `t` is clamped to [0, 1]; out-of-gamut results are clipped to
+/// sRGB.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::shades::mix_lab;
+///
+/// let red = Srgb8::new(255, 0, 0);
+/// let green = Srgb8::new(0, 255, 0);
+/// assert_eq!(mix_lab(red, green, 0.0), red);
+/// assert_eq!(mix_lab(red, green, 1.0), green);
+/// ```
+pub fn mix_lab(a: Srgb8, b: Srgb8, t: f32) -> Srgb8 {
+    Srgb8::from(lerp_lab(Lab::from(a), Lab::from(b), t))
+}
+
+/// Mixes two colors in Lch space, rotating hue along the shortest path around the wheel.
+///
+/// Keeps chroma up through the blend where [`mix_lab`] passes near gray, which suits hue ramps between accents.
+pub fn mix_lch(a: Srgb8, b: Srgb8, t: f32) -> Srgb8 {
+    let a = Lch::from(Lab::from(a));
+    let b = Lch::from(Lab::from(b));
+    Srgb8::from(Lab::from(lerp_lch(a, b, t)))
+}
+
+/// Blends any number of colors as a weighted average in Lab space.
+///
+/// Perceptual counterpart of [`mix_many`], with the same weight normalization; averaging in Lab weights lightness
+/// evenly, where averaging linear RGB skews toward the lightest colors.
+pub fn mix_many_lab(colors: &[(Srgb8, f32)]) -> Srgb8 {
+    let (mut l, mut a, mut b) = (0.0, 0.0, 0.0);
+    for (&(color, _), weight) in colors.iter().zip(normalized_weights(colors)) {
+        let lab = Lab::from(color);
+        l += lab.l * weight;
+        a += lab.a * weight;
+        b += lab.b * weight;
+    }
+    Srgb8::from(Lab::new(l, a, b))
+}
+
+/// Creates a tint by mixing a color with white in Lab space.
+///
+/// Perceptual counterpart of [`tint`]: equal steps of `t` produce equal steps in perceived lightness.
+pub fn tint_lab(color: Srgb8, t: f32) -> Srgb8 {
+    mix_lab(color, Srgb8::new(255, 255, 255), t)
+}
+
+/// Creates a shade by mixing a color with black in Lab space.
+///
+/// Perceptual counterpart of [`shade`].
+pub fn shade_lab(color: Srgb8, t: f32) -> Srgb8 {
+    mix_lab(color, Srgb8::new(0, 0, 0), t)
+}
+
+/// Creates a tone by mixing a color with a neutral gray in Lab space.
+///
+/// Perceptual counterpart of [`tone`]; `gray` is the gray's Lab lightness as a fraction in [0, 1] (0.5 = L 50).
+pub fn tone_lab(color: Srgb8, t: f32, gray: f32) -> Srgb8 {
+    let target = Lab::new(clamp01(gray) * 100.0, 0.0, 0.0);
+    Srgb8::from(lerp_lab(Lab::from(color), target, t))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn scale_runs_from_tint_through_base_to_shade() {
+        let base = Rgb::from(Srgb8::new(0x3b, 0x82, 0xf6));
+        let colors = scale(base, 10);
+        assert_eq!(colors.len(), 10);
+        assert_eq!(colors[4], base);
+
+        let lightness: Vec<f32> = colors.iter().map(|&c| Lab::from(Srgb8::from(c)).l).collect();
+        assert!(lightness.windows(2).all(|pair| pair[0] > pair[1]), "{lightness:?}");
+        assert!(lightness[0] > 85.0, "lightest step L {}", lightness[0]);
+        assert!(lightness[9] < 25.0, "darkest step L {}", lightness[9]);
+
+        assert_eq!(tint_ramp(base, 3).len(), 3);
+        assert_eq!(shade_ramp(base, 0), Vec::new());
+        assert_eq!(scale(base, 1), vec![base]);
+    }
+
     const EPSILON: f32 = 0.001;
 
+    #[test]
+    fn mix_many_averages_with_normalized_weights() {
+        let red = Rgb::new(1.0, 0.0, 0.0);
+        let green = Rgb::new(0.0, 1.0, 0.0);
+        let blue = Rgb::new(0.0, 0.0, 1.0);
+
+        let gray = mix_many(&[(red, 1.0), (green, 1.0), (blue, 1.0)]);
+        assert!(approx_eq(gray.r, 1.0 / 3.0) && approx_eq(gray.g, 1.0 / 3.0) && approx_eq(gray.b, 1.0 / 3.0));
+
+        let weighted = mix_many(&[(red, 6.0), (blue, 2.0)]);
+        let expected = mix_rgb(red, blue, 0.25);
+        assert!(approx_eq(weighted.r, expected.r) && approx_eq(weighted.b, expected.b));
+        assert_eq!(mix_many(&[(red, 0.0), (blue, 0.0)]), mix_rgb(red, blue, 0.5));
+
+        let single = Rgb::new(0.2, 0.4, 0.6);
+        assert_eq!(mix_many(&[(single, 2.5)]), single);
+
+        let teal = Srgb8::new(0x0f, 0x76, 0x6e);
+        assert_eq!(mix_many_lab(&[(teal, 0.7)]), teal);
+        let white = Srgb8::new(255, 255, 255);
+        let black = Srgb8::new(0, 0, 0);
+        assert_eq!(mix_many_lab(&[(white, 1.0), (black, 1.0)]), mix_lab(white, black, 0.5));
+    }
+
     fn approx_eq(a: f32, b: f32) -> bool {
         (a - b).abs() < EPSILON
     }
@@ -235,6 +423,54 @@ mod tests {
         assert!(approx_eq(result.b, 0.5));
     }
 
+    #[test]
+    fn test_mix_lab_midpoint_lightness_is_even() {
+        let red = Srgb8::new(255, 0, 0);
+        let green = Srgb8::new(0, 255, 0);
+        let halfway = (Lab::from(red).l + Lab::from(green).l) / 2.0;
+
+        let perceptual = Lab::from(mix_lab(red, green, 0.5));
+        let linear = Lab::from(Srgb8::from(mix_rgb(Rgb::from(red), Rgb::from(green), 0.5)));
+
+        assert!(
+            (perceptual.l - halfway).abs() < 0.5,
+            "Lab mix L {} should sit at {halfway}",
+            perceptual.l
+        );
+        assert!(
+            linear.l - halfway > 2.0,
+            "linear RGB mix L {} should skew toward the lighter endpoint past {halfway}",
+            linear.l
+        );
+        assert_eq!(mix_lab(red, green, 0.0), red);
+        assert_eq!(mix_lab(red, green, 1.0), green);
+    }
+
+    #[test]
+    fn test_mix_lch_keeps_chroma() {
+        let red = Srgb8::new(220, 50, 47);
+        let blue = Srgb8::new(38, 139, 210);
+
+        let lch_mid = Lch::from(Lab::from(mix_lch(red, blue, 0.5)));
+        let lab_mid = Lch::from(Lab::from(mix_lab(red, blue, 0.5)));
+        assert!(lch_mid.c > lab_mid.c);
+    }
+
+    #[test]
+    fn test_lab_tint_shade_tone_endpoints() {
+        let color = Srgb8::new(38, 139, 210);
+
+        assert_eq!(tint_lab(color, 1.0), Srgb8::new(255, 255, 255));
+        assert_eq!(shade_lab(color, 1.0), Srgb8::new(0, 0, 0));
+        assert_eq!(tint_lab(color, 0.0), color);
+        assert!(Lab::from(tint_lab(color, 0.3)).l > Lab::from(color).l);
+        assert!(Lab::from(shade_lab(color, 0.3)).l < Lab::from(color).l);
+
+        let toned = Lch::from(Lab::from(tone_lab(color, 1.0, 0.5)));
+        assert!((toned.l - 50.0).abs() < 1.0);
+        assert!(toned.c < 1.0);
+    }
+
     #[test]
     fn test_mix_rgb_clamping() {
         let red = Rgb::new(1.0, 0.0, 0.0);
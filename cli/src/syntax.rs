@@ -1,17 +1,24 @@
 //! Syntax highlighting and terminal color display utilities.
 //!
 //! Integrates [syntect] for code highlighting and [owo_colors] for terminal output.
-//! Maps Base16/Base24 color schemes to syntax highlight themes and renders syntax-highlighted code to the terminal using truecolor ANSI escapes.
+//! Maps Base16/Base24 color schemes to syntax highlight themes and renders syntax-highlighted code to the terminal using truecolor ANSI escapes
+//! (or xterm 256-color escapes via [`ColorDepth::Xterm256`]).
 
-use crate::colors::Srgb8;
-use crate::tinted_theming::{Base16Scheme, Base24Scheme};
+use crate::ansi::to_xterm256;
+use crate::colors::{Lab, Srgb8};
+use crate::diffs::delta_e_2000;
+use crate::tinted_theming::{Base16Scheme, Base24Scheme, SchemeMetadata};
+use crate::wcag::{WCAG_AA_NORMAL, best_foreground, black_or_white, contrast_ratio};
 
+use image::RgbImage;
 use owo_colors::OwoColorize;
 use std::io::{self, BufRead};
+use std::path::Path;
 use std::str::FromStr;
+use std::sync::OnceLock;
 use syntect::easy::HighlightLines;
-use syntect::highlighting::{Color, FontStyle, ScopeSelectors, Style as SyntectStyle, Theme};
-use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::highlighting::{Color, FontStyle, Highlighter, ScopeSelectors, Style as SyntectStyle, Theme};
+use syntect::parsing::{Scope, SyntaxReference, SyntaxSet};
 use syntect::util::LinesWithEndings;
 
 const PANEL_BORDER_COLOR: (u8, u8, u8) = (100, 100, 100);
@@ -28,22 +35,197 @@ pub fn display_palette_in_terminal(colors: &[Srgb8], labels: Option<&[String]>)
             .map(|s: &String| s.as_str())
             .unwrap_or("");
 
-        let (fg_r, fg_g, fg_b) = if is_light(color) { (0, 0, 0) } else { (255, 255, 255) };
+        let text = black_or_white(color);
+        let ratio = contrast_ratio(color, text);
 
-        let block = format!("████████████  {:<10} {}", label, color.to_hex());
+        let block = format!("████████████  {:<10} {} {:>6.2}:1", label, color.to_hex(), ratio);
         println!(
             "{}",
             block
                 .on_truecolor(color.r, color.g, color.b)
-                .truecolor(fg_r, fg_g, fg_b)
+                .truecolor(text.r, text.g, text.b)
         );
     }
 }
 
-/// Determines if a color is "light" using a simple luminance heuristic.
-fn is_light(color: Srgb8) -> bool {
-    let luminance = 0.299 * color.r as f32 + 0.587 * color.g as f32 + 0.114 * color.b as f32;
-    luminance > 127.5
+/// Terminal width assumed when stdout is not a terminal (e.g. piped output).
+const FALLBACK_TERMINAL_WIDTH: usize = 80;
+
+/// Returns the `(rows, columns)` needed to lay out `count` swatches at most `columns` wide.
+///
+/// Columns never exceed the number of swatches and are at least one, so an empty palette yields zero rows.
+pub fn grid_dimensions(count: usize, columns: usize) -> (usize, usize) {
+    let columns = columns.min(count).max(1);
+    (count.div_ceil(columns), columns)
+}
+
+/// Displays a palette as a grid of colored blocks, several swatches per line.
+///
+/// Each cell shows a compact label (the hex code when no label is given) on its own color. When `columns` is
+/// `None` the grid fills the current terminal width, falling back to 80 columns when stdout is not a terminal.
+pub fn display_palette_grid(colors: &[Srgb8], labels: Option<&[String]>, columns: Option<usize>) {
+    print!("{}", render_palette_grid(colors, labels, columns));
+}
+
+/// Renders the grid printed by [`display_palette_grid`] as a string of truecolor ANSI escapes.
+pub fn render_palette_grid(colors: &[Srgb8], labels: Option<&[String]>, columns: Option<usize>) -> String {
+    let label_width = labels
+        .map(|l| {
+            l.iter()
+                .take(colors.len())
+                .map(|s| s.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0);
+    let content_width = if label_width == 0 { 7 } else { label_width + 8 };
+    let columns = columns.unwrap_or_else(|| {
+        let width = terminal_size::terminal_size()
+            .map(|(terminal_size::Width(w), _)| w as usize)
+            .unwrap_or(FALLBACK_TERMINAL_WIDTH);
+        (width + 1) / (content_width + 3)
+    });
+    let (_, columns) = grid_dimensions(colors.len(), columns);
+
+    let mut output = String::new();
+    for (row, chunk) in colors.chunks(columns).enumerate() {
+        let cells: Vec<String> = chunk
+            .iter()
+            .enumerate()
+            .map(|(col, &color)| {
+                let text = black_or_white(color);
+                let content = match labels.and_then(|l| l.get(row * columns + col)) {
+                    Some(label) => format!("{label:<label_width$} {}", color.to_hex()),
+                    None => format!("{:<content_width$}", color.to_hex()),
+                };
+                format!(" {content} ")
+                    .on_truecolor(color.r, color.g, color.b)
+                    .truecolor(text.r, text.g, text.b)
+                    .to_string()
+            })
+            .collect();
+        output.push_str(&cells.join(" "));
+        output.push('\n');
+    }
+    output
+}
+
+/// Renders an image as truecolor half-block characters for inline terminal previews.
+///
+/// Each character cell shows two vertically stacked pixels (`▀` with the top pixel as foreground and the bottom as
+/// background). Images wider than `max_cols` are downscaled with nearest-neighbor sampling, keeping the aspect
+/// ratio, so the output has `ceil(scaled_height / 2)` lines.
+pub fn render_image_halfblocks(img: &RgbImage, max_cols: u32) -> String {
+    if img.width() == 0 || img.height() == 0 || max_cols == 0 {
+        return String::new();
+    }
+
+    let cols = img.width().min(max_cols);
+    let rows = ((img.height() as u64 * cols as u64 + img.width() as u64 / 2) / img.width() as u64).max(1) as u32;
+    let sample = |x: u32, y: u32| {
+        let pixel = img.get_pixel(x * img.width() / cols, y * img.height() / rows);
+        (pixel[0], pixel[1], pixel[2])
+    };
+
+    let mut output = String::new();
+    for y in (0..rows).step_by(2) {
+        for x in 0..cols {
+            output.push_str(&ansi_fg(sample(x, y), ColorDepth::TrueColor));
+            if y + 1 < rows {
+                output.push_str(&ansi_bg(sample(x, y + 1), ColorDepth::TrueColor));
+            } else {
+                output.push_str("\x1b[49m");
+            }
+            output.push('▀');
+        }
+        output.push_str("\x1b[0m\n");
+    }
+    output
+}
+
+/// Font styles applied to highlighted scopes in generated themes.
+///
+/// The defaults follow common Base16 template conventions: italic comments and underlined links, with regular-weight keywords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StyleOptions {
+    pub italic_comments: bool,
+    pub bold_keywords: bool,
+    pub underline_links: bool,
+}
+
+impl Default for StyleOptions {
+    fn default() -> Self {
+        Self { italic_comments: true, bold_keywords: false, underline_links: true }
+    }
+}
+
+impl StyleOptions {
+    /// No font styles at all; every scope is rendered with color only.
+    pub fn plain() -> Self {
+        Self { italic_comments: false, bold_keywords: false, underline_links: false }
+    }
+
+    /// Font style for one of the [`SCOPE_SLOTS`] scopes.
+    pub(crate) fn scope_style(&self, scope: &str) -> FontStyle {
+        match scope {
+            "comment" => self.comment_style(),
+            "keyword" => self.keyword_style(),
+            "markup.underline.link" => self.link_style(),
+            _ => FontStyle::empty(),
+        }
+    }
+
+    fn comment_style(&self) -> FontStyle {
+        if self.italic_comments { FontStyle::ITALIC } else { FontStyle::empty() }
+    }
+
+    fn keyword_style(&self) -> FontStyle {
+        if self.bold_keywords { FontStyle::BOLD } else { FontStyle::empty() }
+    }
+
+    fn link_style(&self) -> FontStyle {
+        if self.underline_links { FontStyle::UNDERLINE } else { FontStyle::empty() }
+    }
+}
+
+/// TextMate scopes and the Base16 slot that colors each, shared by syntect themes and editor theme exporters.
+///
+/// The empty scope is the default text color.
+pub(crate) const SCOPE_SLOTS: [(&str, usize); 24] = [
+    ("", 0x05),
+    ("comment", 0x03),
+    ("keyword", 0x0E),
+    ("storage", 0x0E),
+    ("string", 0x0B),
+    ("entity.name.function", 0x0D),
+    ("support.function", 0x0D),
+    ("entity.name.class", 0x0A),
+    ("entity.name.type", 0x0A),
+    ("support.type", 0x0A),
+    ("variable", 0x08),
+    ("entity.name.tag", 0x08),
+    ("constant.numeric", 0x09),
+    ("constant.language", 0x09),
+    ("constant.character", 0x09),
+    ("support", 0x0C),
+    ("string.regexp", 0x0C),
+    ("keyword.operator", 0x05),
+    ("invalid.deprecated", 0x0F),
+    ("markup.underline.link", 0x0C),
+    ("markup.inserted", 0x0B),
+    ("markup.deleted", 0x08),
+    ("markup.heading", 0x0D),
+    ("meta.diff", 0x0D),
+];
+
+/// Escape sequences used when rendering highlighted code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// 24-bit `38;2;R;G;B` escapes.
+    #[default]
+    TrueColor,
+    /// `38;5;N` escapes using the nearest xterm 256-color index.
+    Xterm256,
 }
 
 /// Converts a Base16 scheme to a syntect Theme.
@@ -59,75 +241,70 @@ fn is_light(color: Srgb8) -> bool {
 /// - base0D: functions (blue)
 /// - base0E: keywords (magenta)
 /// - base0F: deprecated (brown)
+///
+/// Markup and diff scopes follow the same hues: `markup.inserted` uses base0B, `markup.deleted` base08, and
+/// `markup.heading` and `meta.diff` base0D.
 pub fn base16_to_theme(scheme: &Base16Scheme) -> Theme {
-    let colors = scheme.colors();
+    base16_to_theme_with(scheme, StyleOptions::default())
+}
 
-    Theme {
-        name: Some(scheme.metadata.name.clone()),
-        author: scheme.metadata.author.clone(),
-        settings: syntect::highlighting::ThemeSettings {
-            foreground: Some(to_syntect_color(colors[5])),
-            background: Some(to_syntect_color(colors[0])),
-            caret: Some(to_syntect_color(colors[5])),
-            line_highlight: Some(to_syntect_color(colors[1])),
-            misspelling: None,
-            minimap_border: None,
-            accent: None,
-            popup_css: None,
-            phantom_css: None,
-            bracket_contents_foreground: None,
-            bracket_contents_options: None,
-            brackets_foreground: None,
-            brackets_background: None,
-            brackets_options: None,
-            tags_foreground: None,
-            tags_options: None,
-            highlight: None,
-            find_highlight: None,
-            find_highlight_foreground: None,
-            gutter: None,
-            gutter_foreground: Some(to_syntect_color(colors[3])),
-            selection: Some(to_syntect_color(colors[2])),
-            selection_foreground: None,
-            selection_border: None,
-            inactive_selection: None,
-            inactive_selection_foreground: None,
-            guide: None,
-            active_guide: None,
-            stack_guide: None,
-            shadow: None,
-        },
-        scopes: vec![
-            scope_item("", colors[5], FontStyle::empty()),
-            scope_item("comment", colors[3], FontStyle::empty()),
-            scope_item("keyword", colors[14], FontStyle::empty()),
-            scope_item("storage", colors[14], FontStyle::empty()),
-            scope_item("string", colors[11], FontStyle::empty()),
-            scope_item("entity.name.function", colors[13], FontStyle::empty()),
-            scope_item("support.function", colors[13], FontStyle::empty()),
-            scope_item("entity.name.class", colors[10], FontStyle::empty()),
-            scope_item("entity.name.type", colors[10], FontStyle::empty()),
-            scope_item("support.type", colors[10], FontStyle::empty()),
-            scope_item("variable", colors[8], FontStyle::empty()),
-            scope_item("entity.name.tag", colors[8], FontStyle::empty()),
-            scope_item("constant.numeric", colors[9], FontStyle::empty()),
-            scope_item("constant.language", colors[9], FontStyle::empty()),
-            scope_item("constant.character", colors[9], FontStyle::empty()),
-            scope_item("support", colors[12], FontStyle::empty()),
-            scope_item("string.regexp", colors[12], FontStyle::empty()),
-            scope_item("keyword.operator", colors[5], FontStyle::empty()),
-            scope_item("invalid.deprecated", colors[15], FontStyle::empty()),
-        ],
-    }
+/// Converts a Base16 scheme to a syntect [Theme] using the given font styles.
+pub fn base16_to_theme_with(scheme: &Base16Scheme, options: StyleOptions) -> Theme {
+    build_theme(&scheme.metadata, scheme.colors(), options, &[])
+}
+
+/// Converts a Base16 scheme to a syntect [Theme], recoloring scopes with `(scope, slot)` overrides.
+///
+/// An override replaces the slot of a built-in scope with the same selector, or adds a new scope item otherwise.
+/// Overrides with an invalid selector or a slot outside the scheme are skipped.
+pub fn base16_to_theme_with_scopes(
+    scheme: &Base16Scheme, options: StyleOptions, overrides: &[(String, usize)],
+) -> Theme {
+    build_theme(&scheme.metadata, scheme.colors(), options, overrides)
 }
 
 /// Converts a Base24 scheme to a syntect [Theme].
+///
+/// Syntax scopes only use the Base16 slots, so this matches [`base16_to_theme`] on the first 16 colors.
 pub fn base24_to_theme(scheme: &Base24Scheme) -> Theme {
-    let colors = scheme.colors();
+    base24_to_theme_with(scheme, StyleOptions::default())
+}
+
+/// Converts a Base24 scheme to a syntect [Theme] using the given font styles.
+pub fn base24_to_theme_with(scheme: &Base24Scheme, options: StyleOptions) -> Theme {
+    build_theme(&scheme.metadata, scheme.colors(), options, &[])
+}
+
+/// Converts a Base24 scheme to a syntect [Theme] with scope overrides; see [`base16_to_theme_with_scopes`].
+///
+/// Overrides may use the Base24 slots (`0x10`–`0x17`).
+pub fn base24_to_theme_with_scopes(
+    scheme: &Base24Scheme, options: StyleOptions, overrides: &[(String, usize)],
+) -> Theme {
+    build_theme(&scheme.metadata, scheme.colors(), options, overrides)
+}
+
+fn build_theme(
+    metadata: &SchemeMetadata, colors: &[Srgb8], options: StyleOptions, overrides: &[(String, usize)],
+) -> Theme {
+    let mut scopes: Vec<_> = SCOPE_SLOTS
+        .iter()
+        .map(|&(scope, slot)| scope_item(scope, colors[slot], options.scope_style(scope)))
+        .collect();
+    for (scope, slot) in overrides {
+        let (Ok(selector), Some(&color)) = (ScopeSelectors::from_str(scope), colors.get(*slot)) else {
+            continue;
+        };
+        let item = scope_item(scope, color, options.scope_style(scope));
+        match scopes.iter_mut().find(|existing| existing.scope == selector) {
+            Some(existing) => *existing = item,
+            None => scopes.push(item),
+        }
+    }
 
     Theme {
-        name: Some(scheme.metadata.name.clone()),
-        author: scheme.metadata.author.clone(),
+        name: Some(metadata.name.clone()),
+        author: metadata.author.clone(),
         settings: syntect::highlighting::ThemeSettings {
             foreground: Some(to_syntect_color(colors[5])),
             background: Some(to_syntect_color(colors[0])),
@@ -160,27 +337,7 @@ pub fn base24_to_theme(scheme: &Base24Scheme) -> Theme {
             stack_guide: None,
             shadow: None,
         },
-        scopes: vec![
-            scope_item("", colors[5], FontStyle::empty()),
-            scope_item("comment", colors[3], FontStyle::empty()),
-            scope_item("keyword", colors[14], FontStyle::empty()),
-            scope_item("storage", colors[14], FontStyle::empty()),
-            scope_item("string", colors[11], FontStyle::empty()),
-            scope_item("entity.name.function", colors[13], FontStyle::empty()),
-            scope_item("support.function", colors[13], FontStyle::empty()),
-            scope_item("entity.name.class", colors[10], FontStyle::empty()),
-            scope_item("entity.name.type", colors[10], FontStyle::empty()),
-            scope_item("support.type", colors[10], FontStyle::empty()),
-            scope_item("variable", colors[8], FontStyle::empty()),
-            scope_item("entity.name.tag", colors[8], FontStyle::empty()),
-            scope_item("constant.numeric", colors[9], FontStyle::empty()),
-            scope_item("constant.language", colors[9], FontStyle::empty()),
-            scope_item("constant.character", colors[9], FontStyle::empty()),
-            scope_item("support", colors[12], FontStyle::empty()),
-            scope_item("string.regexp", colors[12], FontStyle::empty()),
-            scope_item("keyword.operator", colors[5], FontStyle::empty()),
-            scope_item("invalid.deprecated", colors[15], FontStyle::empty()),
-        ],
+        scopes,
     }
 }
 
@@ -201,12 +358,54 @@ fn to_syntect_color(color: Srgb8) -> Color {
     Color { r: color.r, g: color.g, b: color.b, a: 255 }
 }
 
+/// Scope pairs that appear side by side in code, so readers rely on their colors to tell them apart.
+///
+/// The empty scope is the default text color.
+pub const RELATED_SCOPE_PAIRS: [(&str, &str); 10] = [
+    ("", "comment"),
+    ("keyword", "entity.name.function"),
+    ("keyword", "variable"),
+    ("entity.name.function", "string.regexp"),
+    ("entity.name.function", "support"),
+    ("string", "string.regexp"),
+    ("variable", "constant.numeric"),
+    ("entity.name.class", "constant.numeric"),
+    ("variable", "invalid.deprecated"),
+    ("markup.inserted", "markup.deleted"),
+];
+
+/// ΔE2000 below which [`check_scope_distinctness`] flags a related scope pair.
+pub const SCOPE_DISTINCT_DELTA_E: f32 = 10.0;
+
+/// Finds related scope pairs whose foreground colors are hard to distinguish.
+///
+/// Resolves each scope in [`RELATED_SCOPE_PAIRS`] against the theme and returns `(scope, scope, ΔE2000)` for the pairs
+/// closer than [`SCOPE_DISTINCT_DELTA_E`], closest first. The empty scope is reported as `"text"`.
+pub fn check_scope_distinctness(theme: &Theme) -> Vec<(String, String, f32)> {
+    let highlighter = Highlighter::new(theme);
+    let foreground = |scope: &str| {
+        let stack: Vec<Scope> = scope.split_whitespace().filter_map(|s| Scope::new(s).ok()).collect();
+        let Color { r, g, b, .. } = highlighter.style_for_stack(&stack).foreground;
+        Lab::from(Srgb8::new(r, g, b))
+    };
+    let name = |scope: &str| if scope.is_empty() { "text".to_string() } else { scope.to_string() };
+
+    let mut flagged: Vec<_> = RELATED_SCOPE_PAIRS
+        .iter()
+        .map(|&(a, b)| (name(a), name(b), delta_e_2000(foreground(a), foreground(b))))
+        .filter(|&(_, _, delta)| delta < SCOPE_DISTINCT_DELTA_E)
+        .collect();
+    flagged.sort_by(|a, b| a.2.total_cmp(&b.2));
+    flagged
+}
+
 /// Highlights source code and prints it to the terminal with colors in a bordered panel.
 ///
 /// Reads code from the provided reader, highlights it using the theme and syntax, and outputs each line with ANSI color codes to the terminal.
 /// The code is wrapped in a box with a status bar showing file and theme information.
 pub fn highlight_code_to_terminal<R: BufRead>(
     reader: R, syntax: &SyntaxReference, theme: &Theme, file_path: Option<&str>, theme_name: Option<&str>,
+    depth: ColorDepth,
 ) -> io::Result<()> {
     let mut highlighter = HighlightLines::new(syntax, theme);
     let mut highlighted_lines = Vec::new();
@@ -215,15 +414,16 @@ pub fn highlight_code_to_terminal<R: BufRead>(
     let status_bg = theme.settings.line_highlight.map(color_tuple_from_syntect);
     let status_fg = theme.settings.foreground.map(color_tuple_from_syntect);
 
+    let syntax_set = load_syntax_set();
     for line in reader.lines() {
         let line = line?;
         let line_with_newline = format!("{line}\n");
 
         let ranges = highlighter
-            .highlight_line(&line_with_newline, &load_syntax_set())
+            .highlight_line(&line_with_newline, syntax_set)
             .map_err(io::Error::other)?;
 
-        let line_str = render_highlighted_line(&ranges, panel_bg);
+        let line_str = render_highlighted_line(&ranges, panel_bg, depth);
         let visible_width = line.chars().count();
         max_width = max_width.max(visible_width);
         highlighted_lines.push((line_str, visible_width));
@@ -238,6 +438,7 @@ pub fn highlight_code_to_terminal<R: BufRead>(
         panel_bg,
         status_bg,
         status_fg,
+        depth,
     );
 
     Ok(())
@@ -245,7 +446,7 @@ pub fn highlight_code_to_terminal<R: BufRead>(
 
 /// Highlights source code from a string and prints to terminal in a bordered panel.
 pub fn highlight_string_to_terminal(
-    code: &str, syntax: &SyntaxReference, theme: &Theme, theme_name: Option<&str>,
+    code: &str, syntax: &SyntaxReference, theme: &Theme, theme_name: Option<&str>, depth: ColorDepth,
 ) -> io::Result<()> {
     let syntax_set = load_syntax_set();
     let mut highlighter = HighlightLines::new(syntax, theme);
@@ -256,11 +457,9 @@ pub fn highlight_string_to_terminal(
     let status_fg = theme.settings.foreground.map(color_tuple_from_syntect);
 
     for line in LinesWithEndings::from(code) {
-        let ranges = highlighter
-            .highlight_line(line, &syntax_set)
-            .map_err(io::Error::other)?;
+        let ranges = highlighter.highlight_line(line, syntax_set).map_err(io::Error::other)?;
 
-        let line_str = render_highlighted_line(&ranges, panel_bg);
+        let line_str = render_highlighted_line(&ranges, panel_bg, depth);
         let visible_width = line.trim_end().chars().count();
         max_width = max_width.max(visible_width);
         highlighted_lines.push((line_str, visible_width));
@@ -275,13 +474,16 @@ pub fn highlight_string_to_terminal(
         panel_bg,
         status_bg,
         status_fg,
+        depth,
     );
 
     Ok(())
 }
 
 /// Renders a highlighted line to a String with ANSI codes.
-fn render_highlighted_line(ranges: &[(SyntectStyle, &str)], panel_bg: Option<(u8, u8, u8)>) -> String {
+fn render_highlighted_line(
+    ranges: &[(SyntectStyle, &str)], panel_bg: Option<(u8, u8, u8)>, depth: ColorDepth,
+) -> String {
     let mut result = String::new();
 
     for (style, text) in ranges {
@@ -292,10 +494,10 @@ fn render_highlighted_line(ranges: &[(SyntectStyle, &str)], panel_bg: Option<(u8
         }
 
         let mut segment = String::new();
-        segment.push_str(&ansi_fg(style.foreground.r, style.foreground.g, style.foreground.b));
+        segment.push_str(&ansi_fg(color_tuple_from_syntect(style.foreground), depth));
 
-        if let Some((bg_r, bg_g, bg_b)) = style_background(style, panel_bg) {
-            segment.push_str(&ansi_bg(bg_r, bg_g, bg_b));
+        if let Some(bg) = style_background(style, panel_bg) {
+            segment.push_str(&ansi_bg(bg, depth));
         }
 
         if style.font_style.contains(FontStyle::BOLD) {
@@ -317,37 +519,37 @@ fn render_highlighted_line(ranges: &[(SyntectStyle, &str)], panel_bg: Option<(u8
 }
 
 /// Draws a bordered panel around code with a status bar at the bottom.
+#[allow(clippy::too_many_arguments)]
 fn draw_code_panel(
     lines: &[(String, usize)], max_width: usize, file_path: Option<&str>, theme_name: Option<&str>, language: &str,
     panel_bg: Option<(u8, u8, u8)>, status_bg: Option<(u8, u8, u8)>, status_fg: Option<(u8, u8, u8)>,
+    depth: ColorDepth,
 ) {
-    let panel_width = max_width.max(50).min(120);
-    let (border_r, border_g, border_b) = PANEL_BORDER_COLOR;
+    let panel_width = max_width.clamp(50, 120);
+    let border = |text: &str| paint(text, PANEL_BORDER_COLOR, None, depth);
     let top_border = format!("┌{}┐", "─".repeat(panel_width + 2));
-    println!("{}", top_border.truecolor(border_r, border_g, border_b));
+    println!("{}", border(&top_border));
 
     for (line, visible_width) in lines {
         let padding =
             if *visible_width < panel_width { " ".repeat(panel_width - visible_width) } else { String::new() };
 
-        print!("{}", "│ ".truecolor(border_r, border_g, border_b));
+        print!("{}", border("│ "));
         print!("{}", line);
 
-        if let Some((bg_r, bg_g, bg_b)) = panel_bg {
-            let padded = format!("{}", padding.on_truecolor(bg_r, bg_g, bg_b));
-            println!("{}{}", padded, " │".truecolor(border_r, border_g, border_b));
+        if let Some(bg) = panel_bg {
+            println!("{}{padding}\x1b[0m{}", ansi_bg(bg, depth), border(" │"));
         } else {
-            println!("{}{}", padding, " │".truecolor(border_r, border_g, border_b));
+            println!("{}{}", padding, border(" │"));
         }
     }
 
     let bottom_border = format!("└{}┘", "─".repeat(panel_width + 2));
-    println!("{}", bottom_border.truecolor(border_r, border_g, border_b));
+    println!("{}", border(&bottom_border));
 
-    let (status_bg_r, status_bg_g, status_bg_b) = status_bg.unwrap_or(STATUS_BAR_BG);
+    let status_bg = status_bg.unwrap_or(STATUS_BAR_BG);
     let status_fg_from_theme = status_fg.unwrap_or(STATUS_BAR_FG);
-    let (status_fg_r, status_fg_g, status_fg_b) =
-        pick_contrasting_text(status_bg.unwrap_or(STATUS_BAR_BG), status_fg_from_theme);
+    let status_fg = pick_contrasting_text(status_bg, status_fg_from_theme);
 
     let file_info = file_path.unwrap_or("stdin");
     let theme_info = theme_name.unwrap_or("custom");
@@ -362,12 +564,7 @@ fn draw_code_panel(
     };
 
     let full_status = format!("{}{}", status_text, status_padding);
-    println!(
-        "{}",
-        full_status
-            .on_truecolor(status_bg_r, status_bg_g, status_bg_b)
-            .truecolor(status_fg_r, status_fg_g, status_fg_b)
-    );
+    println!("{}", paint(&full_status, status_fg, Some(status_bg), depth));
 }
 
 fn color_tuple_from_syntect(color: Color) -> (u8, u8, u8) {
@@ -375,21 +572,32 @@ fn color_tuple_from_syntect(color: Color) -> (u8, u8, u8) {
 }
 
 fn pick_contrasting_text(bg: (u8, u8, u8), preferred: (u8, u8, u8)) -> (u8, u8, u8) {
-    if is_light(Srgb8::new(bg.0, bg.1, bg.2)) {
-        if is_light(Srgb8::new(preferred.0, preferred.1, preferred.2)) { (0, 0, 0) } else { preferred }
-    } else if !is_light(Srgb8::new(preferred.0, preferred.1, preferred.2)) {
-        (255, 255, 255)
-    } else {
-        preferred
+    let bg = Srgb8::new(bg.0, bg.1, bg.2);
+    if contrast_ratio(bg, Srgb8::new(preferred.0, preferred.1, preferred.2)) >= WCAG_AA_NORMAL {
+        return preferred;
+    }
+    let fg = best_foreground(bg, WCAG_AA_NORMAL);
+    (fg.r, fg.g, fg.b)
+}
+
+fn ansi_fg((r, g, b): (u8, u8, u8), depth: ColorDepth) -> String {
+    match depth {
+        ColorDepth::TrueColor => format!("\x1b[38;2;{r};{g};{b}m"),
+        ColorDepth::Xterm256 => format!("\x1b[38;5;{}m", to_xterm256(Srgb8::new(r, g, b))),
     }
 }
 
-fn ansi_fg(r: u8, g: u8, b: u8) -> String {
-    format!("\x1b[38;2;{r};{g};{b}m")
+fn ansi_bg((r, g, b): (u8, u8, u8), depth: ColorDepth) -> String {
+    match depth {
+        ColorDepth::TrueColor => format!("\x1b[48;2;{r};{g};{b}m"),
+        ColorDepth::Xterm256 => format!("\x1b[48;5;{}m", to_xterm256(Srgb8::new(r, g, b))),
+    }
 }
 
-fn ansi_bg(r: u8, g: u8, b: u8) -> String {
-    format!("\x1b[48;2;{r};{g};{b}m")
+/// Wraps `text` in foreground (and optional background) escapes followed by a reset.
+fn paint(text: &str, fg: (u8, u8, u8), bg: Option<(u8, u8, u8)>, depth: ColorDepth) -> String {
+    let bg = bg.map(|bg| ansi_bg(bg, depth)).unwrap_or_default();
+    format!("{}{bg}{text}\x1b[0m", ansi_fg(fg, depth))
 }
 fn style_background(style: &SyntectStyle, panel_bg: Option<(u8, u8, u8)>) -> Option<(u8, u8, u8)> {
     if style.background.a > 0 && (style.background.r != 0 || style.background.g != 0 || style.background.b != 0) {
@@ -400,8 +608,11 @@ fn style_background(style: &SyntectStyle, panel_bg: Option<(u8, u8, u8)>) -> Opt
 }
 
 /// Loads the extended syntax set with support for 100+ languages including TypeScript and Elm.
-pub fn load_syntax_set() -> SyntaxSet {
-    two_face::syntax::extra_newlines()
+///
+/// The set is built on first use and shared afterwards, so repeated highlight calls don't pay the load cost again.
+pub fn load_syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(two_face::syntax::extra_newlines)
 }
 
 /// Finds a syntax by language name (e.g., "rust", "python").
@@ -420,11 +631,62 @@ pub fn find_syntax_by_name<'a>(syntax_set: &'a SyntaxSet, name: &str) -> Option<
         })
 }
 
+/// Finds a syntax for a source file by its extension, its file name, and finally its first line.
+///
+/// The first-line fallback uses syntect's shebang/modeline detection, so extensionless scripts such as
+/// `#!/usr/bin/env python` still resolve.
+pub fn find_syntax_for_file<'a>(
+    syntax_set: &'a SyntaxSet, path: &Path, first_line: &str,
+) -> Option<&'a SyntaxReference> {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| find_syntax_by_name(syntax_set, ext))
+        .or_else(|| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| syntax_set.find_syntax_by_extension(name))
+        })
+        .or_else(|| syntax_set.find_syntax_by_first_line(first_line))
+}
+
+/// Returns an embedded code snippet for `language`, used when a demo is requested without a source file.
+///
+/// Covers rust, python, and javascript (matched case-insensitively, along with their common extensions).
+pub fn builtin_sample(language: &str) -> Option<&'static str> {
+    match language.to_ascii_lowercase().as_str() {
+        "rust" | "rs" => Some(include_str!("../samples/sample.rs")),
+        "python" | "py" => Some(include_str!("../samples/sample.py")),
+        "javascript" | "js" => Some(include_str!("../samples/sample.js")),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::tinted_theming;
 
+    #[test]
+    fn grid_dimensions_wrap_colors_into_rows() {
+        assert_eq!(grid_dimensions(24, 8), (3, 8));
+        assert_eq!(grid_dimensions(16, 6), (3, 6));
+        assert_eq!(grid_dimensions(5, 8), (1, 5));
+        assert_eq!(grid_dimensions(3, 0), (3, 1));
+        assert_eq!(grid_dimensions(0, 4), (0, 1));
+    }
+
+    #[test]
+    fn palette_grid_renders_one_line_per_row() {
+        let colors: Vec<Srgb8> = (0..10).map(|i| Srgb8::new(i * 20, 100, 200)).collect();
+        let labels: Vec<String> = (0..10).map(|i| format!("{i:02X}")).collect();
+        let grid = render_palette_grid(&colors, Some(&labels), Some(4));
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].matches(" #").count(), 4);
+        assert_eq!(lines[2].matches(" #").count(), 2);
+        assert!(lines[2].contains("09 #b464c8"));
+    }
+
     #[test]
     fn base16_theme_has_correct_colors() {
         let schemes = tinted_theming::load_base16_schemes("../examples/base16/oxocarbon-dark.yml").unwrap();
@@ -442,4 +704,174 @@ mod tests {
         let labels = vec!["Red".to_string(), "Green".to_string(), "Blue".to_string()];
         display_palette_in_terminal(&colors, Some(&labels));
     }
+
+    #[test]
+    fn builtin_samples_cover_common_languages() {
+        let rust = builtin_sample("rust").unwrap();
+        assert!(!rust.trim().is_empty());
+        assert_eq!(builtin_sample("RS"), Some(rust));
+        assert!(builtin_sample("python").is_some());
+        assert!(builtin_sample("js").is_some());
+        assert!(builtin_sample("cobol").is_none());
+    }
+
+    #[test]
+    fn builtin_samples_match_documented_examples() {
+        assert_eq!(
+            builtin_sample("rust"),
+            Some(include_str!("../../examples/languages/sample.rs"))
+        );
+        assert_eq!(
+            builtin_sample("python"),
+            Some(include_str!("../../examples/languages/sample.py"))
+        );
+        assert_eq!(
+            builtin_sample("javascript"),
+            Some(include_str!("../../examples/languages/sample.js"))
+        );
+    }
+
+    #[test]
+    fn syntax_set_is_loaded_once() {
+        assert!(std::ptr::eq(load_syntax_set(), load_syntax_set()));
+    }
+
+    #[test]
+    fn highlights_large_input_quickly() {
+        let schemes = tinted_theming::load_base16_schemes("../examples/base16/oxocarbon-dark.yml").unwrap();
+        let theme = base16_to_theme(&schemes[0]);
+        let syntax = find_syntax_by_name(load_syntax_set(), "rust").unwrap();
+        let code = "fn main() { let value = 42; println!(\"{value}\"); }\n".repeat(1000);
+
+        let start = std::time::Instant::now();
+        highlight_code_to_terminal(code.as_bytes(), syntax, &theme, None, None, ColorDepth::TrueColor).unwrap();
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+    }
+
+    #[test]
+    fn halfblocks_scale_to_max_columns() {
+        let img = RgbImage::from_fn(40, 20, |x, _| image::Rgb([if x < 20 { 255 } else { 0 }, 0, 0]));
+
+        let preview = render_image_halfblocks(&img, 20);
+        let lines: Vec<&str> = preview.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines.iter().all(|line| line.matches('▀').count() == 20));
+        assert!(lines[0].starts_with("\x1b[38;2;255;0;0m"));
+
+        let odd = render_image_halfblocks(&RgbImage::new(3, 3), 80);
+        assert_eq!(odd.lines().count(), 2);
+        assert!(odd.lines().last().unwrap().contains("\x1b[49m"));
+    }
+
+    #[test]
+    fn xterm256_depth_emits_indexed_escapes() {
+        assert_eq!(ansi_fg((255, 0, 0), ColorDepth::Xterm256), "\x1b[38;5;196m");
+        assert_eq!(ansi_bg((255, 0, 0), ColorDepth::TrueColor), "\x1b[48;2;255;0;0m");
+    }
+
+    #[test]
+    fn style_options_set_font_styles() {
+        let schemes = tinted_theming::load_base16_schemes("../examples/base16/oxocarbon-dark.yml").unwrap();
+        let style_of = |theme: &Theme, scope: &str| {
+            let selector = ScopeSelectors::from_str(scope).unwrap();
+            theme
+                .scopes
+                .iter()
+                .find(|item| item.scope == selector)
+                .and_then(|item| item.style.font_style)
+        };
+
+        let options = StyleOptions { italic_comments: true, bold_keywords: true, underline_links: false };
+        let theme = base16_to_theme_with(&schemes[0], options);
+        assert_eq!(style_of(&theme, "comment"), Some(FontStyle::ITALIC));
+        assert_eq!(style_of(&theme, "keyword"), Some(FontStyle::BOLD));
+        assert_eq!(style_of(&theme, "markup.underline.link"), Some(FontStyle::empty()));
+
+        let plain = base16_to_theme_with(&schemes[0], StyleOptions::plain());
+        assert_eq!(style_of(&plain, "comment"), Some(FontStyle::empty()));
+    }
+
+    #[test]
+    fn theme_colors_markup_scopes_and_applies_overrides() {
+        let schemes = tinted_theming::load_base16_schemes("../examples/base16/oxocarbon-dark.yml").unwrap();
+        let scheme = &schemes[0];
+        let foreground_of = |theme: &Theme, scope: &str| {
+            let selector = ScopeSelectors::from_str(scope).unwrap();
+            let items: Vec<_> = theme.scopes.iter().filter(|item| item.scope == selector).collect();
+            assert!(items.len() <= 1, "duplicate items for {scope}");
+            items.first().and_then(|item| item.style.foreground)
+        };
+
+        let theme = base16_to_theme(scheme);
+        assert_eq!(
+            foreground_of(&theme, "markup.inserted"),
+            Some(to_syntect_color(scheme.colors()[0x0B]))
+        );
+        assert_eq!(
+            foreground_of(&theme, "markup.deleted"),
+            Some(to_syntect_color(scheme.colors()[0x08]))
+        );
+
+        let overrides = vec![
+            ("markup.inserted".to_string(), 0x0C),
+            ("entity.other.attribute-name".to_string(), 0x0A),
+            ("markup.heading".to_string(), 0x20),
+        ];
+        let custom = base16_to_theme_with_scopes(scheme, StyleOptions::default(), &overrides);
+        assert_eq!(
+            foreground_of(&custom, "markup.inserted"),
+            Some(to_syntect_color(scheme.colors()[0x0C]))
+        );
+        assert_eq!(
+            foreground_of(&custom, "entity.other.attribute-name"),
+            Some(to_syntect_color(scheme.colors()[0x0A]))
+        );
+        assert_eq!(
+            foreground_of(&custom, "markup.heading"),
+            Some(to_syntect_color(scheme.colors()[0x0D]))
+        );
+    }
+
+    #[test]
+    fn scope_distinctness_flags_close_accents() {
+        let schemes = tinted_theming::load_base16_schemes("../examples/base16/oxocarbon-dark.yml").unwrap();
+        let mut colors: [Srgb8; 16] = schemes[0].colors().try_into().unwrap();
+        colors[0x0C] = Srgb8::new(0x3b, 0x82, 0xf6);
+        colors[0x0D] = Srgb8::new(0x3d, 0x86, 0xf0);
+        let scheme = Base16Scheme::new(schemes[0].metadata.clone(), colors);
+
+        let flagged = check_scope_distinctness(&base16_to_theme(&scheme));
+        let pair = flagged
+            .iter()
+            .find(|(a, b, _)| a == "entity.name.function" && b == "string.regexp")
+            .expect("cyan/blue pair should be flagged");
+        assert!(pair.2 < 3.0, "ΔE {}", pair.2);
+        assert!(
+            flagged
+                .iter()
+                .any(|(a, b, _)| a == "entity.name.function" && b == "support")
+        );
+        assert!(flagged.windows(2).all(|w| w[0].2 <= w[1].2));
+        assert!(flagged.iter().all(|&(_, _, delta)| delta < SCOPE_DISTINCT_DELTA_E));
+
+        let original = check_scope_distinctness(&base16_to_theme(&schemes[0]));
+        assert!(
+            !original
+                .iter()
+                .any(|(a, b, _)| a == "entity.name.function" && b == "string.regexp")
+        );
+    }
+
+    #[test]
+    fn find_syntax_for_file_uses_first_line() {
+        let syntax_set = load_syntax_set();
+
+        let bash = find_syntax_for_file(syntax_set, Path::new("script"), "#!/bin/bash\n").unwrap();
+        assert!(bash.name.to_lowercase().contains("bash"));
+
+        let rust = find_syntax_for_file(syntax_set, Path::new("src/main.rs"), "#!/bin/bash\n").unwrap();
+        assert_eq!(rust.name, "Rust");
+
+        assert!(find_syntax_for_file(syntax_set, Path::new("notes"), "hello").is_none());
+    }
 }
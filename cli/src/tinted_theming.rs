@@ -1,14 +1,23 @@
 //! Base16/Base24 scheme parsing and serialization helpers compatible with tinted-theming.
 
-use crate::colors::{Rgb, Srgb8};
+use crate::base16_builder::{NEUTRAL_MAX_SATURATION, ROLE_HUE_BANDS, Variant, indistinct_accents, neutral_ramp_breaks};
+use crate::colors::{Hsl, Lab, Rgb, Srgb8};
+use crate::diffs::delta_e_2000;
+use crate::wcag::{WCAG_AA_NORMAL, contrast_ratio};
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
+/// ΔE2000 below which two slots look identical, which in a hand-edited scheme usually means a copy-paste slip.
+pub const DUPLICATE_SLOT_DELTA_E: f32 = 1.0;
+
+/// Slack above [`NEUTRAL_MAX_SATURATION`] before [`validate_scheme`] flags a neutral, absorbing hex rounding.
+const NEUTRAL_SATURATION_TOLERANCE: f32 = 0.02;
+
 const BASE16_KEYS: [&str; 16] = [
     "base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07", "base08", "base09", "base0A",
     "base0B", "base0C", "base0D", "base0E", "base0F",
@@ -31,6 +40,21 @@ pub struct SchemeMetadata {
     pub variant: Option<String>,
 }
 
+impl SchemeMetadata {
+    /// Replaces whichever of the name, author, and variant are given, leaving the others (and the system) as-is.
+    pub fn update(&mut self, name: Option<&str>, author: Option<&str>, variant: Option<&str>) {
+        if let Some(name) = name {
+            self.name = name.to_string();
+        }
+        if let Some(author) = author {
+            self.author = Some(author.to_string());
+        }
+        if let Some(variant) = variant {
+            self.variant = Some(variant.to_string());
+        }
+    }
+}
+
 /// Base16 scheme definition (16 canonical colors).
 #[derive(Debug, Clone)]
 pub struct Base16Scheme {
@@ -50,6 +74,18 @@ impl Base16Scheme {
     pub fn as_rgb(&self) -> Vec<Rgb> {
         self.colors.iter().copied().map(Rgb::from).collect()
     }
+
+    /// Replaces the color stored under a palette key such as `base08`.
+    ///
+    /// Keys are matched case-insensitively; unknown keys return [`SchemeError::UnknownKey`].
+    pub fn set_color(&mut self, key: &str, color: Srgb8) -> Result<(), SchemeError> {
+        set_slot(&mut self.colors, &BASE16_KEYS, key, color)
+    }
+
+    /// Serializes the scheme to tinted-theming YAML with palette keys in slot order.
+    pub fn to_yaml(&self) -> String {
+        scheme_to_yaml(&self.metadata, &self.colors, &BASE16_KEYS)
+    }
 }
 
 /// Base24 scheme definition (Base16 + 8 extended slots).
@@ -71,6 +107,18 @@ impl Base24Scheme {
     pub fn as_rgb(&self) -> Vec<Rgb> {
         self.colors.iter().copied().map(Rgb::from).collect()
     }
+
+    /// Replaces the color stored under a palette key such as `base08`.
+    ///
+    /// Keys are matched case-insensitively; unknown keys return [`SchemeError::UnknownKey`].
+    pub fn set_color(&mut self, key: &str, color: Srgb8) -> Result<(), SchemeError> {
+        set_slot(&mut self.colors, &BASE24_KEYS, key, color)
+    }
+
+    /// Serializes the scheme to tinted-theming YAML with palette keys in slot order.
+    pub fn to_yaml(&self) -> String {
+        scheme_to_yaml(&self.metadata, &self.colors, &BASE24_KEYS)
+    }
 }
 
 /// Errors that may occur while loading or writing tinted-theming schemes.
@@ -81,9 +129,12 @@ pub enum SchemeError {
     Serialize { source: serde_yml::Error },
     MissingField(&'static str),
     MissingColor(String),
+    UnknownKey(String),
     InvalidHex { key: String, value: String },
     UnsupportedSystem(String),
     EmptyDirectory(PathBuf),
+    UnexpectedColor(String),
+    UnknownField(String),
 }
 
 impl fmt::Display for SchemeError {
@@ -94,11 +145,14 @@ impl fmt::Display for SchemeError {
             SchemeError::Serialize { source } => write!(f, "failed to serialize scheme: {}", source),
             SchemeError::MissingField(field) => write!(f, "scheme is missing required field '{field}'"),
             SchemeError::MissingColor(key) => write!(f, "scheme palette missing '{key}'"),
+            SchemeError::UnknownKey(key) => write!(f, "'{key}' is not a palette key of this scheme"),
             SchemeError::InvalidHex { key, value } => {
                 write!(f, "palette entry '{key}' is not a valid hex color: {value}")
             }
             SchemeError::UnsupportedSystem(system) => write!(f, "unsupported scheme system '{system}'"),
             SchemeError::EmptyDirectory(path) => write!(f, "no YAML schemes found in {}", path.display()),
+            SchemeError::UnexpectedColor(key) => write!(f, "palette entry '{key}' is not part of the declared system"),
+            SchemeError::UnknownField(field) => write!(f, "unknown field '{field}'"),
         }
     }
 }
@@ -113,13 +167,114 @@ impl std::error::Error for SchemeError {
     }
 }
 
+/// Scheme file as written on disk.
+///
+/// Modern tinted-theming files nest colors under `palette:`, while legacy Base16 files put `baseXX:` keys and a
+/// `scheme:` name at the top level; both layouts deserialize into this struct.
 #[derive(Debug, Deserialize)]
 struct RawScheme {
     system: Option<String>,
+    #[serde(alias = "scheme")]
     name: Option<String>,
     author: Option<String>,
     variant: Option<String>,
+    #[serde(default)]
     palette: HashMap<String, String>,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_yml::Value>,
+}
+
+impl RawScheme {
+    /// Collects top-level `baseXX` keys into the palette when the file uses the legacy flat layout.
+    fn normalize(mut self) -> Self {
+        if self.palette.is_empty() {
+            self.palette = self
+                .extra
+                .iter()
+                .filter(|(key, _)| key.starts_with("base"))
+                .filter_map(|(key, value)| legacy_hex(value).map(|hex| (key.clone(), hex)))
+                .collect();
+        }
+        self
+    }
+}
+
+/// Top-level fields the tinted-theming spec allows besides `system`, `name`, `author`, `variant`, and `palette`.
+const OPTIONAL_FIELDS: [&str; 2] = ["slug", "description"];
+
+/// Strictly checks a scheme file, reporting every structural problem instead of stopping at the first.
+///
+/// Unlike the loaders, this rejects unknown fields and palette keys outside the declared system (Base16 when
+/// `system` is omitted), on top of missing names, missing slots, and malformed hex values. I/O and YAML syntax errors
+/// are returned on their own since nothing else can be checked.
+pub fn validate_scheme_file(path: impl AsRef<Path>) -> Result<(), Vec<SchemeError>> {
+    let raw = parse_file(path.as_ref()).map_err(|err| vec![err])?;
+    validate_raw(&raw)
+}
+
+/// Collects every structural problem in a parsed scheme; see [`validate_scheme_file`].
+fn validate_raw(raw: &RawScheme) -> Result<(), Vec<SchemeError>> {
+    let mut errors = Vec::new();
+
+    let keys: &[&str] = match raw.system.as_deref().map(|system| (system, canonical_system(system))) {
+        None | Some((_, Some("base16"))) => &BASE16_KEYS,
+        Some((_, Some(_))) => &BASE24_KEYS,
+        Some((system, None)) => {
+            errors.push(SchemeError::UnsupportedSystem(system.to_string()));
+            &BASE24_KEYS
+        }
+    };
+    if raw.name.is_none() {
+        errors.push(SchemeError::MissingField("name"));
+    }
+
+    for key in keys {
+        match raw.palette.get(*key) {
+            None => errors.push(SchemeError::MissingColor(key.to_string())),
+            Some(value) if Srgb8::from_hex(value.trim()).is_none() => {
+                errors.push(SchemeError::InvalidHex { key: key.to_string(), value: value.trim().to_string() })
+            }
+            Some(_) => {}
+        }
+    }
+
+    let mut unexpected: Vec<&String> = raw.palette.keys().filter(|key| !keys.contains(&key.as_str())).collect();
+    unexpected.sort();
+    errors.extend(
+        unexpected
+            .into_iter()
+            .map(|key| SchemeError::UnexpectedColor(key.clone())),
+    );
+
+    // Legacy flat files keep their baseXX keys in `extra` alongside the collected palette.
+    let mut unknown: Vec<&String> = raw
+        .extra
+        .keys()
+        .filter(|field| !OPTIONAL_FIELDS.contains(&field.as_str()) && !raw.palette.contains_key(*field))
+        .collect();
+    unknown.sort();
+    errors.extend(
+        unknown
+            .into_iter()
+            .map(|field| SchemeError::UnknownField(field.clone())),
+    );
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Unquoted all-digit hex values such as `000000` are read by YAML as integers, so pad them back to six digits.
+///
+/// Others like `1234e5` are read as floats, which loses the original text; those come back as a note that fails hex
+/// parsing, so loading reports [`SchemeError::InvalidHex`] for the key instead of silently dropping it.
+fn legacy_hex(value: &serde_yml::Value) -> Option<String> {
+    match value {
+        serde_yml::Value::String(hex) => Some(hex.clone()),
+        serde_yml::Value::Number(number) => Some(match number.as_u64() {
+            Some(digits) => format!("{digits:06}"),
+            None => format!("{number} (unquoted value read as a number; quote the hex code)"),
+        }),
+        _ => None,
+    }
 }
 
 /// Loads Base16 schemes from a file or directory path.
@@ -152,10 +307,10 @@ where
         Ok(schemes)
     } else {
         let raw = parse_file(path)?;
-        if let Some(system) = raw.system.as_deref() {
-            if system != expected {
-                return Err(SchemeError::UnsupportedSystem(system.to_string()));
-            }
+        if let Some(system) = raw.system.as_deref()
+            && canonical_system(system) != Some(expected)
+        {
+            return Err(SchemeError::UnsupportedSystem(system.to_string()));
         }
         parser(raw, path.to_path_buf()).map(|scheme| vec![scheme])
     }
@@ -163,7 +318,9 @@ where
 
 fn parse_file(path: &Path) -> Result<RawScheme, SchemeError> {
     let contents = fs::read_to_string(path).map_err(|source| SchemeError::Io { path: path.to_path_buf(), source })?;
-    serde_yml::from_str(&contents).map_err(|source| SchemeError::Parse { path: path.to_path_buf(), source })
+    serde_yml::from_str(&contents)
+        .map(RawScheme::normalize)
+        .map_err(|source| SchemeError::Parse { path: path.to_path_buf(), source })
 }
 
 fn parse_base16(raw: RawScheme, _: PathBuf) -> Result<Base16Scheme, SchemeError> {
@@ -182,13 +339,31 @@ fn parse_base24(raw: RawScheme, _: PathBuf) -> Result<Base24Scheme, SchemeError>
     Ok(Base24Scheme { metadata, colors: array })
 }
 
-fn metadata(raw: &RawScheme, expected_system: &str) -> Result<SchemeMetadata, SchemeError> {
+/// Maps a declared `system` to `"base16"` or `"base24"`, ignoring case and surrounding whitespace.
+fn canonical_system(system: &str) -> Option<&'static str> {
+    let system = system.trim();
+    if system.eq_ignore_ascii_case("base16") {
+        Some("base16")
+    } else if system.eq_ignore_ascii_case("base24") {
+        Some("base24")
+    } else {
+        None
+    }
+}
+
+fn metadata(raw: &RawScheme, expected_system: &'static str) -> Result<SchemeMetadata, SchemeError> {
     let name = raw.name.clone().ok_or(SchemeError::MissingField("name"))?;
-    let system = raw.system.clone().unwrap_or_else(|| expected_system.to_string());
-    if system != expected_system {
-        return Err(SchemeError::UnsupportedSystem(system));
+    if let Some(system) = raw.system.as_deref()
+        && canonical_system(system) != Some(expected_system)
+    {
+        return Err(SchemeError::UnsupportedSystem(system.to_string()));
     }
-    Ok(SchemeMetadata { system, name, author: raw.author.clone(), variant: raw.variant.clone() })
+    Ok(SchemeMetadata {
+        system: expected_system.to_string(),
+        name,
+        author: raw.author.clone(),
+        variant: raw.variant.clone(),
+    })
 }
 
 fn build_palette(palette: &HashMap<String, String>, keys: &[&str]) -> Result<Vec<Srgb8>, SchemeError> {
@@ -211,56 +386,296 @@ fn is_yaml(path: &Path) -> bool {
 
 /// Writes a Base16 scheme to a YAML file in tinted-theming format.
 pub fn write_base16_scheme(scheme: &Base16Scheme, path: impl AsRef<Path>) -> Result<(), SchemeError> {
-    let mut palette = HashMap::new();
-    for (i, &color) in scheme.colors().iter().enumerate() {
-        palette.insert(BASE16_KEYS[i].to_string(), color.to_hex());
-    }
-
-    let output = SchemeYaml {
-        system: scheme.metadata.system.clone(),
-        name: scheme.metadata.name.clone(),
-        author: scheme.metadata.author.clone(),
-        variant: scheme.metadata.variant.clone(),
-        palette,
-    };
+    write_yaml(&scheme.to_yaml(), path.as_ref())
+}
 
-    let yaml = serde_yml::to_string(&output).map_err(|source| SchemeError::Serialize { source })?;
+/// Writes a Base24 scheme to a YAML file in tinted-theming format.
+pub fn write_base24_scheme(scheme: &Base24Scheme, path: impl AsRef<Path>) -> Result<(), SchemeError> {
+    write_yaml(&scheme.to_yaml(), path.as_ref())
+}
 
-    let path_ref = path.as_ref();
-    let mut file = fs::File::create(path_ref)
-        .map_err(|source| SchemeError::Io { path: path_ref.to_path_buf(), source })?;
+fn write_yaml(yaml: &str, path: &Path) -> Result<(), SchemeError> {
+    let mut file = fs::File::create(path).map_err(|source| SchemeError::Io { path: path.to_path_buf(), source })?;
 
     file.write_all(yaml.as_bytes())
-        .map_err(|source| SchemeError::Io { path: path_ref.to_path_buf(), source })?;
+        .map_err(|source| SchemeError::Io { path: path.to_path_buf(), source })?;
 
     Ok(())
 }
 
-/// Writes a Base24 scheme to a YAML file in tinted-theming format.
-pub fn write_base24_scheme(scheme: &Base24Scheme, path: impl AsRef<Path>) -> Result<(), SchemeError> {
-    let mut palette = HashMap::new();
-    for (i, &color) in scheme.colors().iter().enumerate() {
-        palette.insert(BASE24_KEYS[i].to_string(), color.to_hex());
-    }
+fn scheme_to_yaml(metadata: &SchemeMetadata, colors: &[Srgb8], keys: &[&str]) -> String {
+    let palette = keys
+        .iter()
+        .zip(colors)
+        .map(|(key, color)| (key.to_string(), color.to_hex()))
+        .collect();
 
     let output = SchemeYaml {
-        system: scheme.metadata.system.clone(),
-        name: scheme.metadata.name.clone(),
-        author: scheme.metadata.author.clone(),
-        variant: scheme.metadata.variant.clone(),
+        system: metadata.system.clone(),
+        name: metadata.name.clone(),
+        author: metadata.author.clone(),
+        variant: metadata.variant.clone(),
         palette,
     };
 
-    let yaml = serde_yml::to_string(&output).map_err(|source| SchemeError::Serialize { source })?;
+    serde_yml::to_string(&output).expect("scheme fields are plain strings and always serialize")
+}
 
-    let path_ref = path.as_ref();
-    let mut file = fs::File::create(path_ref)
-        .map_err(|source| SchemeError::Io { path: path_ref.to_path_buf(), source })?;
+fn set_slot(colors: &mut [Srgb8], keys: &[&str], key: &str, color: Srgb8) -> Result<(), SchemeError> {
+    let index = keys
+        .iter()
+        .position(|candidate| candidate.eq_ignore_ascii_case(key))
+        .ok_or_else(|| SchemeError::UnknownKey(key.to_string()))?;
+    colors[index] = color;
+    Ok(())
+}
 
-    file.write_all(yaml.as_bytes())
-        .map_err(|source| SchemeError::Io { path: path_ref.to_path_buf(), source })?;
+/// Compares two Base16 schemes slot by slot.
+///
+/// Returns `(key, a, b, ΔE2000)` for every base key in order, including unchanged slots (ΔE of 0).
+pub fn diff_schemes(a: &Base16Scheme, b: &Base16Scheme) -> Vec<(String, Srgb8, Srgb8, f32)> {
+    diff_palettes(a.colors(), b.colors())
+}
 
-    Ok(())
+/// Compares two palettes slot by slot using Base16/Base24 key names.
+///
+/// Only the slots present in both palettes are compared, so a Base16 palette can be diffed against the first 16 slots of a Base24 one.
+pub fn diff_palettes(a: &[Srgb8], b: &[Srgb8]) -> Vec<(String, Srgb8, Srgb8, f32)> {
+    BASE24_KEYS
+        .iter()
+        .zip(a.iter().zip(b.iter()))
+        .map(|(key, (&ca, &cb))| (key.to_string(), ca, cb, delta_e_2000(Lab::from(ca), Lab::from(cb))))
+        .collect()
+}
+
+/// Finds pairs of slots whose colors are closer than `threshold` (ΔE2000).
+///
+/// Returns `(key_a, key_b, ΔE)` for every pair in slot order, using Base16/Base24 key names.
+pub fn duplicate_slots(colors: &[Srgb8], threshold: f32) -> Vec<(String, String, f32)> {
+    let labs: Vec<Lab> = colors.iter().copied().map(Lab::from).collect();
+    let mut pairs = Vec::new();
+    for (i, key_a) in BASE24_KEYS.iter().enumerate().take(labs.len()) {
+        for (j, key_b) in BASE24_KEYS.iter().enumerate().take(labs.len()).skip(i + 1) {
+            let delta = delta_e_2000(labs[i], labs[j]);
+            if delta < threshold {
+                pairs.push((key_a.to_string(), key_b.to_string(), delta));
+            }
+        }
+    }
+    pairs
+}
+
+/// Outcome of a single [`validate_scheme`] check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// One line of a [`ValidationReport`].
+#[derive(Debug, Clone)]
+pub struct ValidationCheck {
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// Results of validating a scheme: every check run, in order, with its severity.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub checks: Vec<ValidationCheck>,
+}
+
+impl ValidationReport {
+    fn push(&mut self, severity: Severity, message: impl Into<String>) {
+        self.checks.push(ValidationCheck { severity, message: message.into() });
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.checks.iter().filter(|check| check.severity == severity).count()
+    }
+
+    pub fn errors(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    /// A scheme passes when no check is an error; warnings are advisory.
+    pub fn passed(&self) -> bool {
+        self.errors() == 0
+    }
+}
+
+/// Checks a Base16/Base24 palette against the tinted-theming guidelines.
+///
+/// `system` is `"base16"` or `"base24"` (case-insensitive) and sets the expected slot count. `variant` comes from the
+/// scheme metadata; when missing, it is inferred from base00's lightness. Errors cover a wrong slot count and accents
+/// below WCAG AA contrast against base00; saturated or non-monotonic neutrals, indistinct accents (closer than
+/// `min_accent_delta_e`), and duplicate slots are warnings.
+pub fn validate_scheme(
+    colors: &[Srgb8], system: &str, variant: Option<&str>, min_accent_delta_e: f32,
+) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    let expected_count = if system.eq_ignore_ascii_case("base24") { 24 } else { 16 };
+    if colors.len() != expected_count {
+        report.push(
+            Severity::Error,
+            format!("Expected {expected_count} colors, found {}", colors.len()),
+        );
+        if colors.len() < 16 {
+            return report;
+        }
+    } else {
+        report.push(Severity::Ok, format!("Color count: {}", colors.len()));
+    }
+
+    let high_saturation_neutrals: Vec<String> = colors[..8]
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &color)| {
+            let hsl: Hsl = Rgb::from(color).into();
+            (hsl.s > NEUTRAL_MAX_SATURATION + NEUTRAL_SATURATION_TOLERANCE).then(|| format!("{i:02X} ({:.2})", hsl.s))
+        })
+        .collect();
+    if high_saturation_neutrals.is_empty() {
+        report.push(Severity::Ok, "Neutrals (base00-base07) have low saturation");
+    } else {
+        report.push(
+            Severity::Warning,
+            format!(
+                "Some neutrals have high saturation: {}",
+                high_saturation_neutrals.join(", ")
+            ),
+        );
+    }
+
+    check_neutral_ramp(&mut report, colors, variant);
+
+    let close_accents = indistinct_accents(&colors[8..16], min_accent_delta_e);
+    if close_accents.is_empty() {
+        report.push(
+            Severity::Ok,
+            format!("Accents are distinct (ΔE2000 >= {min_accent_delta_e:.1})"),
+        );
+    } else {
+        let pairs: Vec<String> = close_accents
+            .iter()
+            .map(|(i, j, delta)| format!("base{:02X}/base{:02X} (ΔE {delta:.2})", i + 8, j + 8))
+            .collect();
+        report.push(
+            Severity::Warning,
+            format!("Accents are hard to tell apart: {}", pairs.join(", ")),
+        );
+    }
+
+    // Base24 bright slots (base10-base17) often reuse the normal accents on purpose, so only check base00-base0F.
+    let duplicates = duplicate_slots(&colors[..16], DUPLICATE_SLOT_DELTA_E);
+    if duplicates.is_empty() {
+        report.push(Severity::Ok, "No two slots in base00-base0F are visually identical");
+    }
+    for (a, b, delta) in &duplicates {
+        report.push(
+            Severity::Warning,
+            format!("{a} and {b} are visually identical (ΔE {delta:.2})"),
+        );
+    }
+
+    let low_contrast_accents: Vec<String> = colors[8..16]
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &color)| {
+            let ratio = contrast_ratio(colors[0], color);
+            (ratio < WCAG_AA_NORMAL).then(|| format!("{:02X} ({ratio:.2}:1)", i + 8))
+        })
+        .collect();
+    if low_contrast_accents.is_empty() {
+        report.push(
+            Severity::Ok,
+            "All accent colors meet WCAG AA contrast (4.5:1) against base00",
+        );
+    } else {
+        report.push(
+            Severity::Error,
+            format!("Low contrast accents: {}", low_contrast_accents.join(", ")),
+        );
+    }
+
+    report
+}
+
+/// Lints a Base16/Base24 palette against tinted-theming style conventions.
+///
+/// Each named accent role (base08 red through base0E magenta) should have a hue inside its [`ROLE_HUE_BANDS`] band;
+/// roles that are way off, or too desaturated to read as their color, are warnings. The neutral ramp (base00-base07)
+/// must also be monotonic in lightness for the variant, inferred from base00 when `variant` is missing.
+pub fn lint_scheme(colors: &[Srgb8], variant: Option<&str>) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    if colors.len() < 16 {
+        report.push(
+            Severity::Error,
+            format!("Expected at least 16 colors, found {}", colors.len()),
+        );
+        return report;
+    }
+
+    for band in &ROLE_HUE_BANDS {
+        let color = colors[band.slot];
+        let hsl = Hsl::from(color);
+        if hsl.s <= NEUTRAL_MAX_SATURATION {
+            report.push(
+                Severity::Warning,
+                format!(
+                    "base{:02X} ({color}) is too desaturated to read as {}",
+                    band.slot, band.name
+                ),
+            );
+        } else if let Some(excess) = band.deviation(color) {
+            report.push(
+                Severity::Warning,
+                format!(
+                    "base{:02X} ({color}) should be {}, but its hue {:.0}° is {excess:.0}° outside {:.0}°±{:.0}°",
+                    band.slot, band.name, hsl.h, band.hue, band.tolerance
+                ),
+            );
+        } else {
+            report.push(
+                Severity::Ok,
+                format!("base{:02X} reads as {} (hue {:.0}°)", band.slot, band.name, hsl.h),
+            );
+        }
+    }
+
+    check_neutral_ramp(&mut report, colors, variant);
+    report
+}
+
+/// Adds a check that base00-base07 run monotonically in lightness for the (given or inferred) variant.
+fn check_neutral_ramp(report: &mut ValidationReport, colors: &[Srgb8], variant: Option<&str>) {
+    let variant = Variant::infer(variant, colors[0]);
+    let ramp_breaks = neutral_ramp_breaks(&colors[..8], variant);
+    if ramp_breaks.is_empty() {
+        report.push(
+            Severity::Ok,
+            format!("Neutral lightness is monotonic for a {} variant", variant.as_str()),
+        );
+    } else {
+        let breaks: Vec<String> = ramp_breaks
+            .iter()
+            .map(|i| format!("base{i:02X} -> base{:02X}", i + 1))
+            .collect();
+        report.push(
+            Severity::Warning,
+            format!(
+                "Neutral lightness is not monotonic for a {} variant: {}",
+                variant.as_str(),
+                breaks.join(", ")
+            ),
+        );
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -271,7 +686,7 @@ struct SchemeYaml {
     author: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     variant: Option<String>,
-    palette: HashMap<String, String>,
+    palette: BTreeMap<String, String>,
 }
 
 #[cfg(test)]
@@ -287,6 +702,228 @@ mod tests {
         assert_eq!(scheme.colors().len(), 16);
     }
 
+    #[test]
+    fn diff_schemes_reports_changed_slots() {
+        let raw: RawScheme = serde_yml::from_str(include_str!("../../examples/base16/oxocarbon-dark.yml")).unwrap();
+        let a = parse_base16(raw, PathBuf::new()).unwrap();
+
+        let identical = diff_schemes(&a, &a);
+        assert_eq!(identical.len(), 16);
+        assert!(identical.iter().all(|(_, _, _, delta)| *delta == 0.0));
+
+        let mut colors = [Srgb8::new(0, 0, 0); 16];
+        colors.copy_from_slice(a.colors());
+        colors[8] = Srgb8::new(0, 200, 0);
+        let b = Base16Scheme::new(a.metadata.clone(), colors);
+
+        let diff = diff_schemes(&a, &b);
+        let changed: Vec<_> = diff.iter().filter(|(_, _, _, delta)| *delta > 0.0).collect();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].0, "base08");
+        assert!(changed[0].3 > 10.0);
+    }
+
+    #[test]
+    fn duplicate_slots_flags_copied_colors() {
+        let raw: RawScheme = serde_yml::from_str(include_str!("../../examples/base16/oxocarbon-dark.yml")).unwrap();
+        let mut scheme = parse_base16(raw, PathBuf::new()).unwrap();
+        let before = duplicate_slots(scheme.colors(), DUPLICATE_SLOT_DELTA_E);
+
+        let cyan = scheme.colors()[0x0C];
+        scheme.set_color("base0D", cyan).unwrap();
+        let after = duplicate_slots(scheme.colors(), DUPLICATE_SLOT_DELTA_E);
+        assert_eq!(after.len(), before.len() + 1);
+        assert!(
+            after
+                .iter()
+                .any(|(a, b, delta)| a == "base0C" && b == "base0D" && *delta < 1e-3)
+        );
+    }
+
+    #[test]
+    fn validate_scheme_reports_errors_and_warnings() {
+        let raw: RawScheme = serde_yml::from_str(include_str!("../../examples/base16/oxocarbon-dark.yml")).unwrap();
+        let mut scheme = parse_base16(raw, PathBuf::new()).unwrap();
+
+        let good = validate_scheme(scheme.colors(), "base16", Some("dark"), 0.0);
+        assert!(good.passed(), "{good:?}");
+        assert_eq!(good.errors(), 0);
+
+        let background = scheme.colors()[0];
+        scheme.set_color("base08", background).unwrap();
+        scheme.set_color("base02", Srgb8::new(200, 40, 40)).unwrap();
+        let broken = validate_scheme(scheme.colors(), "base16", Some("dark"), 0.0);
+        assert!(!broken.passed());
+        assert_eq!(broken.errors(), 1, "only the low-contrast accent is an error");
+        assert!(broken.warnings() > good.warnings());
+        assert!(
+            broken
+                .checks
+                .iter()
+                .any(|check| check.severity == Severity::Error && check.message.contains("08"))
+        );
+
+        let short = validate_scheme(&scheme.colors()[..8], "base16", None, 0.0);
+        assert_eq!(short.errors(), 1);
+        assert_eq!(short.checks.len(), 1);
+    }
+
+    #[test]
+    fn validate_raw_collects_every_problem() {
+        let example: RawScheme = serde_yml::from_str(include_str!("../../examples/base16/oxocarbon-dark.yml")).unwrap();
+        assert!(validate_raw(&example.normalize()).is_ok());
+
+        let yaml = r##"
+system: base16
+author: Someone
+colour: typo
+palette:
+  base00: "#161616"
+  base01: "#262626"
+  base02: "#393939"
+  base03: "#525252"
+  base04: "#dde1e6"
+  base05: "#f2f4f8"
+  base06: "#ffffff"
+  base07: "#08bdba"
+  base08: "#3ddbd9"
+  base09: "#78a9ff"
+  base0A: "#ee5396"
+  base0B: "#33b1ff"
+  base0C: "#ff7eb6"
+  base0D: "#42be65"
+  base0E: "#zzzzzz"
+  base10: "#000000"
+"##;
+        let raw: RawScheme = serde_yml::from_str::<RawScheme>(yaml).unwrap().normalize();
+        let errors = validate_raw(&raw).unwrap_err();
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+
+        assert_eq!(errors.len(), 5, "{messages:?}");
+        assert!(matches!(errors[0], SchemeError::MissingField("name")));
+        assert!(matches!(&errors[1], SchemeError::InvalidHex { key, .. } if key == "base0E"));
+        assert!(matches!(&errors[2], SchemeError::MissingColor(key) if key == "base0F"));
+        assert!(matches!(&errors[3], SchemeError::UnexpectedColor(key) if key == "base10"));
+        assert!(matches!(&errors[4], SchemeError::UnknownField(field) if field == "colour"));
+    }
+
+    #[test]
+    fn set_color_round_trips_through_yaml() {
+        let raw: RawScheme = serde_yml::from_str(include_str!("../../examples/base16/oxocarbon-dark.yml")).unwrap();
+        let mut scheme = parse_base16(raw, PathBuf::new()).unwrap();
+        let red = Srgb8::new(255, 0, 0);
+        scheme.set_color("base08", red).unwrap();
+        assert!(matches!(scheme.set_color("base18", red), Err(SchemeError::UnknownKey(key)) if key == "base18"));
+
+        let yaml = scheme.to_yaml();
+        let reparsed: RawScheme = serde_yml::from_str(&yaml).unwrap();
+        let reloaded = parse_base16(reparsed, PathBuf::new()).unwrap();
+        assert_eq!(reloaded.colors()[8], red);
+        assert_eq!(reloaded.colors()[0], scheme.colors()[0]);
+        assert_eq!(reloaded.metadata.name, "Oxocarbon Dark");
+    }
+
+    #[test]
+    fn metadata_update_leaves_palette_untouched() {
+        let raw: RawScheme = serde_yml::from_str(include_str!("../../examples/base16/oxocarbon-dark.yml")).unwrap();
+        let mut scheme = parse_base16(raw, PathBuf::new()).unwrap();
+        let original = scheme.clone();
+        scheme.metadata.update(Some("Carbon Night"), Some("Me"), None);
+
+        let reparsed: RawScheme = serde_yml::from_str(&scheme.to_yaml()).unwrap();
+        let reloaded = parse_base16(reparsed, PathBuf::new()).unwrap();
+        assert_eq!(reloaded.metadata.name, "Carbon Night");
+        assert_eq!(reloaded.metadata.author.as_deref(), Some("Me"));
+        assert_eq!(reloaded.metadata.variant, original.metadata.variant);
+        assert_eq!(reloaded.metadata.system, "base16");
+        for (key, (before, after)) in BASE16_KEYS.iter().zip(original.colors().iter().zip(reloaded.colors())) {
+            assert_eq!(before, after, "{key} changed");
+        }
+    }
+
+    #[test]
+    fn parse_nested_palette_layout() {
+        let yaml = "system: base16\nname: Nested\npalette:\n".to_string()
+            + &BASE16_KEYS.map(|key| format!("  {key}: \"#112233\"\n")).concat();
+        let raw: RawScheme = serde_yml::from_str::<RawScheme>(&yaml).unwrap().normalize();
+        let scheme = parse_base16(raw, PathBuf::new()).unwrap();
+        assert_eq!(scheme.metadata.name, "Nested");
+        assert!(scheme.colors().iter().all(|&c| c == Srgb8::new(0x11, 0x22, 0x33)));
+    }
+
+    #[test]
+    fn system_field_ignores_case_but_not_spelling() {
+        let palette = BASE16_KEYS.map(|key| format!("  {key}: \"#112233\"\n")).concat();
+        let parse = |system: &str| {
+            let yaml = format!("system: {system}\nname: Cased\npalette:\n{palette}");
+            parse_base16(
+                serde_yml::from_str::<RawScheme>(&yaml).unwrap().normalize(),
+                PathBuf::new(),
+            )
+        };
+
+        let scheme = parse("Base16").unwrap();
+        assert_eq!(scheme.metadata.system, "base16");
+        assert!(parse("BASE16").is_ok());
+        assert!(matches!(parse("base24"), Err(SchemeError::UnsupportedSystem(system)) if system == "base24"));
+        assert!(matches!(parse("base17"), Err(SchemeError::UnsupportedSystem(_))));
+
+        let yaml = format!("system: Base16\nname: Cased\npalette:\n{palette}");
+        assert!(validate_raw(&serde_yml::from_str::<RawScheme>(&yaml).unwrap().normalize()).is_ok());
+    }
+
+    #[test]
+    fn lint_flags_accents_outside_their_role_band() {
+        let raw: RawScheme =
+            serde_yml::from_str(include_str!("../../examples/base24/catppuccin-macchiato.yml")).unwrap();
+        let scheme = parse_base24(raw, PathBuf::new()).unwrap();
+        let mut colors = scheme.colors().to_vec();
+        let hue_warnings = |report: &ValidationReport| {
+            report
+                .checks
+                .iter()
+                .filter(|check| check.severity == Severity::Warning && check.message.contains("should be"))
+                .map(|check| check.message.clone())
+                .collect::<Vec<_>>()
+        };
+        assert!(hue_warnings(&lint_scheme(&colors, Some("dark"))).is_empty());
+
+        colors[0x08] = Srgb8::new(0x40, 0x60, 0xf0);
+        let warnings = hue_warnings(&lint_scheme(&colors, Some("dark")));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("base08") && warnings[0].contains("red"));
+
+        colors[0x0B] = Srgb8::new(0x70, 0x70, 0x74);
+        let report = lint_scheme(&colors, Some("dark"));
+        assert!(
+            report
+                .checks
+                .iter()
+                .any(|check| check.message.contains("base0B") && check.message.contains("desaturated"))
+        );
+    }
+
+    #[test]
+    fn parse_legacy_flat_layout() {
+        let yaml = "scheme: Legacy\nauthor: Someone\n".to_string()
+            + &BASE16_KEYS.map(|key| format!("{key}: \"ff8000\"\n")).concat()
+            + "base00: 000000\n";
+        let yaml = yaml.replacen("base00: \"ff8000\"\n", "", 1);
+        let raw: RawScheme = serde_yml::from_str::<RawScheme>(&yaml).unwrap().normalize();
+        let scheme = parse_base16(raw, PathBuf::new()).unwrap();
+        assert_eq!(scheme.metadata.name, "Legacy");
+        assert_eq!(scheme.metadata.author.as_deref(), Some("Someone"));
+        assert_eq!(scheme.colors()[0], Srgb8::new(0, 0, 0));
+        assert_eq!(scheme.colors()[15], Srgb8::new(255, 128, 0));
+
+        let float = yaml.replacen("base00: 000000\n", "base00: 1234e5\n", 1);
+        let raw: RawScheme = serde_yml::from_str::<RawScheme>(&float).unwrap().normalize();
+        assert!(matches!(
+            parse_base16(raw, PathBuf::new()),
+            Err(SchemeError::InvalidHex { key, .. }) if key == "base00"
+        ));
+    }
+
     #[test]
     fn parse_base24_example() {
         let raw: RawScheme =
@@ -2,7 +2,7 @@
 //!
 //! Implements relative luminance and contrast ratio calculations per WCAG 2.1 specification.
 
-use crate::colors::{Rgb, Srgb8};
+use crate::colors::{Hsl, Lab, Rgb, Srgb8, Xyz};
 
 /// WCAG AA minimum contrast ratio for normal text.
 pub const WCAG_AA_NORMAL: f32 = 4.5;
@@ -38,8 +38,19 @@ pub const WCAG_AAA_LARGE: f32 = 4.5;
 /// assert_eq!(relative_luminance(black), 0.0);
 /// ```
 pub fn relative_luminance(color: Srgb8) -> f32 {
-    let linear: Rgb = color.into();
-    0.2126 * linear.r + 0.7152 * linear.g + 0.0722 * linear.b
+    relative_luminance_rgb(color.into())
+}
+
+/// Calculates relative luminance of a color that is already in linear RGB.
+///
+/// Skips the sRGB decoding step performed by [`relative_luminance`], so callers holding [`Rgb`] values avoid a round trip through `Srgb8`.
+pub fn relative_luminance_rgb(color: Rgb) -> f32 {
+    0.2126 * color.r + 0.7152 * color.g + 0.0722 * color.b
+}
+
+/// Calculates relative luminance of a Lab color (the CIE Y tristimulus value, clamped to [0, 1]).
+pub fn relative_luminance_lab(color: Lab) -> f32 {
+    Xyz::from(color).y.clamp(0.0, 1.0)
 }
 
 /// Calculates the contrast ratio between two colors per WCAG specification.
@@ -65,10 +76,21 @@ pub fn relative_luminance(color: Srgb8) -> f32 {
 /// assert!(ratio > 20.0); // Maximum contrast is 21:1
 /// ```
 pub fn contrast_ratio(c1: Srgb8, c2: Srgb8) -> f32 {
-    let l1 = relative_luminance(c1);
-    let l2 = relative_luminance(c2);
-    let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+    ratio_from_luminance(relative_luminance(c1), relative_luminance(c2))
+}
+
+/// Calculates the WCAG contrast ratio between two linear RGB colors.
+pub fn contrast_ratio_rgb(c1: Rgb, c2: Rgb) -> f32 {
+    ratio_from_luminance(relative_luminance_rgb(c1), relative_luminance_rgb(c2))
+}
 
+/// Calculates the WCAG contrast ratio between two Lab colors.
+pub fn contrast_ratio_lab(c1: Lab, c2: Lab) -> f32 {
+    ratio_from_luminance(relative_luminance_lab(c1), relative_luminance_lab(c2))
+}
+
+fn ratio_from_luminance(l1: f32, l2: f32) -> f32 {
+    let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
     (lighter + 0.05) / (darker + 0.05)
 }
 
@@ -150,6 +172,209 @@ pub fn choose_accessible_foreground(bg: Srgb8, candidates: &[Srgb8], min_ratio:
         .copied()
 }
 
+/// Picks an accessible foreground for `bg` without requiring a candidate list.
+///
+/// Lightness is walked outward from the background's own lightness (toward white and toward black, keeping its hue and saturation)
+/// by [`nearest_accessible_foreground`], and the first color reaching `min_ratio` is returned.
+/// Falls back to whichever of pure black or white has the higher contrast when no tinted candidate passes.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::wcag::{best_foreground, contrast_ratio, WCAG_AA_NORMAL};
+///
+/// let bg = Srgb8::new(40, 44, 52);
+/// let fg = best_foreground(bg, WCAG_AA_NORMAL);
+/// assert!(contrast_ratio(bg, fg) >= WCAG_AA_NORMAL);
+/// ```
+pub fn best_foreground(bg: Srgb8, min_ratio: f32) -> Srgb8 {
+    nearest_accessible_foreground(bg, bg, min_ratio).unwrap_or_else(|| black_or_white(bg))
+}
+
+/// Returns pure black or pure white, whichever has the higher contrast against `bg`; ties go to white.
+pub fn black_or_white(bg: Srgb8) -> Srgb8 {
+    let white = Srgb8::new(255, 255, 255);
+    let black = Srgb8::new(0, 0, 0);
+    if contrast_ratio(bg, white) >= contrast_ratio(bg, black) { white } else { black }
+}
+
+/// Pass/fail summary of a foreground/background pair against every WCAG 2.1 threshold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastReport {
+    pub ratio: f32,
+    pub aa_normal: bool,
+    pub aa_large: bool,
+    pub aaa_normal: bool,
+    pub aaa_large: bool,
+}
+
+impl ContrastReport {
+    /// Returns true when the pair passes every WCAG level.
+    pub fn passes_all(&self) -> bool {
+        self.aa_normal && self.aa_large && self.aaa_normal && self.aaa_large
+    }
+}
+
+/// Grades a foreground/background pair against the AA/AAA thresholds for normal and large text.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::wcag::grade_contrast;
+///
+/// let report = grade_contrast(Srgb8::new(221, 221, 221), Srgb8::new(17, 17, 17));
+/// assert!(report.aa_normal);
+/// assert!(report.aaa_large);
+/// ```
+pub fn grade_contrast(fg: Srgb8, bg: Srgb8) -> ContrastReport {
+    let ratio = contrast_ratio(fg, bg);
+    ContrastReport {
+        ratio,
+        aa_normal: meets_aa_normal(ratio),
+        aa_large: meets_aa_large(ratio),
+        aaa_normal: meets_aaa_normal(ratio),
+        aaa_large: meets_aaa_large(ratio),
+    }
+}
+
+/// Finds the smallest HSL lightness adjustment of `fg` that reaches `min_ratio` against `bg`.
+///
+/// Lightness is stepped outward in both directions (lighter and darker) in 1% increments while hue and saturation are preserved,
+/// returning the first candidate that passes.
+/// Returns `fg` unchanged when it already passes and None when no lightness value reaches the ratio.
+pub fn nearest_accessible_foreground(fg: Srgb8, bg: Srgb8, min_ratio: f32) -> Option<Srgb8> {
+    if contrast_ratio(fg, bg) >= min_ratio {
+        return Some(fg);
+    }
+
+    let hsl: Hsl = Rgb::from(fg).into();
+    for step in 1..=100 {
+        let delta = step as f32 * 0.01;
+        for lightness in [hsl.l + delta, hsl.l - delta] {
+            if !(0.0..=1.0).contains(&lightness) {
+                continue;
+            }
+            let candidate = Srgb8::from(Rgb::from(Hsl::new(hsl.h, hsl.s, lightness)));
+            if contrast_ratio(candidate, bg) >= min_ratio {
+                return Some(candidate);
+            }
+        }
+    }
+
+    None
+}
+
+/// Repairs a palette so every color reaches `min_ratio` against `bg`, changing each failing color as little as possible.
+///
+/// Passing colors are returned untouched. For a failing color, Lab lightness is binary-searched toward the
+/// contrast-increasing direction (away from the background's luminance) while a and b are preserved; if that side
+/// cannot reach the ratio the opposite side is tried, and pure black or white is used as a last resort.
+/// Unlike [`choose_accessible_foreground`], nothing is dropped: the output has the same length and order as `colors`.
+///
+/// # Examples
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::wcag::{contrast_ratio, make_accessible, WCAG_AA_NORMAL};
+///
+/// let bg = Srgb8::new(40, 44, 52);
+/// let fixed = make_accessible(&[Srgb8::new(90, 60, 140)], bg, WCAG_AA_NORMAL);
+/// assert!(contrast_ratio(fixed[0], bg) >= WCAG_AA_NORMAL);
+/// ```
+pub fn make_accessible(colors: &[Srgb8], bg: Srgb8, min_ratio: f32) -> Vec<Srgb8> {
+    colors
+        .iter()
+        .map(|&color| {
+            if contrast_ratio(color, bg) >= min_ratio {
+                return color;
+            }
+
+            let lighter_first = relative_luminance(color) >= relative_luminance(bg);
+            let targets = if lighter_first { [100.0, 0.0] } else { [0.0, 100.0] };
+            targets
+                .into_iter()
+                .find_map(|target| search_lightness(color, bg, min_ratio, target))
+                .unwrap_or_else(|| black_or_white(bg))
+        })
+        .collect()
+}
+
+/// Binary-searches Lab lightness between `color` and `target` for the closest value that reaches `min_ratio`.
+fn search_lightness(color: Srgb8, bg: Srgb8, min_ratio: f32, target: f32) -> Option<Srgb8> {
+    let lab = Lab::from(color);
+    let with_lightness = |l: f32| Srgb8::from(Lab::new(l, lab.a, lab.b));
+
+    let mut best = with_lightness(target);
+    if contrast_ratio(best, bg) < min_ratio {
+        return None;
+    }
+
+    let (mut passing, mut failing) = (target, lab.l);
+    for _ in 0..24 {
+        let mid = (passing + failing) / 2.0;
+        let candidate = with_lightness(mid);
+        if contrast_ratio(candidate, bg) >= min_ratio {
+            passing = mid;
+            best = candidate;
+        } else {
+            failing = mid;
+        }
+    }
+
+    Some(best)
+}
+
+/// Computes the contrast ratio between every foreground and background slot of a palette.
+///
+/// Row `i` holds the ratios of `colors[fg_idx[i]]` against each `colors[bg_idx[j]]`, so the result is
+/// `fg_idx.len()` rows by `bg_idx.len()` columns.
+///
+/// # Panics
+///
+/// Panics if any index is out of bounds for `colors`.
+pub fn contrast_matrix(colors: &[Srgb8], fg_idx: &[usize], bg_idx: &[usize]) -> Vec<Vec<f32>> {
+    fg_idx
+        .iter()
+        .map(|&fg| {
+            bg_idx
+                .iter()
+                .map(|&bg| contrast_ratio(colors[fg], colors[bg]))
+                .collect()
+        })
+        .collect()
+}
+
+/// Finds the pair of palette colors with the highest contrast ratio, e.g. for badge or label text.
+///
+/// Returns `(i, j, ratio)` with `i < j`, or `None` when the palette has fewer than two colors.
+///
+/// ```
+/// use colorizer::colors::Srgb8;
+/// use colorizer::wcag::max_contrast_pair;
+///
+/// let palette = [Srgb8::new(0, 0, 0), Srgb8::new(120, 40, 200), Srgb8::new(255, 255, 255)];
+/// let (i, j, ratio) = max_contrast_pair(&palette).unwrap();
+/// assert_eq!((i, j), (0, 2));
+/// assert!(ratio > 20.9);
+/// ```
+pub fn max_contrast_pair(colors: &[Srgb8]) -> Option<(usize, usize, f32)> {
+    contrast_pairs(colors).max_by(|a, b| a.2.total_cmp(&b.2))
+}
+
+/// Finds the pair of palette colors with the lowest contrast ratio, which flags colors that are hard to tell apart.
+///
+/// Returns `(i, j, ratio)` with `i < j`, or `None` when the palette has fewer than two colors.
+pub fn min_contrast_pair(colors: &[Srgb8]) -> Option<(usize, usize, f32)> {
+    contrast_pairs(colors).min_by(|a, b| a.2.total_cmp(&b.2))
+}
+
+fn contrast_pairs(colors: &[Srgb8]) -> impl Iterator<Item = (usize, usize, f32)> + '_ {
+    (0..colors.len())
+        .flat_map(move |i| (i + 1..colors.len()).map(move |j| (i, j, contrast_ratio(colors[i], colors[j]))))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,6 +427,20 @@ mod tests {
         assert!(approx_eq(ratio, ratio_reversed));
     }
 
+    #[test]
+    fn test_contrast_ratio_rgb_matches_srgb8() {
+        let pairs = [
+            (Srgb8::new(255, 255, 255), Srgb8::new(0, 0, 0)),
+            (Srgb8::new(100, 150, 200), Srgb8::new(50, 75, 100)),
+            (Srgb8::new(229, 108, 117), Srgb8::new(22, 22, 22)),
+        ];
+        for (a, b) in pairs {
+            let expected = contrast_ratio(a, b);
+            assert!(approx_eq(contrast_ratio_rgb(a.into(), b.into()), expected));
+            assert!(approx_eq(contrast_ratio_lab(a.into(), b.into()), expected));
+        }
+    }
+
     #[test]
     fn test_contrast_ratio_same_color() {
         let color = Srgb8::new(128, 128, 128);
@@ -321,4 +560,144 @@ mod tests {
         assert!(meets_aa_large(ratio));
         assert!(!meets_aa_normal(ratio));
     }
+
+    #[test]
+    fn test_grade_contrast_reports_every_level() {
+        let report = grade_contrast(Srgb8::from_hex("#dddddd").unwrap(), Srgb8::from_hex("#111111").unwrap());
+        assert!(report.ratio > 13.0, "Ratio: {}", report.ratio);
+        assert!(report.passes_all());
+
+        let report = grade_contrast(Srgb8::from_hex("#777777").unwrap(), Srgb8::new(255, 255, 255));
+        assert!(report.aa_large);
+        assert!(!report.aa_normal);
+        assert!(!report.aaa_normal);
+        assert!(!report.aaa_large);
+    }
+
+    #[test]
+    fn test_nearest_accessible_foreground_fixes_failing_pair() {
+        let bg = Srgb8::new(255, 255, 255);
+        let fg = Srgb8::from_hex("#777777").unwrap();
+        let suggested = nearest_accessible_foreground(fg, bg, WCAG_AA_NORMAL).unwrap();
+
+        assert!(contrast_ratio(suggested, bg) >= WCAG_AA_NORMAL);
+        assert!(
+            relative_luminance(suggested) < relative_luminance(fg),
+            "Should darken on white"
+        );
+    }
+
+    #[test]
+    fn test_nearest_accessible_foreground_keeps_passing_color() {
+        let bg = Srgb8::new(17, 17, 17);
+        let fg = Srgb8::new(221, 221, 221);
+        assert_eq!(nearest_accessible_foreground(fg, bg, WCAG_AA_NORMAL), Some(fg));
+    }
+
+    #[test]
+    fn test_make_accessible_repairs_failing_colors() {
+        let bg = Srgb8::from_hex("#1d2021").unwrap();
+        let colors = [
+            Srgb8::from_hex("#3c3836").unwrap(),
+            Srgb8::from_hex("#7c2a2a").unwrap(),
+            Srgb8::from_hex("#2a4a7c").unwrap(),
+            Srgb8::from_hex("#ebdbb2").unwrap(),
+        ];
+        let fixed = make_accessible(&colors, bg, WCAG_AA_NORMAL);
+
+        assert_eq!(fixed.len(), colors.len());
+        for (original, repaired) in colors.iter().zip(&fixed) {
+            assert!(
+                contrast_ratio(*repaired, bg) >= WCAG_AA_NORMAL,
+                "{} -> {} should pass",
+                original.to_hex(),
+                repaired.to_hex()
+            );
+            assert!(
+                relative_luminance(*repaired) >= relative_luminance(*original),
+                "Should lighten on dark"
+            );
+        }
+        assert_eq!(fixed[3], colors[3]);
+    }
+
+    #[test]
+    fn test_make_accessible_keeps_passing_palette() {
+        let bg = Srgb8::new(255, 255, 255);
+        let colors = [
+            Srgb8::new(0, 0, 0),
+            Srgb8::from_hex("#1a4d8f").unwrap(),
+            Srgb8::from_hex("#8f1a1a").unwrap(),
+        ];
+        assert_eq!(make_accessible(&colors, bg, WCAG_AA_NORMAL), colors.to_vec());
+    }
+
+    #[test]
+    fn test_best_foreground_is_white_on_near_black() {
+        let bg = Srgb8::new(8, 8, 8);
+        let fg = best_foreground(bg, WCAG_AAA_NORMAL);
+        assert!(relative_luminance(fg) > relative_luminance(bg));
+        assert!(contrast_ratio(bg, fg) >= WCAG_AAA_NORMAL);
+
+        assert_eq!(best_foreground(bg, 21.0), Srgb8::new(255, 255, 255));
+    }
+
+    #[test]
+    fn test_best_foreground_is_black_on_near_white() {
+        let bg = Srgb8::new(250, 250, 250);
+        let fg = best_foreground(bg, WCAG_AAA_NORMAL);
+        assert!(relative_luminance(fg) < relative_luminance(bg));
+        assert!(contrast_ratio(bg, fg) >= WCAG_AAA_NORMAL);
+
+        assert_eq!(best_foreground(bg, 21.0), Srgb8::new(0, 0, 0));
+    }
+
+    #[test]
+    fn test_black_or_white_uses_higher_contrast_option() {
+        // A luma heuristic calls #777777 dark, but black text contrasts slightly better than white (4.69 vs 4.48).
+        let mid_gray = Srgb8::new(0x77, 0x77, 0x77);
+        assert_eq!(black_or_white(mid_gray), Srgb8::new(0, 0, 0));
+        assert!(contrast_ratio(mid_gray, Srgb8::new(0, 0, 0)) > contrast_ratio(mid_gray, Srgb8::new(255, 255, 255)));
+
+        assert_eq!(black_or_white(Srgb8::new(20, 20, 60)), Srgb8::new(255, 255, 255));
+        assert_eq!(black_or_white(Srgb8::new(250, 240, 200)), Srgb8::new(0, 0, 0));
+    }
+
+    #[test]
+    fn test_contrast_matrix_dimensions_and_diagonal() {
+        let colors = [
+            Srgb8::new(0, 0, 0),
+            Srgb8::new(40, 40, 40),
+            Srgb8::new(255, 255, 255),
+            Srgb8::new(255, 0, 0),
+        ];
+        let matrix = contrast_matrix(&colors, &[0, 2, 3], &[0, 1]);
+
+        assert_eq!(matrix.len(), 3);
+        assert!(matrix.iter().all(|row| row.len() == 2));
+        assert!(approx_eq(matrix[0][0], 1.0));
+        assert!(approx_eq(matrix[1][0], 21.0));
+        assert!(approx_eq(matrix[2][1], contrast_ratio(colors[3], colors[1])));
+    }
+
+    #[test]
+    fn test_contrast_pairs_find_extremes() {
+        let palette = [
+            Srgb8::new(200, 60, 60),
+            Srgb8::new(255, 255, 255),
+            Srgb8::new(80, 80, 80),
+            Srgb8::new(0, 0, 0),
+            Srgb8::new(82, 80, 80),
+        ];
+
+        let (i, j, ratio) = max_contrast_pair(&palette).unwrap();
+        assert_eq!((i, j), (1, 3));
+        assert!(approx_eq(ratio, 21.0));
+
+        let (i, j, ratio) = min_contrast_pair(&palette).unwrap();
+        assert_eq!((i, j), (2, 4));
+        assert!(ratio < 1.05);
+
+        assert!(max_contrast_pair(&palette[..1]).is_none());
+    }
 }